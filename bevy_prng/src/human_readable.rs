@@ -0,0 +1,112 @@
+//! Human-readable serialization of the `ChaCha` PRNGs, which writes the seed as a hex string
+//! for formats like JSON or RON, while keeping the compact form of `rand_chacha` for binary
+//! formats.
+
+use serde::{de::Error, Deserialize, Deserializer, Serialize, Serializer};
+
+/// The parts of the state of a `ChaCha` PRNG that fully determine its output.
+pub(crate) trait ChaChaState: Serialize + for<'de> Deserialize<'de> {
+    fn to_parts(&self) -> ([u8; 32], u64, u128);
+
+    fn from_parts(seed: [u8; 32], stream: u64, word_pos: u128) -> Self;
+}
+
+macro_rules! chacha_state {
+    ($rng:ty) => {
+        impl ChaChaState for $rng {
+            fn to_parts(&self) -> ([u8; 32], u64, u128) {
+                (self.get_seed(), self.get_stream(), self.get_word_pos())
+            }
+
+            fn from_parts(seed: [u8; 32], stream: u64, word_pos: u128) -> Self {
+                let mut rng = <$rng as rand_core::SeedableRng>::from_seed(seed);
+
+                rng.set_stream(stream);
+                rng.set_word_pos(word_pos);
+
+                rng
+            }
+        }
+    };
+}
+
+chacha_state!(rand_chacha::ChaCha8Rng);
+chacha_state!(rand_chacha::ChaCha12Rng);
+chacha_state!(rand_chacha::ChaCha20Rng);
+
+#[derive(Serialize, Deserialize)]
+struct HumanReadableState<S> {
+    seed: S,
+    stream: u64,
+    word_pos: u128,
+}
+
+/// A seed as accepted by [`deserialize`], either as a hex string, or as the sequence of bytes
+/// written before the seed was serialized as hex, so that older save files keep loading.
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum HumanReadableSeed {
+    Hex(String),
+    Bytes([u8; 32]),
+}
+
+pub(crate) fn serialize<R: ChaChaState, S: Serializer>(
+    rng: &R,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    if !serializer.is_human_readable() {
+        return rng.serialize(serializer);
+    }
+
+    let (seed, stream, word_pos) = rng.to_parts();
+
+    HumanReadableState {
+        seed: seed
+            .iter()
+            .map(|byte| format!("{byte:02x}"))
+            .collect::<String>(),
+        stream,
+        word_pos,
+    }
+    .serialize(serializer)
+}
+
+pub(crate) fn deserialize<'de, R: ChaChaState, D: Deserializer<'de>>(
+    deserializer: D,
+) -> Result<R, D::Error> {
+    if !deserializer.is_human_readable() {
+        return R::deserialize(deserializer);
+    }
+
+    let state = HumanReadableState::<HumanReadableSeed>::deserialize(deserializer)?;
+
+    let hex = match state.seed {
+        HumanReadableSeed::Hex(hex) => hex,
+        HumanReadableSeed::Bytes(seed) => {
+            return Ok(R::from_parts(seed, state.stream, state.word_pos))
+        }
+    };
+
+    let hex = hex.as_bytes();
+
+    if hex.len() != 64 {
+        return Err(D::Error::invalid_length(
+            hex.len(),
+            &"a seed of 64 hex digits",
+        ));
+    }
+
+    let mut seed = [0; 32];
+
+    for (byte, pair) in seed.iter_mut().zip(hex.chunks_exact(2)) {
+        let digit = |digit: u8| {
+            char::from(digit)
+                .to_digit(16)
+                .ok_or_else(|| D::Error::custom("the seed is not a valid hex string"))
+        };
+
+        *byte = (digit(pair[0])? << 4 | digit(pair[1])?) as u8;
+    }
+
+    Ok(R::from_parts(seed, state.stream, state.word_pos))
+}
@@ -39,6 +39,9 @@ use bevy::prelude::{ReflectDeserialize, ReflectSerialize};
 ))]
 use serde::{Deserialize, Serialize};
 
+#[cfg(all(feature = "human_readable", feature = "rand_chacha"))]
+mod human_readable;
+
 #[cfg(any(
     feature = "wyrand",
     feature = "rand_chacha",
@@ -46,7 +49,7 @@ use serde::{Deserialize, Serialize};
     feature = "rand_xoshiro"
 ))]
 macro_rules! newtype_prng {
-    ($newtype:tt, $rng:ty, $seed:ty, $doc:tt, $feature:tt) => {
+    ($newtype:tt, $rng:ty, $seed:ty, $doc:tt, $feature:tt $(, serde_with = $with:literal)?) => {
         #[doc = $doc]
         #[derive(Debug, Clone, PartialEq, Reflect)]
         #[cfg_attr(feature = "serialize", derive(Serialize, Deserialize))]
@@ -60,7 +63,10 @@ macro_rules! newtype_prng {
         )]
         #[cfg_attr(docsrs, doc(cfg(feature = $feature)))]
         #[repr(transparent)]
-        pub struct $newtype($rng);
+        pub struct $newtype(
+            $(#[cfg_attr(feature = "human_readable", serde(with = $with))])?
+            $rng
+        );
 
         impl $newtype {
             /// Create a new instance.
@@ -111,6 +117,85 @@ macro_rules! newtype_prng {
     };
 }
 
+/// A PRNG with a seekable output stream, which can report and move its position within the
+/// stream of output it produces.
+pub trait SeekableRng: rand_core::RngCore {
+    /// Returns the current position within the output stream, counted in `u32` words.
+    fn get_word_pos(&self) -> u128;
+
+    /// Moves to the given position within the output stream, counted in `u32` words.
+    fn set_word_pos(&mut self, word_pos: u128);
+
+    /// Returns `true` if both instances will produce the exact same output from their current
+    /// positions onwards. Unlike `==`, which compares whatever state a PRNG derives equality
+    /// over, this compares only the seed, stream and position, so two instances that reached
+    /// the same position through different calls (such as `next_u64` versus two `next_u32`s)
+    /// are always considered equal.
+    fn stream_eq(&self, other: &Self) -> bool;
+}
+
+#[cfg(feature = "rand_chacha")]
+macro_rules! seekable_prng {
+    ($newtype:tt) => {
+        /// For `ChaCha`, `stream_eq` agrees with `==`, as the underlying `PartialEq` already
+        /// compares the seed, stream and word position rather than the internal block buffer.
+        impl SeekableRng for $newtype {
+            #[inline]
+            fn get_word_pos(&self) -> u128 {
+                self.0.get_word_pos()
+            }
+
+            #[inline]
+            fn set_word_pos(&mut self, word_pos: u128) {
+                self.0.set_word_pos(word_pos);
+            }
+
+            #[inline]
+            fn stream_eq(&self, other: &Self) -> bool {
+                self.0.get_seed() == other.0.get_seed()
+                    && self.0.get_stream() == other.0.get_stream()
+                    && self.0.get_word_pos() == other.0.get_word_pos()
+            }
+        }
+    };
+}
+
+/// A PRNG that can check whether a seed is valid for it. Some PRNGs have seeds that would put
+/// them in a degenerate state, such as the all-zero seed for the `Xoshiro`/`Xoroshiro` families,
+/// which is a fixed point of their algorithms. `rand_xoshiro` silently swaps such seeds out for a
+/// different one, so the resulting stream is not the one that the seed would suggest either.
+pub trait ValidatedSeed: rand_core::SeedableRng {
+    /// Returns `true` if the seed can be used without producing a degenerate stream of output.
+    fn is_valid_seed(seed: &Self::Seed) -> bool;
+}
+
+#[cfg(any(
+    feature = "wyrand",
+    feature = "rand_chacha",
+    feature = "rand_pcg",
+    feature = "rand_xoshiro"
+))]
+macro_rules! validated_seed {
+    ($newtype:tt) => {
+        /// Every seed is valid for this PRNG.
+        impl ValidatedSeed for $newtype {
+            #[inline]
+            fn is_valid_seed(_: &Self::Seed) -> bool {
+                true
+            }
+        }
+    };
+    ($newtype:tt, $seed:ident => $check:expr) => {
+        /// All-zero seeds are invalid for this PRNG.
+        impl ValidatedSeed for $newtype {
+            #[inline]
+            fn is_valid_seed($seed: &Self::Seed) -> bool {
+                $check
+            }
+        }
+    };
+}
+
 #[cfg(feature = "wyrand")]
 newtype_prng!(
     WyRand,
@@ -126,27 +211,39 @@ newtype_prng!(
     ::rand_chacha::ChaCha8Rng,
     [u8; 32],
     "A newtyped [`rand_chacha::ChaCha8Rng`] RNG",
-    "rand_chacha"
+    "rand_chacha",
+    serde_with = "crate::human_readable"
 );
 
+#[cfg(feature = "rand_chacha")]
+seekable_prng!(ChaCha8Rng);
+
 #[cfg(feature = "rand_chacha")]
 newtype_prng!(
     ChaCha12Rng,
     ::rand_chacha::ChaCha12Rng,
     [u8; 32],
     "A newtyped [`rand_chacha::ChaCha12Rng`] RNG",
-    "rand_chacha"
+    "rand_chacha",
+    serde_with = "crate::human_readable"
 );
 
+#[cfg(feature = "rand_chacha")]
+seekable_prng!(ChaCha12Rng);
+
 #[cfg(feature = "rand_chacha")]
 newtype_prng!(
     ChaCha20Rng,
     ::rand_chacha::ChaCha20Rng,
     [u8; 32],
     "A newtyped [`rand_chacha::ChaCha20Rng`] RNG",
-    "rand_chacha"
+    "rand_chacha",
+    serde_with = "crate::human_readable"
 );
 
+#[cfg(feature = "rand_chacha")]
+seekable_prng!(ChaCha20Rng);
+
 #[cfg(feature = "rand_pcg")]
 newtype_prng!(
     Pcg32,
@@ -303,3 +400,66 @@ newtype_prng!(
     "A newtyped [`rand_xoshiro::Xoroshiro64Star`] RNG",
     "rand_xoshiro"
 );
+
+#[cfg(feature = "wyrand")]
+validated_seed!(WyRand);
+
+#[cfg(feature = "rand_chacha")]
+validated_seed!(ChaCha8Rng);
+
+#[cfg(feature = "rand_chacha")]
+validated_seed!(ChaCha12Rng);
+
+#[cfg(feature = "rand_chacha")]
+validated_seed!(ChaCha20Rng);
+
+#[cfg(feature = "rand_pcg")]
+validated_seed!(Pcg32);
+
+#[cfg(feature = "rand_pcg")]
+validated_seed!(Pcg64);
+
+#[cfg(feature = "rand_pcg")]
+validated_seed!(Pcg64Mcg);
+
+#[cfg(feature = "rand_xoshiro")]
+validated_seed!(Xoshiro512StarStar, seed => seed.0.iter().any(|&byte| byte != 0));
+
+#[cfg(feature = "rand_xoshiro")]
+validated_seed!(Xoshiro512PlusPlus, seed => seed.0.iter().any(|&byte| byte != 0));
+
+#[cfg(feature = "rand_xoshiro")]
+validated_seed!(Xoshiro512Plus, seed => seed.0.iter().any(|&byte| byte != 0));
+
+#[cfg(feature = "rand_xoshiro")]
+validated_seed!(Xoshiro256StarStar, seed => seed.iter().any(|&byte| byte != 0));
+
+#[cfg(feature = "rand_xoshiro")]
+validated_seed!(Xoshiro256PlusPlus, seed => seed.iter().any(|&byte| byte != 0));
+
+#[cfg(feature = "rand_xoshiro")]
+validated_seed!(Xoshiro256Plus, seed => seed.iter().any(|&byte| byte != 0));
+
+#[cfg(feature = "rand_xoshiro")]
+validated_seed!(Xoroshiro128StarStar, seed => seed.iter().any(|&byte| byte != 0));
+
+#[cfg(feature = "rand_xoshiro")]
+validated_seed!(Xoroshiro128PlusPlus, seed => seed.iter().any(|&byte| byte != 0));
+
+#[cfg(feature = "rand_xoshiro")]
+validated_seed!(Xoroshiro128Plus, seed => seed.iter().any(|&byte| byte != 0));
+
+#[cfg(feature = "rand_xoshiro")]
+validated_seed!(Xoshiro128StarStar, seed => seed.iter().any(|&byte| byte != 0));
+
+#[cfg(feature = "rand_xoshiro")]
+validated_seed!(Xoshiro128PlusPlus, seed => seed.iter().any(|&byte| byte != 0));
+
+#[cfg(feature = "rand_xoshiro")]
+validated_seed!(Xoshiro128Plus, seed => seed.iter().any(|&byte| byte != 0));
+
+#[cfg(feature = "rand_xoshiro")]
+validated_seed!(Xoroshiro64StarStar, seed => seed.iter().any(|&byte| byte != 0));
+
+#[cfg(feature = "rand_xoshiro")]
+validated_seed!(Xoroshiro64Star, seed => seed.iter().any(|&byte| byte != 0));
@@ -0,0 +1,86 @@
+//! Demonstrates replicating the state of an `EntropyComponent` from a server to a client, as a
+//! replication crate like `bevy_replicon` would. `EntropyComponent` already implements
+//! `Serialize` and `Deserialize`, so it can be registered for replication like any other
+//! component. Here, the replication is simulated by serializing the component with `bincode`
+//! on the server and applying the payload on the client.
+
+use bevy::prelude::*;
+use bevy_prng::ChaCha8Rng;
+use bevy_rand::prelude::*;
+use rand::Rng;
+
+/// Replicated entities are matched up between the server and client by a shared id.
+#[derive(Component, Clone, Copy, PartialEq, Eq)]
+struct NetworkId(u32);
+
+#[derive(Component)]
+struct LootTable;
+
+fn main() {
+    let mut server = App::new();
+
+    server
+        .add_plugins(EntropyPlugin::<ChaCha8Rng>::with_seed([1; 32]))
+        .add_systems(Startup, setup_server)
+        .add_systems(Update, roll_loot);
+
+    let mut client = App::new();
+
+    client.add_plugins(EntropyPlugin::<ChaCha8Rng>::default());
+
+    for _ in 0..3 {
+        server.update();
+
+        // Send the RNG state over to the client, so it can predict the next rolls.
+        let payloads = send_rng_state(&mut server.world);
+        receive_rng_state(&mut client.world, &payloads);
+    }
+
+    let predicted = client
+        .world
+        .query::<&mut EntropyComponent<ChaCha8Rng>>()
+        .single_mut(&mut client.world)
+        .gen_range(0..100);
+
+    println!("Client predicts the next roll to be {predicted}");
+
+    server.update();
+}
+
+fn setup_server(mut commands: Commands, mut global: ResMut<GlobalEntropy<ChaCha8Rng>>) {
+    commands.spawn((NetworkId(0), LootTable, EntropyComponent::from(&mut global)));
+}
+
+fn roll_loot(mut q_loot: Query<&mut EntropyComponent<ChaCha8Rng>, With<LootTable>>) {
+    for mut rng in &mut q_loot {
+        println!("Server rolled {}", rng.gen_range(0..100));
+    }
+}
+
+fn send_rng_state(world: &mut World) -> Vec<(NetworkId, Vec<u8>)> {
+    world
+        .query::<(&NetworkId, &EntropyComponent<ChaCha8Rng>)>()
+        .iter(world)
+        .map(|(id, rng)| (*id, bincode::serialize(rng).unwrap()))
+        .collect()
+}
+
+fn receive_rng_state(world: &mut World, payloads: &[(NetworkId, Vec<u8>)]) {
+    for (id, payload) in payloads {
+        let rng: EntropyComponent<ChaCha8Rng> = bincode::deserialize(payload).unwrap();
+
+        let existing = world
+            .query::<(Entity, &NetworkId)>()
+            .iter(world)
+            .find_map(|(entity, network_id)| (network_id == id).then_some(entity));
+
+        match existing {
+            Some(entity) => {
+                world.entity_mut(entity).insert(rng);
+            }
+            None => {
+                world.spawn((*id, rng));
+            }
+        }
+    }
+}
@@ -0,0 +1,193 @@
+use crate::{resource::GlobalEntropy, traits::SeedableEntropySource};
+use rand::Rng;
+use rand_core::RngCore;
+
+/// A table for sampling indices in proportion to a histogram of counts in constant time, via
+/// Walker's alias method. Building the table takes linear time, so it should be kept around
+/// and reused when sampling from the same histogram many times, such as for reproducing
+/// observed frequencies of events.
+///
+/// The table is built and sampled with integer arithmetic only, so for the same RNG state and
+/// counts, the same indices are always sampled on every platform.
+///
+/// ```
+/// use bevy_rand::prelude::*;
+/// use bevy_prng::ChaCha8Rng;
+/// use rand_core::SeedableRng;
+///
+/// let mut rng = GlobalEntropy::<ChaCha8Rng>::from_seed([1; 32]);
+///
+/// let table = AliasTable::new(&[50, 30, 0, 20]).unwrap();
+///
+/// let index = table.sample(&mut rng);
+///
+/// assert_ne!(index, 2);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AliasTable {
+    total: u64,
+    thresholds: Vec<u64>,
+    aliases: Vec<usize>,
+}
+
+impl AliasTable {
+    /// Create a new table from a histogram of counts, where each index is sampled in proportion
+    /// to its count. Returns `None` if there are no counts or all counts are zero, as there is
+    /// nothing to sample.
+    #[must_use]
+    pub fn new(counts: &[u32]) -> Option<Self> {
+        let total: u64 = counts.iter().map(|&count| u64::from(count)).sum();
+
+        if total == 0 {
+            return None;
+        }
+
+        let len = counts.len();
+
+        // Every column of the table holds `total` units of weight, split between its own index
+        // and an alias.
+        let mut scaled: Vec<u64> = counts
+            .iter()
+            .map(|&count| u64::from(count) * len as u64)
+            .collect();
+        let mut thresholds = vec![total; len];
+        let mut aliases: Vec<usize> = (0..len).collect();
+
+        let (mut small, mut large): (Vec<usize>, Vec<usize>) =
+            (0..len).partition(|&index| scaled[index] < total);
+
+        while let (Some(&under), Some(&over)) = (small.last(), large.last()) {
+            small.pop();
+
+            thresholds[under] = scaled[under];
+            aliases[under] = over;
+
+            // Move the weight filling up the under-full column out of the over-full one.
+            scaled[over] -= total - scaled[under];
+
+            if scaled[over] < total {
+                large.pop();
+                small.push(over);
+            }
+        }
+
+        Some(Self {
+            total,
+            thresholds,
+            aliases,
+        })
+    }
+
+    /// Returns the amount of indices in the table.
+    #[inline]
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.thresholds.len()
+    }
+
+    /// Returns `true` if the table has no indices, which is never the case for a built table.
+    #[inline]
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.thresholds.is_empty()
+    }
+
+    /// Samples an index in proportion to its count.
+    pub fn sample(&self, rng: &mut impl RngCore) -> usize {
+        let column = rng.gen_range(0..self.len());
+
+        if rng.gen_range(0..self.total) < self.thresholds[column] {
+            column
+        } else {
+            self.aliases[column]
+        }
+    }
+}
+
+impl<R: SeedableEntropySource + 'static> GlobalEntropy<R> {
+    /// Samples an index in proportion to the given histogram of counts, such as for producing
+    /// outcomes matching observed frequencies. Returns `None` if there are no counts or all
+    /// counts are zero. An [`AliasTable`] is built for every call, so when sampling from the
+    /// same histogram repeatedly, build an [`AliasTable`] once and reuse it instead.
+    ///
+    /// ```
+    /// use bevy_rand::prelude::*;
+    /// use bevy_prng::ChaCha8Rng;
+    /// use rand_core::SeedableRng;
+    ///
+    /// let mut rng = GlobalEntropy::<ChaCha8Rng>::from_seed([1; 32]);
+    ///
+    /// let index = rng.sample_histogram(&[5, 0, 3]);
+    ///
+    /// assert_ne!(index, Some(1));
+    /// ```
+    #[cfg_attr(docsrs, doc(cfg(feature = "rand")))]
+    pub fn sample_histogram(&mut self, counts: &[u32]) -> Option<usize> {
+        Some(AliasTable::new(counts)?.sample(self))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy_prng::ChaCha8Rng;
+    use rand_core::SeedableRng;
+
+    use super::*;
+
+    #[test]
+    fn matches_histogram() {
+        let mut rng = GlobalEntropy::<ChaCha8Rng>::from_seed([5; 32]);
+
+        let counts = [10, 0, 25, 5, 60];
+        let table = AliasTable::new(&counts).unwrap();
+
+        let samples = 100_000;
+        let mut observed = [0u32; 5];
+
+        for _ in 0..samples {
+            observed[table.sample(&mut rng)] += 1;
+        }
+
+        assert_eq!(observed[1], 0, "zero counts should never be sampled");
+
+        for (index, (&count, &seen)) in counts.iter().zip(observed.iter()).enumerate() {
+            let expected = count as f64 / 100.0;
+            let actual = seen as f64 / samples as f64;
+
+            assert!(
+                (expected - actual).abs() < 0.01,
+                "index {index} was sampled at {actual}, expected {expected}"
+            );
+        }
+    }
+
+    #[test]
+    fn deterministic_sampling() {
+        let mut rng1 = GlobalEntropy::<ChaCha8Rng>::from_seed([5; 32]);
+        let mut rng2 = GlobalEntropy::<ChaCha8Rng>::from_seed([5; 32]);
+
+        let counts = [3, 1, 4, 1, 5, 9, 2, 6];
+
+        let samples1: Vec<_> = (0..50)
+            .map(|_| rng1.sample_histogram(&counts).unwrap())
+            .collect();
+        let samples2: Vec<_> = (0..50)
+            .map(|_| rng2.sample_histogram(&counts).unwrap())
+            .collect();
+
+        assert_eq!(samples1, samples2);
+    }
+
+    #[test]
+    fn nothing_to_sample() {
+        let mut rng = GlobalEntropy::<ChaCha8Rng>::from_seed([5; 32]);
+
+        assert_eq!(AliasTable::new(&[]), None);
+        assert_eq!(rng.sample_histogram(&[0, 0, 0]), None);
+        assert_eq!(
+            rng.sample_histogram(&[0, 7, 0]),
+            Some(1),
+            "a single count should always be sampled"
+        );
+    }
+}
@@ -0,0 +1,140 @@
+use rand::Rng;
+use rand_core::RngCore;
+
+/// A chooser that picks random items while never picking the same item twice in a row, such as
+/// for varying sound effects or music clips without an audible repeat. The last pick is
+/// remembered, and excluded from the next choice unless nothing else can be chosen.
+///
+/// Given the same RNG state, the same sequence of items is always chosen.
+///
+/// ```
+/// use bevy_rand::prelude::*;
+/// use bevy_prng::ChaCha8Rng;
+/// use rand_core::SeedableRng;
+///
+/// let mut rng = GlobalEntropy::<ChaCha8Rng>::from_seed([1; 32]);
+///
+/// let clips = ["footstep_1.ogg", "footstep_2.ogg", "footstep_3.ogg"];
+///
+/// let mut chooser = AntiRepeatChooser::new();
+///
+/// let first = chooser.choose(&clips, &mut rng);
+/// let second = chooser.choose(&clips, &mut rng);
+///
+/// assert_ne!(first, second);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AntiRepeatChooser<T> {
+    last: Option<T>,
+}
+
+impl<T: PartialEq + Clone> AntiRepeatChooser<T> {
+    /// Create a new chooser with no previous pick.
+    #[inline]
+    #[must_use]
+    pub fn new() -> Self {
+        Self { last: None }
+    }
+
+    /// Chooses a random item from `items` that isn't equal to the previous pick, unless every
+    /// item is equal to it, such as when there is only one item. Returns `None` if `items` is
+    /// empty, in which case the previous pick is kept.
+    pub fn choose<'a>(&mut self, items: &'a [T], rng: &mut impl RngCore) -> Option<&'a T> {
+        if items.is_empty() {
+            return None;
+        }
+
+        let is_fresh = |item: &&T| self.last.as_ref() != Some(*item);
+
+        let chosen = match items.iter().filter(is_fresh).count() {
+            0 => &items[rng.gen_range(0..items.len())],
+            fresh => items.iter().filter(is_fresh).nth(rng.gen_range(0..fresh))?,
+        };
+
+        self.last = Some(chosen.clone());
+
+        Some(chosen)
+    }
+
+    /// Returns the previous pick, if any.
+    #[inline]
+    #[must_use]
+    pub fn last(&self) -> Option<&T> {
+        self.last.as_ref()
+    }
+
+    /// Forgets the previous pick, so any item can be chosen next.
+    #[inline]
+    pub fn reset(&mut self) {
+        self.last = None;
+    }
+}
+
+impl<T: PartialEq + Clone> Default for AntiRepeatChooser<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy_prng::ChaCha8Rng;
+    use rand_core::SeedableRng;
+
+    use crate::resource::GlobalEntropy;
+
+    use super::*;
+
+    #[test]
+    fn never_repeats() {
+        let mut rng = GlobalEntropy::<ChaCha8Rng>::from_seed([2; 32]);
+
+        let items = [1, 2, 3];
+        let mut chooser = AntiRepeatChooser::new();
+
+        let picks: Vec<_> = (0..100)
+            .map(|_| *chooser.choose(&items, &mut rng).unwrap())
+            .collect();
+
+        assert!(
+            picks.windows(2).all(|pair| pair[0] != pair[1]),
+            "consecutive picks should differ"
+        );
+        assert!(items.iter().all(|item| picks.contains(item)));
+    }
+
+    #[test]
+    fn deterministic_sequence() {
+        let mut rng1 = GlobalEntropy::<ChaCha8Rng>::from_seed([2; 32]);
+        let mut rng2 = GlobalEntropy::<ChaCha8Rng>::from_seed([2; 32]);
+
+        let items = ['a', 'b', 'c', 'd'];
+        let mut chooser1 = AntiRepeatChooser::new();
+        let mut chooser2 = AntiRepeatChooser::new();
+
+        let picks1: Vec<_> = (0..20)
+            .map(|_| *chooser1.choose(&items, &mut rng1).unwrap())
+            .collect();
+        let picks2: Vec<_> = (0..20)
+            .map(|_| *chooser2.choose(&items, &mut rng2).unwrap())
+            .collect();
+
+        assert_eq!(picks1, picks2);
+    }
+
+    #[test]
+    fn single_and_empty_items() {
+        let mut rng = GlobalEntropy::<ChaCha8Rng>::from_seed([2; 32]);
+
+        let mut chooser = AntiRepeatChooser::new();
+
+        assert_eq!(chooser.choose(&[7], &mut rng), Some(&7));
+        assert_eq!(
+            chooser.choose(&[7], &mut rng),
+            Some(&7),
+            "a single item should still be chosen"
+        );
+        assert_eq!(chooser.choose(&[], &mut rng), None);
+        assert_eq!(chooser.last(), Some(&7));
+    }
+}
@@ -0,0 +1,101 @@
+use crate::{resource::GlobalEntropy, traits::SeedableEntropySource};
+use bevy::asset::{Asset, AssetId, Handle};
+use rand::seq::SliceRandom;
+
+impl<R: SeedableEntropySource + 'static> GlobalEntropy<R> {
+    /// Returns the [`AssetId`]s of `handles` in a random order, such as for processing loaded
+    /// assets in a shuffled order when load testing. The order is keyed on the identity of the
+    /// assets rather than the handles, so the ids are deduplicated and sorted before being
+    /// shuffled. For the same RNG state and set of asset ids, the same order is thus always
+    /// produced, regardless of the order of `handles` or how many handles point to the same
+    /// asset.
+    ///
+    /// Index based [`AssetId`]s are only stable as long as assets are allocated in the same
+    /// order, so prefer [`AssetId::Uuid`]s when the order must be reproducible across runs that
+    /// load assets differently.
+    ///
+    /// ```
+    /// use bevy::asset::Handle;
+    /// use bevy_rand::prelude::*;
+    /// use bevy_rand::level_seed::LevelSeed;
+    /// use bevy_prng::ChaCha8Rng;
+    /// use rand_core::SeedableRng;
+    ///
+    /// let mut rng = GlobalEntropy::<ChaCha8Rng>::from_seed([1; 32]);
+    ///
+    /// let handles: Vec<Handle<LevelSeed>> = (0..4).map(Handle::weak_from_u128).collect();
+    ///
+    /// let order = rng.shuffled_asset_ids(&handles);
+    ///
+    /// assert_eq!(order.len(), 4);
+    /// ```
+    #[cfg_attr(docsrs, doc(cfg(all(feature = "asset", feature = "rand"))))]
+    pub fn shuffled_asset_ids<A: Asset>(&mut self, handles: &[Handle<A>]) -> Vec<AssetId<A>> {
+        let mut ids: Vec<AssetId<A>> = handles.iter().map(Handle::id).collect();
+
+        ids.sort_unstable();
+        ids.dedup();
+        ids.shuffle(self);
+
+        ids
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy::asset::Assets;
+    use bevy_prng::ChaCha8Rng;
+    use rand_core::SeedableRng;
+
+    use super::*;
+    use crate::level_seed::LevelSeed;
+
+    #[test]
+    fn asset_order_reproducible() {
+        let mut rng1 = GlobalEntropy::<ChaCha8Rng>::from_seed([6; 32]);
+        let mut rng2 = GlobalEntropy::<ChaCha8Rng>::from_seed([6; 32]);
+
+        let handles: Vec<Handle<LevelSeed>> = (0..20).map(Handle::weak_from_u128).collect();
+
+        // The same assets, but in a different order and with duplicate handles.
+        let mut reordered: Vec<Handle<LevelSeed>> = handles.iter().rev().cloned().collect();
+        reordered.extend(handles.iter().take(5).cloned());
+
+        let order1 = rng1.shuffled_asset_ids(&handles);
+        let order2 = rng2.shuffled_asset_ids(&reordered);
+
+        assert_eq!(order1, order2);
+
+        let mut sorted = order1.clone();
+        sorted.sort();
+
+        assert_eq!(
+            sorted,
+            handles.iter().map(Handle::id).collect::<Vec<_>>(),
+            "every asset id should be returned exactly once"
+        );
+        assert_ne!(order1, sorted, "the asset ids should be shuffled");
+    }
+
+    #[test]
+    fn asset_order_keyed_on_ids() {
+        let mut rng1 = GlobalEntropy::<ChaCha8Rng>::from_seed([6; 32]);
+        let mut rng2 = GlobalEntropy::<ChaCha8Rng>::from_seed([6; 32]);
+
+        let mut assets1 = Assets::<LevelSeed>::default();
+        let mut assets2 = Assets::<LevelSeed>::default();
+
+        let handles1: Vec<_> = (0..10)
+            .map(|seed| assets1.add(LevelSeed::new(seed)))
+            .collect();
+        let handles2: Vec<_> = (0..10)
+            .map(|seed| assets2.add(LevelSeed::new(seed)))
+            .collect();
+
+        // Assets allocated in the same order by separate collections get the same ids.
+        assert_eq!(
+            rng1.shuffled_asset_ids(&handles1),
+            rng2.shuffled_asset_ids(&handles2)
+        );
+    }
+}
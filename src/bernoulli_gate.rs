@@ -0,0 +1,92 @@
+use rand::{distributions::Bernoulli, Rng};
+use rand_core::RngCore;
+
+/// A precomputed probability check, for hot paths like per-frame chance rolls that repeatedly
+/// check the same probability. The [`Bernoulli`] distribution is built once up front, instead
+/// of on every call as with [`Rng::gen_bool`], while producing the exact same outcomes as
+/// [`Rng::gen_bool`] for the same RNG state and probability.
+///
+/// ```
+/// use bevy_rand::prelude::*;
+/// use bevy_prng::ChaCha8Rng;
+/// use rand_core::SeedableRng;
+///
+/// let mut rng = GlobalEntropy::<ChaCha8Rng>::from_seed([1; 32]);
+///
+/// let critical_hit = BernoulliGate::new(0.1);
+///
+/// let hits = (0..100).filter(|_| critical_hit.check(&mut rng)).count();
+///
+/// assert!(hits < 100);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct BernoulliGate {
+    distribution: Bernoulli,
+}
+
+impl BernoulliGate {
+    /// Create a new gate that passes with a probability of `p`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `p` is not within `0.0..=1.0`.
+    #[inline]
+    #[must_use]
+    pub fn new(p: f64) -> Self {
+        let Ok(distribution) = Bernoulli::new(p) else {
+            panic!("BernoulliGate::new called with a probability outside of `0.0..=1.0`");
+        };
+
+        Self { distribution }
+    }
+
+    /// Returns `true` with the probability of the gate.
+    #[inline]
+    pub fn check(&self, rng: &mut impl RngCore) -> bool {
+        rng.sample(self.distribution)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy_prng::ChaCha8Rng;
+    use rand_core::SeedableRng;
+
+    use super::*;
+    use crate::resource::GlobalEntropy;
+
+    #[test]
+    fn matches_gen_bool() {
+        for p in [0.0, 0.001, 0.25, 0.5, 0.9, 1.0] {
+            let mut cached = GlobalEntropy::<ChaCha8Rng>::from_seed([3; 32]);
+            let mut fresh = GlobalEntropy::<ChaCha8Rng>::from_seed([3; 32]);
+
+            let gate = BernoulliGate::new(p);
+
+            for _ in 0..1000 {
+                assert_eq!(
+                    gate.check(&mut cached),
+                    fresh.gen_bool(p),
+                    "the gate should match gen_bool for a probability of {p}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn extreme_probabilities() {
+        let mut rng = GlobalEntropy::<ChaCha8Rng>::from_seed([3; 32]);
+
+        let never = BernoulliGate::new(0.0);
+        let always = BernoulliGate::new(1.0);
+
+        assert!((0..100).all(|_| !never.check(&mut rng)));
+        assert!((0..100).all(|_| always.check(&mut rng)));
+    }
+
+    #[test]
+    #[should_panic]
+    fn invalid_probability() {
+        let _ = BernoulliGate::new(1.5);
+    }
+}
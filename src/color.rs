@@ -0,0 +1,301 @@
+use crate::{resource::GlobalEntropy, traits::SeedableEntropySource};
+use bevy::render::color::Color;
+use rand::Rng;
+
+/// A colour in HSV space, with the hue in degrees in `[0, 360)` and the saturation and value in
+/// `[0, 1)`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Hsv {
+    hue: f32,
+    saturation: f32,
+    value: f32,
+}
+
+impl Hsv {
+    fn random(rng: &mut impl Rng) -> Self {
+        Self {
+            hue: rng.gen::<f32>() * 360.0,
+            saturation: rng.gen(),
+            value: rng.gen(),
+        }
+    }
+
+    /// Interpolates towards `other` by `t`, with the hue going the shortest way around the
+    /// colour wheel.
+    fn lerp(self, other: Self, t: f32) -> Self {
+        let mut delta = other.hue - self.hue;
+
+        if delta > 180.0 {
+            delta -= 360.0;
+        } else if delta < -180.0 {
+            delta += 360.0;
+        }
+
+        Self {
+            hue: (self.hue + delta * t).rem_euclid(360.0),
+            saturation: self.saturation + (other.saturation - self.saturation) * t,
+            value: self.value + (other.value - self.value) * t,
+        }
+    }
+
+    fn to_color(self) -> Color {
+        let chroma = self.value * self.saturation;
+        let sector = self.hue / 60.0;
+        let x = chroma * (1.0 - (sector.rem_euclid(2.0) - 1.0).abs());
+
+        let (r, g, b) = match sector as u32 {
+            0 => (chroma, x, 0.0),
+            1 => (x, chroma, 0.0),
+            2 => (0.0, chroma, x),
+            3 => (0.0, x, chroma),
+            4 => (x, 0.0, chroma),
+            _ => (chroma, 0.0, x),
+        };
+
+        let m = self.value - chroma;
+
+        Color::rgb(r + m, g + m, b + m)
+    }
+}
+
+/// Converts a colour temperature in kelvin to the sRGB colour of a blackbody radiating at that
+/// temperature, via Tanner Helland's curve fit, which is accurate within `1000..=40000` kelvin.
+fn kelvin_to_color(kelvin: f32) -> Color {
+    let temperature = kelvin / 100.0;
+
+    let red = if temperature <= 66.0 {
+        255.0
+    } else {
+        329.698_73 * (temperature - 60.0).powf(-0.133_204_76)
+    };
+
+    let green = if temperature <= 66.0 {
+        99.470_8 * temperature.ln() - 161.119_57
+    } else {
+        288.122_17 * (temperature - 60.0).powf(-0.075_514_846)
+    };
+
+    let blue = if temperature >= 66.0 {
+        255.0
+    } else if temperature <= 19.0 {
+        0.0
+    } else {
+        138.517_73 * (temperature - 10.0).ln() - 305.044_8
+    };
+
+    let channel = |value: f32| value.clamp(0.0, 255.0) / 255.0;
+
+    Color::rgb(channel(red), channel(green), channel(blue))
+}
+
+impl<R: SeedableEntropySource + 'static> GlobalEntropy<R> {
+    /// Generates a gradient of `stops` colours, such as for procedural skyboxes. Two random
+    /// endpoint colours are chosen, and the gradient is interpolated between them in HSV space,
+    /// with the hue going the shortest way around the colour wheel. The first and last colours
+    /// are the endpoints themselves. Returns only the starting endpoint if `stops` is one, and
+    /// an empty `Vec` if `stops` is zero.
+    ///
+    /// For the same RNG state, the same gradient is always generated.
+    ///
+    /// ```
+    /// use bevy_rand::prelude::*;
+    /// use bevy_prng::ChaCha8Rng;
+    /// use rand_core::SeedableRng;
+    ///
+    /// let mut rng = GlobalEntropy::<ChaCha8Rng>::from_seed([1; 32]);
+    ///
+    /// let gradient = rng.gen_gradient(8);
+    ///
+    /// assert_eq!(gradient.len(), 8);
+    /// ```
+    #[cfg_attr(docsrs, doc(cfg(feature = "color")))]
+    pub fn gen_gradient(&mut self, stops: usize) -> Vec<Color> {
+        let start = Hsv::random(self);
+        let end = Hsv::random(self);
+
+        match stops {
+            0 => Vec::new(),
+            1 => vec![start.to_color()],
+            _ => (0..stops)
+                .map(|stop| {
+                    let t = stop as f32 / (stops - 1) as f32;
+
+                    start.lerp(end, t).to_color()
+                })
+                .collect(),
+        }
+    }
+
+    /// Generates the colour of a blackbody light, with a temperature sampled uniformly within
+    /// the inclusive `kelvin_range`, such as for varying the colour of lights in a plausible
+    /// way. Low temperatures give warm, orange light like candles or sunsets, while high
+    /// temperatures give cool, blue light like an overcast sky, with daylight at around 6500
+    /// kelvin. The temperature is converted to an sRGB colour with a standard curve fit of the
+    /// blackbody spectrum.
+    ///
+    /// For the same RNG state and range, the same colour is always generated.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the range is not within `1000.0..=40000.0`, where the curve fit is accurate,
+    /// or if its minimum is greater than its maximum.
+    ///
+    /// ```
+    /// use bevy_rand::prelude::*;
+    /// use bevy_prng::ChaCha8Rng;
+    /// use rand_core::SeedableRng;
+    ///
+    /// let mut rng = GlobalEntropy::<ChaCha8Rng>::from_seed([1; 32]);
+    ///
+    /// // Warm, incandescent light.
+    /// let light = rng.gen_color_temperature((2500.0, 3500.0));
+    ///
+    /// assert!(light.r() > light.b());
+    /// ```
+    #[cfg_attr(docsrs, doc(cfg(feature = "color")))]
+    pub fn gen_color_temperature(&mut self, kelvin_range: (f32, f32)) -> Color {
+        let (min, max) = kelvin_range;
+
+        assert!(
+            (1000.0..=40000.0).contains(&min) && (1000.0..=40000.0).contains(&max) && min <= max,
+            "gen_color_temperature called with a range outside of `1000.0..=40000.0`"
+        );
+
+        kelvin_to_color(self.gen_range(min..=max))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy_prng::ChaCha8Rng;
+    use rand_core::SeedableRng;
+
+    use super::*;
+
+    fn assert_close(a: Color, b: Color) {
+        let (a, b) = (a.as_rgba_f32(), b.as_rgba_f32());
+
+        assert!(
+            a.iter().zip(b.iter()).all(|(a, b)| (a - b).abs() < 1e-5),
+            "{a:?} should be equal to {b:?}"
+        );
+    }
+
+    #[test]
+    fn gradient_endpoints() {
+        let mut rng = GlobalEntropy::<ChaCha8Rng>::from_seed([7; 32]);
+        let mut expected = GlobalEntropy::<ChaCha8Rng>::from_seed([7; 32]);
+
+        for stops in [2, 3, 16] {
+            let gradient = rng.gen_gradient(stops);
+
+            let start = Hsv::random(&mut expected).to_color();
+            let end = Hsv::random(&mut expected).to_color();
+
+            assert_eq!(gradient.len(), stops);
+            assert_close(gradient[0], start);
+            assert_close(gradient[stops - 1], end);
+        }
+
+        let single = rng.gen_gradient(1);
+        let start = Hsv::random(&mut expected).to_color();
+
+        assert_eq!(single.len(), 1);
+        assert_close(single[0], start);
+        assert!(rng.gen_gradient(0).is_empty());
+    }
+
+    #[test]
+    fn gradient_deterministic() {
+        let mut rng1 = GlobalEntropy::<ChaCha8Rng>::from_seed([7; 32]);
+        let mut rng2 = GlobalEntropy::<ChaCha8Rng>::from_seed([7; 32]);
+
+        assert_eq!(rng1.gen_gradient(10), rng2.gen_gradient(10));
+    }
+
+    #[test]
+    fn hue_takes_shortest_path() {
+        let start = Hsv {
+            hue: 350.0,
+            saturation: 1.0,
+            value: 1.0,
+        };
+        let end = Hsv { hue: 10.0, ..start };
+
+        let middle = start.lerp(end, 0.5);
+
+        assert!(
+            middle.hue < 1e-3 || middle.hue > 360.0 - 1e-3,
+            "hue should wrap around through red, got {}",
+            middle.hue
+        );
+    }
+
+    #[test]
+    fn color_temperature_within_range() {
+        let mut rng = GlobalEntropy::<ChaCha8Rng>::from_seed([7; 32]);
+
+        let (min, max) = (2700.0, 6500.0);
+
+        // Every channel changes monotonically with the temperature within this range, so any
+        // colour of the range lies between the colours of its bounds.
+        let (low, high) = (
+            kelvin_to_color(min).as_rgba_f32(),
+            kelvin_to_color(max).as_rgba_f32(),
+        );
+
+        for _ in 0..100 {
+            let color = rng.gen_color_temperature((min, max)).as_rgba_f32();
+
+            for ((channel, low), high) in color.iter().zip(low).zip(high) {
+                assert!(
+                    (low.min(high) - 1e-5..=low.max(high) + 1e-5).contains(channel),
+                    "{color:?} should lie between {low:?} and {high:?}"
+                );
+            }
+        }
+
+        assert_close(
+            rng.gen_color_temperature((4000.0, 4000.0)),
+            kelvin_to_color(4000.0),
+        );
+    }
+
+    #[test]
+    fn color_temperature_deterministic() {
+        let mut rng1 = GlobalEntropy::<ChaCha8Rng>::from_seed([7; 32]);
+        let mut rng2 = GlobalEntropy::<ChaCha8Rng>::from_seed([7; 32]);
+
+        for _ in 0..10 {
+            assert_eq!(
+                rng1.gen_color_temperature((1000.0, 40000.0)),
+                rng2.gen_color_temperature((1000.0, 40000.0))
+            );
+        }
+    }
+
+    #[test]
+    fn blackbody_colors() {
+        let candle = kelvin_to_color(1900.0);
+        let daylight = kelvin_to_color(6600.0);
+        let sky = kelvin_to_color(15000.0);
+
+        assert!(
+            candle.r() > candle.g() && candle.g() > candle.b(),
+            "low temperatures should be warm"
+        );
+        assert!(
+            daylight.as_rgba_f32()[..3].iter().all(|&c| c > 0.95),
+            "daylight should be close to white"
+        );
+        assert!(sky.b() > sky.r(), "high temperatures should be cool");
+    }
+
+    #[test]
+    #[should_panic]
+    fn color_temperature_out_of_range() {
+        let mut rng = GlobalEntropy::<ChaCha8Rng>::from_seed([7; 32]);
+
+        rng.gen_color_temperature((500.0, 3000.0));
+    }
+}
@@ -0,0 +1,300 @@
+use std::marker::PhantomData;
+
+use crate::{
+    plugin::RngSystemSet,
+    resource::{GlobalEntropy, LazyGlobalEntropy},
+    traits::SeedableEntropySource,
+};
+use bevy::{
+    ecs::system::Command,
+    log::debug,
+    prelude::{App, Commands, First, IntoSystemConfigs, ResMut, Resource, World},
+    reflect::TypePath,
+};
+
+/// Extension methods on [`Commands`] for deferred operations on RNG resources.
+pub trait RngCommandsExt {
+    /// Queues a reseed of the [`GlobalEntropy`] of `R` with `seed`, such as from a plugin that
+    /// runs before [`EntropyPlugin`](crate::plugin::EntropyPlugin) has set the resource up.
+    /// If the resource, or a [`LazyGlobalEntropy`] of `R` in its place, exists once the command
+    /// is applied, it is reseeded right away.
+    /// Otherwise the reseed is held back until the resource appears, and is then applied in
+    /// the [`First`](bevy::app::First) schedule by a system that the
+    /// [`EntropyPlugin`](crate::plugin::EntropyPlugin) of `R` adds. Queuing another reseed
+    /// before then replaces the held back seed. A reseed that is never applied is discarded
+    /// with a debug log when the world is dropped.
+    ///
+    /// ```
+    /// use bevy::prelude::*;
+    /// use bevy_rand::prelude::*;
+    /// use bevy_prng::ChaCha8Rng;
+    ///
+    /// fn load_save(mut commands: Commands) {
+    ///     commands.queue_reseed::<ChaCha8Rng>([7; 32]);
+    /// }
+    ///
+    /// App::new()
+    ///     .add_plugins(EntropyPlugin::<ChaCha8Rng>::with_seed([1; 32]))
+    ///     .add_systems(Startup, load_save);
+    /// ```
+    fn queue_reseed<R: SeedableEntropySource + 'static>(&mut self, seed: R::Seed);
+}
+
+impl RngCommandsExt for Commands<'_, '_> {
+    fn queue_reseed<R: SeedableEntropySource + 'static>(&mut self, mut seed: R::Seed) {
+        self.add(QueuedReseed::<R> {
+            seed: seed.as_mut().into(),
+            _source: PhantomData,
+        });
+    }
+}
+
+/// Returns the seed of `R` stored in `bytes`.
+fn seed_from_bytes<R: SeedableEntropySource>(bytes: &[u8]) -> R::Seed {
+    let mut seed = R::Seed::default();
+
+    seed.as_mut().copy_from_slice(bytes);
+
+    seed
+}
+
+/// The [`Command`] queued by [`RngCommandsExt::queue_reseed`].
+struct QueuedReseed<R: SeedableEntropySource + 'static> {
+    // Stored as bytes, as `R::Seed` is not guaranteed to be `Send + Sync`.
+    seed: Box<[u8]>,
+    _source: PhantomData<fn() -> R>,
+}
+
+impl<R: SeedableEntropySource + 'static> Command for QueuedReseed<R> {
+    fn apply(self, world: &mut World) {
+        if let Some(mut rng) = world.get_resource_mut::<GlobalEntropy<R>>() {
+            rng.reseed(seed_from_bytes::<R>(&self.seed));
+        } else if let Some(mut rng) = world.get_resource_mut::<LazyGlobalEntropy<R>>() {
+            rng.reseed(seed_from_bytes::<R>(&self.seed));
+        } else if let Some(mut pending) = world.get_resource_mut::<PendingReseed<R>>() {
+            pending.seed = Some(self.seed);
+        } else {
+            debug!(
+                "Holding back a reseed of {} until the resource exists.",
+                GlobalEntropy::<R>::short_type_path()
+            );
+
+            world.insert_resource(PendingReseed::<R> {
+                seed: Some(self.seed),
+                _source: PhantomData,
+            });
+        }
+    }
+}
+
+/// A reseed of the [`GlobalEntropy`] of `R` that is held back until the resource exists.
+#[derive(Resource)]
+pub(crate) struct PendingReseed<R: SeedableEntropySource + 'static> {
+    seed: Option<Box<[u8]>>,
+    _source: PhantomData<fn() -> R>,
+}
+
+impl<R: SeedableEntropySource + 'static> Drop for PendingReseed<R> {
+    fn drop(&mut self) {
+        if self.seed.is_some() {
+            debug!(
+                "Discarding a queued reseed of {}, as the resource never appeared.",
+                GlobalEntropy::<R>::short_type_path()
+            );
+        }
+    }
+}
+
+/// Marks that [`apply_pending_reseed`] has been added for `R`, so that it is only added once.
+#[derive(Resource)]
+struct PendingReseedSystem<R: SeedableEntropySource + 'static>(PhantomData<fn() -> R>);
+
+/// Adds [`apply_pending_reseed`] for `R` to the [`First`] schedule, unless it already has been.
+pub(crate) fn add_pending_reseed_system<R: SeedableEntropySource + 'static>(app: &mut App) {
+    if !app.world.contains_resource::<PendingReseedSystem<R>>() {
+        app.insert_resource(PendingReseedSystem::<R>(PhantomData))
+            .add_systems(First, apply_pending_reseed::<R>.in_set(RngSystemSet));
+    }
+}
+
+/// Applies a held back reseed once the [`GlobalEntropy`] of `R`, or a [`LazyGlobalEntropy`] of
+/// `R` in its place, exists.
+fn apply_pending_reseed<R: SeedableEntropySource + 'static>(
+    mut commands: Commands,
+    pending: Option<ResMut<PendingReseed<R>>>,
+    rng: Option<ResMut<GlobalEntropy<R>>>,
+    lazy: Option<ResMut<LazyGlobalEntropy<R>>>,
+) {
+    let Some(mut pending) = pending else {
+        return;
+    };
+
+    if let Some(mut rng) = rng {
+        if let Some(seed) = pending.seed.take() {
+            rng.reseed(seed_from_bytes::<R>(&seed));
+        }
+    } else if let Some(mut rng) = lazy {
+        if let Some(seed) = pending.seed.take() {
+            rng.reseed(seed_from_bytes::<R>(&seed));
+        }
+    } else {
+        return;
+    }
+
+    commands.remove_resource::<PendingReseed<R>>();
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy::prelude::{App, Startup};
+    use bevy_prng::ChaCha8Rng;
+    use rand_core::SeedableRng;
+
+    use super::*;
+    use crate::plugin::EntropyPlugin;
+
+    fn queue_reseed(mut commands: Commands) {
+        commands.queue_reseed::<ChaCha8Rng>([7; 32]);
+    }
+
+    #[test]
+    fn reseeds_existing_resource() {
+        let mut app = App::new();
+
+        app.add_plugins(EntropyPlugin::<ChaCha8Rng>::with_seed([1; 32]))
+            .add_systems(Startup, queue_reseed);
+
+        app.update();
+
+        assert_eq!(
+            app.world.resource::<GlobalEntropy<ChaCha8Rng>>(),
+            &GlobalEntropy::<ChaCha8Rng>::from_seed([7; 32])
+        );
+        assert!(!app.world.contains_resource::<PendingReseed<ChaCha8Rng>>());
+    }
+
+    #[test]
+    fn deferred_until_resource_exists() {
+        let mut app = App::new();
+
+        app.add_plugins(EntropyPlugin::<ChaCha8Rng>::with_seed([1; 32]))
+            .add_systems(Startup, queue_reseed);
+
+        // Simulate the resource not having been set up yet.
+        app.world.remove_resource::<GlobalEntropy<ChaCha8Rng>>();
+
+        app.update();
+
+        assert!(app.world.contains_resource::<PendingReseed<ChaCha8Rng>>());
+
+        app.update();
+
+        assert!(
+            app.world.contains_resource::<PendingReseed<ChaCha8Rng>>(),
+            "the reseed should be held back while the resource is missing"
+        );
+
+        app.insert_resource(GlobalEntropy::<ChaCha8Rng>::from_seed([1; 32]));
+        app.update();
+
+        assert_eq!(
+            app.world.resource::<GlobalEntropy<ChaCha8Rng>>(),
+            &GlobalEntropy::<ChaCha8Rng>::from_seed([7; 32]),
+            "the reseed should apply once the resource exists"
+        );
+        assert!(!app.world.contains_resource::<PendingReseed<ChaCha8Rng>>());
+    }
+
+    #[test]
+    fn applies_to_resource_inserted_later() {
+        let mut app = App::new();
+
+        // The plugin finds an existing resource, and so leaves it as is.
+        app.insert_resource(GlobalEntropy::<ChaCha8Rng>::from_seed([1; 32]))
+            .add_plugins(EntropyPlugin::<ChaCha8Rng>::with_seed([1; 32]));
+
+        app.world.remove_resource::<GlobalEntropy<ChaCha8Rng>>();
+
+        QueuedReseed::<ChaCha8Rng> {
+            seed: [7; 32].into(),
+            _source: PhantomData,
+        }
+        .apply(&mut app.world);
+
+        app.update();
+
+        app.insert_resource(GlobalEntropy::<ChaCha8Rng>::from_seed([1; 32]));
+        app.update();
+
+        assert_eq!(
+            app.world.resource::<GlobalEntropy<ChaCha8Rng>>(),
+            &GlobalEntropy::<ChaCha8Rng>::from_seed([7; 32]),
+            "the reseed should apply to a resource inserted after the plugin was built"
+        );
+        assert!(!app.world.contains_resource::<PendingReseed<ChaCha8Rng>>());
+    }
+
+    #[test]
+    fn applies_to_lazy_resource() {
+        let mut app = App::new();
+
+        QueuedReseed::<ChaCha8Rng> {
+            seed: [7; 32].into(),
+            _source: PhantomData,
+        }
+        .apply(&mut app.world);
+
+        app.add_plugins(EntropyPlugin::<ChaCha8Rng>::lazy());
+        app.update();
+
+        let mut lazy = app.world.resource_mut::<LazyGlobalEntropy<ChaCha8Rng>>();
+
+        assert!(
+            lazy.is_initialized(),
+            "the reseed should seed the lazy resource in place of entropy"
+        );
+        assert_eq!(lazy.get(), &GlobalEntropy::<ChaCha8Rng>::from_seed([7; 32]));
+        assert!(!app.world.contains_resource::<PendingReseed<ChaCha8Rng>>());
+
+        // Once the lazy resource exists, reseeds apply to it right away.
+        QueuedReseed::<ChaCha8Rng> {
+            seed: [8; 32].into(),
+            _source: PhantomData,
+        }
+        .apply(&mut app.world);
+
+        assert_eq!(
+            app.world
+                .resource_mut::<LazyGlobalEntropy<ChaCha8Rng>>()
+                .get(),
+            &GlobalEntropy::<ChaCha8Rng>::from_seed([8; 32])
+        );
+    }
+
+    #[test]
+    fn later_reseeds_replace_pending() {
+        let mut world = World::new();
+
+        QueuedReseed::<ChaCha8Rng> {
+            seed: [2; 32].into(),
+            _source: PhantomData,
+        }
+        .apply(&mut world);
+        QueuedReseed::<ChaCha8Rng> {
+            seed: [3; 32].into(),
+            _source: PhantomData,
+        }
+        .apply(&mut world);
+
+        assert_eq!(
+            world
+                .resource::<PendingReseed<ChaCha8Rng>>()
+                .seed
+                .as_deref(),
+            Some(&[3; 32][..])
+        );
+
+        // Dropping the world discards the reseed that was never applied.
+        drop(world);
+    }
+}
@@ -2,11 +2,15 @@ use std::fmt::Debug;
 
 use crate::{resource::GlobalEntropy, traits::SeedableEntropySource};
 use bevy::prelude::{Component, Mut, Reflect, ReflectComponent, ReflectFromReflect, ResMut};
+use bevy_prng::SeekableRng;
 use rand_core::{RngCore, SeedableRng};
 
-#[cfg(feature = "thread_local_entropy")]
+#[cfg(all(feature = "thread_local_entropy", not(feature = "deterministic_only")))]
 use crate::thread_local_entropy::ThreadLocalEntropy;
 
+#[cfg(feature = "deterministic_only")]
+use crate::seed::deny_entropy_seeding;
+
 #[cfg(feature = "serialize")]
 use bevy::prelude::{ReflectDeserialize, ReflectSerialize};
 
@@ -115,6 +119,29 @@ impl<R: SeedableEntropySource + 'static> EntropyComponent<R> {
     pub fn reseed(&mut self, seed: R::Seed) {
         self.0 = R::from_seed(seed);
     }
+
+    /// Create a new component seeded from any other [`RngCore`] source, such as seeding a
+    /// per-entity PRNG from a [`GlobalEntropy`] of a different algorithm. Unlike the `From<&mut R>`
+    /// impls, the source doesn't have to be the same PRNG type. The source is advanced by the
+    /// length of the seed, so the same source state always produces the same component.
+    ///
+    /// ```
+    /// use bevy_rand::prelude::*;
+    /// use bevy_prng::{ChaCha8Rng, Xoshiro256StarStar};
+    /// use rand_core::SeedableRng;
+    ///
+    /// let mut source = GlobalEntropy::<ChaCha8Rng>::from_seed([1; 32]);
+    ///
+    /// let rng = EntropyComponent::<Xoshiro256StarStar>::from_source(&mut source);
+    /// ```
+    #[must_use]
+    pub fn from_source<S: RngCore>(source: &mut S) -> Self {
+        let mut seed = R::Seed::default();
+
+        source.fill_bytes(seed.as_mut());
+
+        Self::from_seed(seed)
+    }
 }
 
 impl<R: SeedableEntropySource + 'static> Default for EntropyComponent<R> {
@@ -123,6 +150,16 @@ impl<R: SeedableEntropySource + 'static> Default for EntropyComponent<R> {
     }
 }
 
+impl<R: SeedableEntropySource + SeekableRng + 'static> EntropyComponent<R> {
+    /// Returns `true` if both instances will produce the exact same output from their current
+    /// positions onwards, comparing the seed, stream and position of the PRNGs rather than
+    /// their raw state. For the `ChaCha` PRNGs of `bevy_prng`, this agrees with `==`.
+    #[inline]
+    pub fn stream_eq(&self, other: &Self) -> bool {
+        self.0.stream_eq(&other.0)
+    }
+}
+
 impl<R: SeedableEntropySource + 'static> RngCore for EntropyComponent<R> {
     #[inline]
     fn next_u32(&mut self) -> u32 {
@@ -160,8 +197,8 @@ impl<R: SeedableEntropySource + 'static> SeedableRng for EntropyComponent<R> {
     /// # Panics
     ///
     /// If [`ThreadLocalEntropy`] cannot get initialised because `getrandom` is unable to provide secure entropy,
-    /// this method will panic.
-    #[cfg(feature = "thread_local_entropy")]
+    /// and no fallback has been registered via [`crate::set_fallback_seed_source`], this method will panic.
+    #[cfg(all(feature = "thread_local_entropy", not(feature = "deterministic_only")))]
     #[cfg_attr(docsrs, doc(cfg(feature = "thread_local_entropy")))]
     fn from_entropy() -> Self {
         let mut seed = Self::Seed::default();
@@ -173,6 +210,15 @@ impl<R: SeedableEntropySource + 'static> SeedableRng for EntropyComponent<R> {
 
         Self::from_seed(seed)
     }
+
+    /// Panics, as seeding from entropy is forbidden with the `deterministic_only` feature. This
+    /// covers every way of seeding from entropy, such as via `Default` or an unseeded
+    /// [`EntropyPlugin`](crate::plugin::EntropyPlugin).
+    #[cfg(feature = "deterministic_only")]
+    #[track_caller]
+    fn from_entropy() -> Self {
+        deny_entropy_seeding::<Self>()
+    }
 }
 
 impl<R: SeedableEntropySource + 'static> From<R> for EntropyComponent<R> {
@@ -212,7 +258,7 @@ mod tests {
 
     #[test]
     fn forking() {
-        let mut rng1 = EntropyComponent::<ChaCha8Rng>::default();
+        let mut rng1 = EntropyComponent::<ChaCha8Rng>::from_seed([1; 32]);
 
         let rng2 = EntropyComponent::from(&mut rng1);
 
@@ -222,6 +268,44 @@ mod tests {
         );
     }
 
+    #[cfg(feature = "wyrand")]
+    #[test]
+    fn from_global_source() {
+        use bevy_prng::WyRand;
+
+        let mut global1 = GlobalEntropy::<ChaCha8Rng>::from_seed([2; 32]);
+        let mut global2 = GlobalEntropy::<ChaCha8Rng>::from_seed([2; 32]);
+
+        let mut rng1 = EntropyComponent::<WyRand>::from_source(&mut global1);
+        let mut rng2 = EntropyComponent::<WyRand>::from_source(&mut global2);
+
+        assert_eq!(
+            rng1, rng2,
+            "components seeded from the same global source should match"
+        );
+        assert_eq!(rng1.next_u64(), rng2.next_u64());
+    }
+
+    #[test]
+    fn stream_equality() {
+        let mut rng1 = EntropyComponent::<ChaCha8Rng>::from_seed([2; 32]);
+        let mut rng2 = EntropyComponent::<ChaCha8Rng>::from_seed([2; 32]);
+
+        let mut bytes = [0u8; 8];
+
+        // Reach the same stream position through different calls
+        rng1.fill_bytes(&mut bytes);
+        rng2.next_u64();
+
+        assert!(rng1.stream_eq(&rng2));
+        assert_eq!(rng1, rng2, "stream_eq should agree with == for ChaCha");
+
+        rng1.next_u32();
+
+        assert!(!rng1.stream_eq(&rng2));
+        assert_ne!(rng1, rng2, "stream_eq should agree with == for ChaCha");
+    }
+
     #[test]
     fn type_paths() {
         assert_eq!(
@@ -257,10 +341,16 @@ mod tests {
 
         let serialized = to_string(&ser).unwrap();
 
+        #[cfg(not(feature = "human_readable"))]
         assert_eq!(
             &serialized,
             "{\"bevy_rand::component::EntropyComponent<bevy_prng::ChaCha8Rng>\":(((seed:(7,7,7,7,7,7,7,7,7,7,7,7,7,7,7,7,7,7,7,7,7,7,7,7,7,7,7,7,7,7,7,7),stream:0,word_pos:1)))}"
         );
+        #[cfg(feature = "human_readable")]
+        assert_eq!(
+            &serialized,
+            "{\"bevy_rand::component::EntropyComponent<bevy_prng::ChaCha8Rng>\":(((seed:\"0707070707070707070707070707070707070707070707070707070707070707\",stream:0,word_pos:1)))}"
+        );
 
         let mut deserializer = ron::Deserializer::from_str(&serialized).unwrap();
 
@@ -307,10 +397,16 @@ mod tests {
 
         let serialized = to_string(&ser).unwrap();
 
+        #[cfg(not(feature = "human_readable"))]
         assert_eq!(
             &serialized,
             "(((seed:(7,7,7,7,7,7,7,7,7,7,7,7,7,7,7,7,7,7,7,7,7,7,7,7,7,7,7,7,7,7,7,7),stream:0,word_pos:1)))"
         );
+        #[cfg(feature = "human_readable")]
+        assert_eq!(
+            &serialized,
+            "(((seed:\"0707070707070707070707070707070707070707070707070707070707070707\",stream:0,word_pos:1)))"
+        );
 
         let mut deserializer = ron::Deserializer::from_str(&serialized).unwrap();
 
@@ -332,4 +428,145 @@ mod tests {
             "The deserialized EntropyComponent should have the same output as original"
         );
     }
+
+    #[cfg(feature = "serialize")]
+    #[test]
+    fn rng_dynamic_scene_roundtrip() {
+        use crate::plugin::EntropyPlugin;
+        use bevy::{
+            ecs::entity::Entity,
+            prelude::{App, AppTypeRegistry, World},
+            scene::{serde::SceneDeserializer, DynamicScene},
+            utils::HashMap,
+        };
+        use serde::de::DeserializeSeed;
+
+        let mut app = App::new();
+        app.add_plugins(EntropyPlugin::<ChaCha8Rng>::with_seed([1; 32]));
+
+        let registry = app.world.resource::<AppTypeRegistry>().clone();
+
+        let mut world = World::new();
+        world.insert_resource(registry.clone());
+
+        let mut val = EntropyComponent::<ChaCha8Rng>::from_seed([7; 32]);
+
+        // Modify the state of the RNG instance
+        val.next_u32();
+
+        let entity = world.spawn(val.clone()).id();
+
+        let serialized = DynamicScene::from_world(&world)
+            .serialize_ron(&registry)
+            .unwrap();
+
+        let mut deserializer = ron::Deserializer::from_str(&serialized).unwrap();
+
+        let scene = SceneDeserializer {
+            type_registry: &registry.read(),
+        }
+        .deserialize(&mut deserializer)
+        .unwrap();
+
+        let mut new_world = World::new();
+        new_world.insert_resource(registry);
+
+        let mut entity_map: HashMap<Entity, Entity> = HashMap::default();
+
+        scene
+            .write_to_world(&mut new_world, &mut entity_map)
+            .unwrap();
+
+        let mut dynamic = new_world
+            .get::<EntropyComponent<ChaCha8Rng>>(entity_map[&entity])
+            .cloned()
+            .unwrap();
+
+        // The two instances should be the same
+        assert_eq!(
+            val, dynamic,
+            "The EntropyComponent spawned from the scene should equal the original"
+        );
+        // They should output the same numbers, as no state is lost when round-tripping through a scene.
+        assert_eq!(
+            val.next_u32(),
+            dynamic.next_u32(),
+            "The EntropyComponent spawned from the scene should have the same output as original"
+        );
+    }
+
+    #[cfg(feature = "serialize")]
+    #[test]
+    fn rng_replication_payload() {
+        let mut server = EntropyComponent::<ChaCha8Rng>::from_seed([7; 32]);
+
+        server.next_u64();
+        server.next_u32();
+
+        // Replication crates send components over the network as compact binary payloads.
+        let payload = bincode::serialize(&server).unwrap();
+
+        let mut client: EntropyComponent<ChaCha8Rng> = bincode::deserialize(&payload).unwrap();
+
+        assert_eq!(
+            server, client,
+            "The replicated EntropyComponent should equal the original"
+        );
+        assert_eq!(
+            server.next_u64(),
+            client.next_u64(),
+            "The replicated EntropyComponent should have the same output as original"
+        );
+    }
+
+    #[cfg(feature = "human_readable")]
+    #[test]
+    fn rng_human_readable_roundtrip() {
+        let mut val = EntropyComponent::<ChaCha8Rng>::from_seed([0xab; 32]);
+
+        val.next_u32();
+
+        let json = serde_json::to_string(&val).unwrap();
+
+        assert_eq!(
+            json,
+            format!(
+                "{{\"seed\":\"{}\",\"stream\":0,\"word_pos\":1}}",
+                "ab".repeat(32)
+            ),
+            "human-readable formats should write the seed as hex"
+        );
+
+        let mut from_json: EntropyComponent<ChaCha8Rng> = serde_json::from_str(&json).unwrap();
+
+        assert_eq!(val, from_json);
+        assert_eq!(val.clone().next_u64(), from_json.next_u64());
+
+        let binary = bincode::serialize(&val).unwrap();
+
+        assert_eq!(
+            binary.len(),
+            32 + 8 + 16,
+            "binary formats should keep the compact representation"
+        );
+
+        let from_binary: EntropyComponent<ChaCha8Rng> = bincode::deserialize(&binary).unwrap();
+
+        assert_eq!(val, from_binary);
+        assert!(
+            serde_json::from_str::<EntropyComponent<ChaCha8Rng>>(
+                r#"{"seed":"zz","stream":0,"word_pos":1}"#
+            )
+            .is_err(),
+            "invalid hex seeds should be rejected"
+        );
+
+        let legacy = format!("(((seed:({}),stream:0,word_pos:1)))", ["171"; 32].join(","));
+
+        assert_eq!(
+            ron::from_str::<EntropyComponent<ChaCha8Rng>>(&legacy).unwrap(),
+            val,
+            "seeds written as a sequence of bytes should still be accepted"
+        );
+    }
 }
@@ -0,0 +1,984 @@
+use std::f64::consts::{SQRT_2, TAU};
+
+use crate::{resource::GlobalEntropy, traits::SeedableEntropySource};
+use rand::{
+    distributions::{uniform::SampleUniform, Distribution, Uniform},
+    Rng,
+};
+use rand_core::RngCore;
+
+/// Samples a pair of independent standard normal values, via the Box–Muller transform.
+pub(crate) fn standard_normal_pair(rng: &mut impl Rng) -> (f64, f64) {
+    // Flipping the sample from `0.0..1.0` avoids taking the logarithm of zero.
+    let radius = (-2.0 * (1.0 - rng.gen::<f64>()).ln()).sqrt();
+    let (sin, cos) = (TAU * rng.gen::<f64>()).sin_cos();
+
+    (radius * cos, radius * sin)
+}
+
+/// Returns the cumulative distribution function of the standard normal distribution at `z`,
+/// via the Chebyshev fit of the complementary error function from Numerical Recipes, which has
+/// a relative error below `1.2e-7` everywhere.
+fn normal_cdf(z: f64) -> f64 {
+    let x = z.abs() / SQRT_2;
+    let t = 1.0 / (1.0 + 0.5 * x);
+
+    let erfc = t
+        * (-x * x - 1.265_512_23
+            + t * (1.000_023_68
+                + t * (0.374_091_96
+                    + t * (0.096_784_18
+                        + t * (-0.186_288_06
+                            + t * (0.278_868_07
+                                + t * (-1.135_203_98
+                                    + t * (1.488_515_87
+                                        + t * (-0.822_152_23 + t * 0.170_872_77)))))))))
+            .exp();
+
+    if z >= 0.0 {
+        1.0 - 0.5 * erfc
+    } else {
+        0.5 * erfc
+    }
+}
+
+impl<R: SeedableEntropySource + 'static> GlobalEntropy<R> {
+    /// Generates a random `f32` within the inclusive range of `min..=max`.
+    ///
+    /// Unlike sampling from a half-open range, `max` is a possible output, so a range
+    /// of `0.0..=1.0` can return `1.0`. If `min == max`, then `min` is returned without
+    /// advancing the RNG. Very large ranges, up to and including `f32::MIN..=f32::MAX`, are
+    /// supported by sampling across a quarter of the range and then scaling the output back up.
+    /// For normal floats this is exact, as multiplying by a power of two only changes their
+    /// exponent. Subnormal bounds lose precision when scaled down, so the output is clamped to
+    /// the range to never fall outside of it.
+    ///
+    /// # Panics
+    ///
+    /// Panics if either `min` or `max` are not finite, or if `min > max`.
+    ///
+    /// ```
+    /// use bevy_rand::prelude::*;
+    /// use bevy_prng::ChaCha8Rng;
+    /// use rand_core::SeedableRng;
+    ///
+    /// let mut rng = GlobalEntropy::<ChaCha8Rng>::from_seed([1; 32]);
+    ///
+    /// let value = rng.gen_f32_range(-1.0, 1.0);
+    ///
+    /// assert!((-1.0..=1.0).contains(&value));
+    /// ```
+    #[cfg_attr(docsrs, doc(cfg(feature = "rand")))]
+    pub fn gen_f32_range(&mut self, min: f32, max: f32) -> f32 {
+        assert!(
+            min.is_finite() && max.is_finite(),
+            "gen_f32_range called with a non-finite bound"
+        );
+        assert!(min <= max, "gen_f32_range called with `min > max`");
+
+        if min == max {
+            return min;
+        }
+
+        // The span of the range must leave headroom for `Uniform`'s own scaling, else
+        // it will overflow.
+        if max * 0.5 - min * 0.5 <= f32::MAX * 0.25 {
+            Uniform::new_inclusive(min, max).sample(self)
+        } else {
+            (Uniform::new_inclusive(min * 0.25, max * 0.25).sample(self) * 4.0).clamp(min, max)
+        }
+    }
+
+    /// Generates an array of random values within `range`, which can be either a half-open
+    /// `low..high` or inclusive `low..=high` range. The range is only set up once for the whole
+    /// array, making this faster than sampling each value separately. The output is the same as
+    /// sampling a [`Uniform`] of the range for each element in turn, but can differ from calling
+    /// [`Rng::gen_range`](rand::Rng::gen_range) per element, which is optimised for sampling
+    /// single values instead.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the range is empty.
+    ///
+    /// ```
+    /// use bevy_rand::prelude::*;
+    /// use bevy_prng::ChaCha8Rng;
+    /// use rand_core::SeedableRng;
+    ///
+    /// let mut rng = GlobalEntropy::<ChaCha8Rng>::from_seed([1; 32]);
+    ///
+    /// let heights: [u32; 16] = rng.gen_range_array(1..=8);
+    ///
+    /// assert!(heights.iter().all(|height| (1..=8).contains(height)));
+    /// ```
+    #[cfg_attr(docsrs, doc(cfg(feature = "rand")))]
+    pub fn gen_range_array<const N: usize, T: SampleUniform>(
+        &mut self,
+        range: impl Into<Uniform<T>>,
+    ) -> [T; N] {
+        let uniform = range.into();
+
+        std::array::from_fn(|_| uniform.sample(self))
+    }
+
+    /// Generates a random `f64` from a triangular distribution within `min..=max`, with the
+    /// likelihood rising linearly from `min` up to a peak at `mode`, then falling linearly back
+    /// down to `max`. Useful for designer friendly rolls that should be "most likely around"
+    /// some value, without the unbounded tails of a normal distribution. If `min == max`, then
+    /// `min` is returned without advancing the RNG.
+    ///
+    /// # Panics
+    ///
+    /// Panics if any of the bounds are not finite, or if `min <= mode <= max` does not hold.
+    ///
+    /// ```
+    /// use bevy_rand::prelude::*;
+    /// use bevy_prng::ChaCha8Rng;
+    /// use rand_core::SeedableRng;
+    ///
+    /// let mut rng = GlobalEntropy::<ChaCha8Rng>::from_seed([1; 32]);
+    ///
+    /// let damage = rng.gen_triangular(10.0, 15.0, 30.0);
+    ///
+    /// assert!((10.0..=30.0).contains(&damage));
+    /// ```
+    #[cfg_attr(docsrs, doc(cfg(feature = "rand")))]
+    pub fn gen_triangular(&mut self, min: f64, mode: f64, max: f64) -> f64 {
+        assert!(
+            min.is_finite() && mode.is_finite() && max.is_finite(),
+            "gen_triangular called with a non-finite bound"
+        );
+        assert!(
+            min <= mode && mode <= max,
+            "gen_triangular called without `min <= mode <= max`"
+        );
+
+        if min == max {
+            return min;
+        }
+
+        let range = max - min;
+        let split = (mode - min) / range;
+
+        // Invert the CDF, which is quadratic on either side of the mode.
+        let u: f64 = self.gen();
+
+        let value = if u < split {
+            min + (u * range * (mode - min)).sqrt()
+        } else {
+            max - ((1.0 - u) * range * (max - mode)).sqrt()
+        };
+
+        value.clamp(min, max)
+    }
+
+    /// Generates a random bitmask with each of the low `bits` bits being set with a probability
+    /// of `density`, and all higher bits being left unset, such as for fuzzing combinations of
+    /// feature flags. `bits` is clamped to at most `64`. A `density` of `0.0` always produces an
+    /// empty mask, and `1.0` always sets all of the low `bits` bits.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `density` is not within `0.0..=1.0`.
+    ///
+    /// ```
+    /// use bevy_rand::prelude::*;
+    /// use bevy_prng::ChaCha8Rng;
+    /// use rand_core::SeedableRng;
+    ///
+    /// let mut rng = GlobalEntropy::<ChaCha8Rng>::from_seed([1; 32]);
+    ///
+    /// let flags = rng.gen_mask(12, 0.25);
+    ///
+    /// assert_eq!(flags >> 12, 0);
+    /// ```
+    #[cfg_attr(docsrs, doc(cfg(feature = "rand")))]
+    pub fn gen_mask(&mut self, bits: u32, density: f64) -> u64 {
+        assert!(
+            (0.0..=1.0).contains(&density),
+            "gen_mask called with a density outside of `0.0..=1.0`"
+        );
+
+        (0..bits.min(u64::BITS)).fold(0, |mask, bit| {
+            mask | (u64::from(self.gen_bool(density)) << bit)
+        })
+    }
+
+    /// Scatters resources over a grid of `width` by `height` cells, such as for placing ore or
+    /// foliage on a tile map, returning the `(x, y)` coordinates of the occupied cells. Each
+    /// cell is occupied independently with a probability of `density`, and cells are visited
+    /// and returned in row-major order, so for the same RNG state and grid size, the same cells
+    /// are always occupied.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `density` is not within `0.0..=1.0`.
+    ///
+    /// ```
+    /// use bevy_rand::prelude::*;
+    /// use bevy_prng::ChaCha8Rng;
+    /// use rand_core::SeedableRng;
+    ///
+    /// let mut rng = GlobalEntropy::<ChaCha8Rng>::from_seed([1; 32]);
+    ///
+    /// let ore = rng.scatter_into_grid(64, 32, 0.05);
+    ///
+    /// assert!(ore.iter().all(|&(x, y)| x < 64 && y < 32));
+    /// ```
+    #[cfg_attr(docsrs, doc(cfg(feature = "rand")))]
+    pub fn scatter_into_grid(
+        &mut self,
+        width: usize,
+        height: usize,
+        density: f64,
+    ) -> Vec<(usize, usize)> {
+        assert!(
+            (0.0..=1.0).contains(&density),
+            "scatter_into_grid called with a density outside of `0.0..=1.0`"
+        );
+
+        (0..height)
+            .flat_map(|y| (0..width).map(move |x| (x, y)))
+            .filter(|_| self.gen_bool(density))
+            .collect()
+    }
+
+    /// Generates a bitmask of `len` bits with each bit being set with a probability of
+    /// `density`, such as for fog-of-war or procedural masks too long for
+    /// [`GlobalEntropy::gen_mask`]. A `density` of `0.0` always produces an empty mask, and
+    /// `1.0` always sets every bit. For the same RNG state, the same mask is always generated.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `density` is not within `0.0..=1.0`.
+    ///
+    /// ```
+    /// use bevy_rand::prelude::*;
+    /// use bevy_prng::ChaCha8Rng;
+    /// use rand_core::SeedableRng;
+    ///
+    /// let mut rng = GlobalEntropy::<ChaCha8Rng>::from_seed([1; 32]);
+    ///
+    /// let fog = rng.gen_bitvec(1000, 0.3);
+    ///
+    /// assert_eq!(fog.len(), 1000);
+    /// ```
+    #[cfg(feature = "bitvec")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "bitvec")))]
+    pub fn gen_bitvec(&mut self, len: usize, density: f64) -> bitvec::vec::BitVec {
+        assert!(
+            (0.0..=1.0).contains(&density),
+            "gen_bitvec called with a density outside of `0.0..=1.0`"
+        );
+
+        (0..len).map(|_| self.gen_bool(density)).collect()
+    }
+
+    /// Splits `total` into `parts` non-negative integers that sum to `total`, with every such
+    /// split being equally likely, such as for randomly allocating resources between regions.
+    /// The split is sampled with the "stars and bars" method, by choosing the positions of
+    /// `parts - 1` bars among `total + parts - 1` slots, with the parts being the counts of
+    /// stars between consecutive bars. Returns an empty `Vec` if both `total` and `parts` are
+    /// zero.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `parts` is zero while `total` is not, as there is nothing to split `total` into.
+    ///
+    /// ```
+    /// use bevy_rand::prelude::*;
+    /// use bevy_prng::ChaCha8Rng;
+    /// use rand_core::SeedableRng;
+    ///
+    /// let mut rng = GlobalEntropy::<ChaCha8Rng>::from_seed([1; 32]);
+    ///
+    /// let ore = rng.gen_composition(100, 4);
+    ///
+    /// assert_eq!(ore.iter().sum::<u32>(), 100);
+    /// ```
+    #[cfg_attr(docsrs, doc(cfg(feature = "rand")))]
+    pub fn gen_composition(&mut self, total: u32, parts: usize) -> Vec<u32> {
+        if parts == 0 {
+            assert_eq!(total, 0, "gen_composition called with zero parts");
+
+            return Vec::new();
+        }
+
+        let slots = total as usize + parts - 1;
+
+        let mut bars = rand::seq::index::sample(self, slots, parts - 1).into_vec();
+        bars.sort_unstable();
+
+        // Every part is the amount of stars between the previous bar and the next one.
+        let mut previous = 0;
+        let mut composition: Vec<u32> = bars
+            .iter()
+            .map(|&bar| {
+                let part = (bar - previous) as u32;
+                previous = bar + 1;
+                part
+            })
+            .collect();
+
+        composition.push((slots - previous) as u32);
+
+        composition
+    }
+
+    /// Generates a random `f64` within the inclusive range of `min..=max`, in a way that is
+    /// bit-identical across all platforms, for cases like cross-play where even the slightest
+    /// difference in output between architectures breaks determinism.
+    ///
+    /// The unit value is constructed purely from integer operations: the top 52 bits of a `u64`
+    /// of output are placed into the mantissa of a float with an exponent of zero, producing a
+    /// value within `1.0..2.0`, from which `1.0` is then subtracted exactly. The unit value `u`
+    /// is then mapped onto the range as `min * (1.0 - u) + max * u`, which only uses the basic
+    /// IEEE-754 operations of subtraction, multiplication and addition. These are correctly rounded
+    /// by the standard, so produce the same bits on every conforming target, and are never fused
+    /// into FMA instructions by the compiler. No transcendental functions are used. The result is
+    /// finally clamped to the range, as rounding can overshoot either bound by one ULP, which is
+    /// also why `max` is a possible output. This form can't overflow, so supports ranges up to
+    /// and including `f64::MIN..=f64::MAX`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if either `min` or `max` are not finite, or if `min > max`.
+    ///
+    /// ```
+    /// use bevy_rand::prelude::*;
+    /// use bevy_prng::ChaCha8Rng;
+    /// use rand_core::SeedableRng;
+    ///
+    /// let mut rng = GlobalEntropy::<ChaCha8Rng>::from_seed([1; 32]);
+    ///
+    /// let value = rng.gen_f64_deterministic(-1.0, 1.0);
+    ///
+    /// assert!((-1.0..=1.0).contains(&value));
+    /// ```
+    #[cfg_attr(docsrs, doc(cfg(feature = "rand")))]
+    pub fn gen_f64_deterministic(&mut self, min: f64, max: f64) -> f64 {
+        assert!(
+            min.is_finite() && max.is_finite(),
+            "gen_f64_deterministic called with a non-finite bound"
+        );
+        assert!(min <= max, "gen_f64_deterministic called with `min > max`");
+
+        let unit = f64::from_bits(0x3ff0_0000_0000_0000 | (self.next_u64() >> 12)) - 1.0;
+
+        (min * (1.0 - unit) + max * unit).clamp(min, max)
+    }
+
+    /// Generates `n` non-negative weights that sum to `1.0`, as a uniformly random point on the
+    /// simplex, such as for randomised blending weights. The weights are sampled from a
+    /// symmetric Dirichlet distribution by normalising exponentially distributed values, as
+    /// naively normalising uniform values is biased towards evenly spread weights. Returns an
+    /// empty `Vec` if `n` is zero.
+    ///
+    /// ```
+    /// use bevy_rand::prelude::*;
+    /// use bevy_prng::ChaCha8Rng;
+    /// use rand_core::SeedableRng;
+    ///
+    /// let mut rng = GlobalEntropy::<ChaCha8Rng>::from_seed([1; 32]);
+    ///
+    /// let weights = rng.gen_simplex(3);
+    ///
+    /// assert!((weights.iter().sum::<f32>() - 1.0).abs() < 1e-5);
+    /// ```
+    #[cfg_attr(docsrs, doc(cfg(feature = "rand")))]
+    pub fn gen_simplex(&mut self, n: usize) -> Vec<f32> {
+        // Sampling from `0.0..1.0` and flipping it avoids taking the logarithm of zero.
+        let mut weights: Vec<f32> = (0..n).map(|_| -(1.0 - self.gen::<f32>()).ln()).collect();
+
+        let sum: f32 = weights.iter().sum();
+
+        if sum > 0.0 {
+            weights.iter_mut().for_each(|weight| *weight /= sum);
+        } else {
+            // Only when every sampled value was zero, so fall back to the centre of the simplex.
+            weights.fill(1.0 / n as f32);
+        }
+
+        weights
+    }
+
+    /// Generates a stat by summing `dice` rolls of a die with `sides` sides, such as `3d6` for
+    /// RPG ability scores. Summing several rolls clusters the results around the mean in an
+    /// approximation of a normal distribution, becoming more bell-shaped as `dice` increases,
+    /// while staying within `dice..=dice * sides`. Returns `0` if `dice` is zero.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `sides` is zero, or if `dice * sides` overflows a `u32`.
+    ///
+    /// ```
+    /// use bevy_rand::prelude::*;
+    /// use bevy_prng::ChaCha8Rng;
+    /// use rand_core::SeedableRng;
+    ///
+    /// let mut rng = GlobalEntropy::<ChaCha8Rng>::from_seed([1; 32]);
+    ///
+    /// let strength = rng.gen_stat(3, 6);
+    ///
+    /// assert!((3..=18).contains(&strength));
+    /// ```
+    #[cfg_attr(docsrs, doc(cfg(feature = "rand")))]
+    pub fn gen_stat(&mut self, dice: u32, sides: u32) -> u32 {
+        assert!(sides > 0, "gen_stat called with dice of zero sides");
+        assert!(
+            dice.checked_mul(sides).is_some(),
+            "gen_stat called with `dice * sides` overflowing a `u32`"
+        );
+
+        (0..dice).map(|_| self.gen_range(1..=sides)).sum()
+    }
+
+    /// Generates a pair of uniform values within `0.0..1.0` that are correlated with each
+    /// other via a Gaussian copula, such as for attributes like strength and size that tend to
+    /// go together. A pair of standard normal values with a correlation of `rho` is sampled and
+    /// then mapped through the normal CDF, so each value on its own is still uniform. A `rho`
+    /// of `0.0` produces independent values, `1.0` produces equal values, and negative values
+    /// of `rho` make one value tend to be high when the other is low.
+    ///
+    /// The copula preserves the ranks of the normal values rather than their exact correlation,
+    /// so the correlation of the uniform values is `(6 / π) * asin(rho / 2)`, which is slightly
+    /// weaker than `rho` itself, such as `0.68` for a `rho` of `0.7`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `rho` is not within `-1.0..=1.0`.
+    ///
+    /// ```
+    /// use bevy_rand::prelude::*;
+    /// use bevy_prng::ChaCha8Rng;
+    /// use rand_core::SeedableRng;
+    ///
+    /// let mut rng = GlobalEntropy::<ChaCha8Rng>::from_seed([1; 32]);
+    ///
+    /// let (strength, size) = rng.gen_correlated_pair(0.8);
+    ///
+    /// assert!((0.0..1.0).contains(&strength) && (0.0..1.0).contains(&size));
+    /// ```
+    #[cfg_attr(docsrs, doc(cfg(feature = "rand")))]
+    pub fn gen_correlated_pair(&mut self, rho: f64) -> (f64, f64) {
+        /// The largest `f64` below `1.0`, for keeping values within the half-open range.
+        const BELOW_ONE: f64 = 1.0 - f64::EPSILON / 2.0;
+
+        assert!(
+            (-1.0..=1.0).contains(&rho),
+            "gen_correlated_pair called with a correlation outside of `-1.0..=1.0`"
+        );
+
+        let (a, b) = standard_normal_pair(self);
+        let correlated = rho * a + (1.0 - rho * rho).sqrt() * b;
+
+        (
+            normal_cdf(a).min(BELOW_ONE),
+            normal_cdf(correlated).min(BELOW_ONE),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy_prng::ChaCha8Rng;
+    use rand_core::{RngCore, SeedableRng};
+
+    use super::*;
+
+    #[test]
+    fn f32_range_equal_bounds() {
+        let mut rng = GlobalEntropy::<ChaCha8Rng>::from_seed([7; 32]);
+        let untouched = rng.clone();
+
+        assert_eq!(rng.gen_f32_range(3.5, 3.5), 3.5);
+        assert_eq!(
+            rng, untouched,
+            "a range of a single value should not advance the RNG"
+        );
+    }
+
+    #[test]
+    fn f32_range_within_bounds() {
+        let mut rng = GlobalEntropy::<ChaCha8Rng>::from_seed([7; 32]);
+
+        for _ in 0..1000 {
+            let value = rng.gen_f32_range(-0.5, 0.25);
+
+            assert!((-0.5..=0.25).contains(&value), "{value} is out of range");
+        }
+    }
+
+    #[test]
+    fn f32_range_full_range() {
+        let mut rng = GlobalEntropy::<ChaCha8Rng>::from_seed([7; 32]);
+
+        for _ in 0..1000 {
+            let value = rng.gen_f32_range(f32::MIN, f32::MAX);
+
+            assert!(value.is_finite(), "{value} should be finite");
+        }
+
+        // The subnormal bound rounds to zero when scaled down, which must not be returned.
+        let min = f32::from_bits(1);
+
+        for _ in 0..1000 {
+            let value = rng.gen_f32_range(min, f32::MAX);
+
+            assert!(value >= min, "{value} is below the subnormal bound");
+        }
+    }
+
+    #[test]
+    fn f32_range_deterministic() {
+        let mut rng1 = GlobalEntropy::<ChaCha8Rng>::from_seed([7; 32]);
+        let mut rng2 = GlobalEntropy::<ChaCha8Rng>::from_seed([7; 32]);
+
+        for _ in 0..10 {
+            assert_eq!(
+                rng1.gen_f32_range(0.0, 100.0).to_bits(),
+                rng2.gen_f32_range(0.0, 100.0).to_bits()
+            );
+        }
+
+        assert_eq!(rng1.next_u32(), rng2.next_u32());
+    }
+
+    #[test]
+    fn range_array_matches_loop() {
+        let mut rng1 = GlobalEntropy::<ChaCha8Rng>::from_seed([7; 32]);
+        let mut rng2 = GlobalEntropy::<ChaCha8Rng>::from_seed([7; 32]);
+
+        let values: [i32; 64] = rng1.gen_range_array(-10..10);
+
+        let uniform = Uniform::new(-10, 10);
+
+        for value in values {
+            assert_eq!(value, uniform.sample(&mut rng2));
+        }
+
+        let values: [f32; 8] = rng1.gen_range_array(0.0..=1.0);
+
+        let uniform = Uniform::new_inclusive(0.0, 1.0);
+
+        for value in values {
+            assert_eq!(
+                value,
+                uniform.sample(&mut rng2),
+                "inclusive ranges should match as well"
+            );
+        }
+    }
+
+    #[test]
+    fn range_array_within_range() {
+        let mut rng = GlobalEntropy::<ChaCha8Rng>::from_seed([7; 32]);
+
+        let values: [u8; 256] = rng.gen_range_array(3..=5);
+
+        assert!(values.iter().all(|value| (3..=5).contains(value)));
+
+        let empty: [u8; 0] = rng.gen_range_array(0..1);
+
+        assert!(empty.is_empty());
+    }
+
+    #[test]
+    fn triangular_within_bounds() {
+        let mut rng = GlobalEntropy::<ChaCha8Rng>::from_seed([7; 32]);
+
+        for _ in 0..1000 {
+            let value = rng.gen_triangular(-2.0, 1.0, 4.0);
+
+            assert!((-2.0..=4.0).contains(&value), "{value} is out of bounds");
+        }
+
+        // The mode can sit on either bound
+        for _ in 0..100 {
+            assert!((0.0..=1.0).contains(&rng.gen_triangular(0.0, 0.0, 1.0)));
+            assert!((0.0..=1.0).contains(&rng.gen_triangular(0.0, 1.0, 1.0)));
+        }
+
+        assert_eq!(rng.gen_triangular(3.0, 3.0, 3.0), 3.0);
+    }
+
+    #[test]
+    fn triangular_favours_mode() {
+        let mut rng = GlobalEntropy::<ChaCha8Rng>::from_seed([7; 32]);
+
+        let samples = 10_000;
+
+        // The middle third around a centred mode holds 5/9 of the probability mass, whereas a
+        // uniform distribution would only hold 1/3.
+        let middle = (0..samples)
+            .map(|_| rng.gen_triangular(0.0, 3.0, 6.0))
+            .filter(|value| (2.0..4.0).contains(value))
+            .count();
+
+        assert!(
+            (5_200..5_900).contains(&middle),
+            "the region around the mode should be favoured: {middle}"
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn triangular_invalid_mode() {
+        let mut rng = GlobalEntropy::<ChaCha8Rng>::from_seed([7; 32]);
+
+        rng.gen_triangular(0.0, 2.0, 1.0);
+    }
+
+    #[test]
+    fn mask_extreme_densities() {
+        let mut rng = GlobalEntropy::<ChaCha8Rng>::from_seed([7; 32]);
+
+        for (bits, full) in [
+            (0, 0),
+            (1, 1),
+            (17, 0x1_ffff),
+            (64, u64::MAX),
+            (100, u64::MAX),
+        ] {
+            assert_eq!(rng.gen_mask(bits, 0.0), 0);
+            assert_eq!(
+                rng.gen_mask(bits, 1.0),
+                full,
+                "a density of 1.0 should set all {bits} low bits"
+            );
+        }
+    }
+
+    #[test]
+    fn mask_density() {
+        let mut rng = GlobalEntropy::<ChaCha8Rng>::from_seed([7; 32]);
+
+        let set: u32 = (0..1000).map(|_| rng.gen_mask(40, 0.25).count_ones()).sum();
+
+        // 1000 masks of 40 bits should have close to 10,000 bits set
+        assert!(
+            (9_500..10_500).contains(&set),
+            "the mask should follow the density: {set}"
+        );
+
+        let mut rng1 = GlobalEntropy::<ChaCha8Rng>::from_seed([7; 32]);
+        let mut rng2 = GlobalEntropy::<ChaCha8Rng>::from_seed([7; 32]);
+
+        assert_eq!(rng1.gen_mask(64, 0.5), rng2.gen_mask(64, 0.5));
+        assert_eq!(
+            rng1.gen_mask(10, 0.9) >> 10,
+            0,
+            "only the low bits should be set"
+        );
+    }
+
+    #[test]
+    fn scatter_follows_density() {
+        let mut rng = GlobalEntropy::<ChaCha8Rng>::from_seed([7; 32]);
+
+        let (width, height) = (200, 50);
+
+        let cells = rng.scatter_into_grid(width, height, 0.2);
+
+        // 10,000 cells should have close to 2,000 occupied.
+        assert!(
+            (1_850..2_150).contains(&cells.len()),
+            "the grid should follow the density: {}",
+            cells.len()
+        );
+        assert!(cells.iter().all(|&(x, y)| x < width && y < height));
+        assert!(
+            cells
+                .windows(2)
+                .all(|pair| (pair[0].1, pair[0].0) < (pair[1].1, pair[1].0)),
+            "cells should be unique and in row-major order"
+        );
+
+        assert!(rng.scatter_into_grid(10, 10, 0.0).is_empty());
+        assert_eq!(rng.scatter_into_grid(10, 10, 1.0).len(), 100);
+        assert!(rng.scatter_into_grid(0, 10, 1.0).is_empty());
+    }
+
+    #[test]
+    fn scatter_deterministic() {
+        let mut rng1 = GlobalEntropy::<ChaCha8Rng>::from_seed([7; 32]);
+        let mut rng2 = GlobalEntropy::<ChaCha8Rng>::from_seed([7; 32]);
+
+        assert_eq!(
+            rng1.scatter_into_grid(32, 32, 0.3),
+            rng2.scatter_into_grid(32, 32, 0.3)
+        );
+    }
+
+    #[cfg(feature = "bitvec")]
+    #[test]
+    fn bitvec_density() {
+        let mut rng = GlobalEntropy::<ChaCha8Rng>::from_seed([7; 32]);
+
+        for len in [0, 1, 63, 64, 65, 1000] {
+            assert_eq!(rng.gen_bitvec(len, 0.5).len(), len);
+        }
+
+        let mask = rng.gen_bitvec(10_000, 0.3);
+        let density = mask.count_ones() as f64 / 10_000.0;
+
+        assert!(
+            (density - 0.3).abs() < 0.02,
+            "bits should be set at the given density: {density}"
+        );
+        assert!(rng.gen_bitvec(500, 0.0).not_any());
+        assert!(rng.gen_bitvec(500, 1.0).all());
+    }
+
+    #[cfg(feature = "bitvec")]
+    #[test]
+    fn bitvec_deterministic() {
+        let mut rng1 = GlobalEntropy::<ChaCha8Rng>::from_seed([7; 32]);
+        let mut rng2 = GlobalEntropy::<ChaCha8Rng>::from_seed([7; 32]);
+
+        assert_eq!(rng1.gen_bitvec(300, 0.5), rng2.gen_bitvec(300, 0.5));
+    }
+
+    #[test]
+    fn composition_sums_to_total() {
+        let mut rng = GlobalEntropy::<ChaCha8Rng>::from_seed([7; 32]);
+
+        for (total, parts) in [(0, 1), (0, 5), (1, 1), (10, 3), (100, 7), (3, 10)] {
+            let composition = rng.gen_composition(total, parts);
+
+            assert_eq!(composition.len(), parts);
+            assert_eq!(composition.iter().sum::<u32>(), total);
+        }
+
+        assert!(rng.gen_composition(0, 0).is_empty());
+    }
+
+    #[test]
+    fn composition_uniform() {
+        let mut rng = GlobalEntropy::<ChaCha8Rng>::from_seed([7; 32]);
+
+        // There are 6 ways of splitting 2 into 3 parts, which should all be equally likely.
+        let mut counts = std::collections::HashMap::new();
+
+        for _ in 0..6000 {
+            *counts.entry(rng.gen_composition(2, 3)).or_insert(0) += 1;
+        }
+
+        assert_eq!(counts.len(), 6, "every composition should be sampled");
+        assert!(
+            counts.values().all(|&count| (850..1150).contains(&count)),
+            "compositions should be equally likely: {counts:?}"
+        );
+    }
+
+    #[test]
+    fn composition_deterministic() {
+        let mut rng1 = GlobalEntropy::<ChaCha8Rng>::from_seed([7; 32]);
+        let mut rng2 = GlobalEntropy::<ChaCha8Rng>::from_seed([7; 32]);
+
+        assert_eq!(rng1.gen_composition(50, 6), rng2.gen_composition(50, 6));
+    }
+
+    #[test]
+    #[should_panic]
+    fn composition_zero_parts() {
+        let mut rng = GlobalEntropy::<ChaCha8Rng>::from_seed([7; 32]);
+
+        rng.gen_composition(5, 0);
+    }
+
+    #[test]
+    fn f64_deterministic_golden() {
+        let mut rng = GlobalEntropy::<ChaCha8Rng>::from_seed([7; 32]);
+
+        let bits: Vec<u64> = (0..4)
+            .map(|_| rng.gen_f64_deterministic(-10.0, 25.0).to_bits())
+            .collect();
+
+        assert_eq!(
+            bits,
+            [
+                0xc001_8662_1ab5_c916,
+                0x4033_1d86_981e_dfac,
+                0x3fc6_ff14_5652_a640,
+                0x4003_16e5_f390_ca78,
+            ],
+            "output should match the golden bit patterns"
+        );
+    }
+
+    #[test]
+    fn f64_deterministic_bounds() {
+        let mut rng = GlobalEntropy::<ChaCha8Rng>::from_seed([7; 32]);
+
+        for _ in 0..1000 {
+            let value = rng.gen_f64_deterministic(f64::MIN, f64::MAX);
+
+            assert!(value.is_finite(), "the full range should not overflow");
+        }
+
+        for _ in 0..1000 {
+            assert!((0.5..=0.75).contains(&rng.gen_f64_deterministic(0.5, 0.75)));
+        }
+
+        assert_eq!(rng.gen_f64_deterministic(2.0, 2.0), 2.0);
+    }
+
+    #[test]
+    fn simplex_sums_to_one() {
+        let mut rng = GlobalEntropy::<ChaCha8Rng>::from_seed([4; 32]);
+
+        for n in [1, 2, 5, 64] {
+            let weights = rng.gen_simplex(n);
+
+            assert_eq!(weights.len(), n);
+            assert!(weights.iter().all(|&weight| weight >= 0.0));
+            assert!(
+                (weights.iter().sum::<f32>() - 1.0).abs() < 1e-5,
+                "the weights should sum to 1.0"
+            );
+        }
+
+        assert!(rng.gen_simplex(0).is_empty());
+    }
+
+    #[test]
+    fn simplex_deterministic() {
+        let mut rng1 = GlobalEntropy::<ChaCha8Rng>::from_seed([4; 32]);
+        let mut rng2 = GlobalEntropy::<ChaCha8Rng>::from_seed([4; 32]);
+
+        assert_eq!(rng1.gen_simplex(8), rng2.gen_simplex(8));
+    }
+
+    #[test]
+    fn stat_within_range() {
+        let mut rng = GlobalEntropy::<ChaCha8Rng>::from_seed([4; 32]);
+
+        for (dice, sides) in [(1, 1), (1, 20), (3, 6), (10, 4)] {
+            for _ in 0..1000 {
+                let stat = rng.gen_stat(dice, sides);
+
+                assert!(
+                    (dice..=dice * sides).contains(&stat),
+                    "{dice}d{sides} rolled {stat}"
+                );
+            }
+        }
+
+        assert_eq!(rng.gen_stat(0, 6), 0);
+    }
+
+    #[test]
+    fn stat_golden() {
+        let mut rng = GlobalEntropy::<ChaCha8Rng>::from_seed([4; 32]);
+
+        let stats: Vec<u32> = (0..6).map(|_| rng.gen_stat(3, 6)).collect();
+
+        assert_eq!(
+            stats,
+            [11, 12, 6, 11, 8, 17],
+            "output should match the golden sums"
+        );
+    }
+
+    #[test]
+    fn stat_clusters_around_mean() {
+        let mut rng = GlobalEntropy::<ChaCha8Rng>::from_seed([4; 32]);
+
+        let stats: Vec<u32> = (0..10_000).map(|_| rng.gen_stat(3, 6)).collect();
+
+        let middle = stats
+            .iter()
+            .filter(|&&stat| (9..=12).contains(&stat))
+            .count();
+        let extremes = stats
+            .iter()
+            .filter(|&&stat| stat == 3 || stat == 18)
+            .count();
+
+        assert!(
+            middle > extremes * 10,
+            "sums should cluster around the mean: {middle} in the middle, {extremes} at the extremes"
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn stat_zero_sides() {
+        let mut rng = GlobalEntropy::<ChaCha8Rng>::from_seed([4; 32]);
+
+        rng.gen_stat(2, 0);
+    }
+
+    fn correlation(pairs: &[(f64, f64)]) -> f64 {
+        let len = pairs.len() as f64;
+        let (mean_a, mean_b) = pairs
+            .iter()
+            .fold((0.0, 0.0), |(a, b), &(x, y)| (a + x / len, b + y / len));
+
+        let (covariance, variance_a, variance_b) =
+            pairs.iter().fold((0.0, 0.0, 0.0), |(c, va, vb), &(x, y)| {
+                let (dx, dy) = (x - mean_a, y - mean_b);
+
+                (c + dx * dy, va + dx * dx, vb + dy * dy)
+            });
+
+        covariance / (variance_a * variance_b).sqrt()
+    }
+
+    #[test]
+    fn correlated_pairs_follow_rho() {
+        let mut rng = GlobalEntropy::<ChaCha8Rng>::from_seed([4; 32]);
+
+        for rho in [-0.9, -0.3, 0.0, 0.5, 0.8, 1.0] {
+            let pairs: Vec<(f64, f64)> =
+                (0..20_000).map(|_| rng.gen_correlated_pair(rho)).collect();
+
+            assert!(pairs
+                .iter()
+                .all(|&(a, b)| (0.0..1.0).contains(&a) && (0.0..1.0).contains(&b)));
+
+            let expected = 6.0 / std::f64::consts::PI * (rho / 2.0).asin();
+            let actual = correlation(&pairs);
+
+            assert!(
+                (actual - expected).abs() < 0.03,
+                "a rho of {rho} gave a correlation of {actual}, expected about {expected}"
+            );
+        }
+    }
+
+    #[test]
+    fn correlated_pairs_deterministic() {
+        let mut rng1 = GlobalEntropy::<ChaCha8Rng>::from_seed([4; 32]);
+        let mut rng2 = GlobalEntropy::<ChaCha8Rng>::from_seed([4; 32]);
+
+        for _ in 0..10 {
+            assert_eq!(rng1.gen_correlated_pair(0.6), rng2.gen_correlated_pair(0.6));
+        }
+    }
+
+    #[test]
+    fn normal_cdf_accuracy() {
+        for (z, expected) in [
+            (0.0, 0.5),
+            (1.0, 0.841_344_746),
+            (-1.96, 0.024_997_895),
+            (3.0, 0.998_650_102),
+        ] {
+            assert!(
+                (normal_cdf(z) - expected).abs() < 1e-7,
+                "the cdf at {z} should be {expected}, got {}",
+                normal_cdf(z)
+            );
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn correlated_pair_invalid_rho() {
+        let mut rng = GlobalEntropy::<ChaCha8Rng>::from_seed([4; 32]);
+
+        rng.gen_correlated_pair(1.5);
+    }
+}
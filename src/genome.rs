@@ -0,0 +1,280 @@
+use std::fmt;
+
+use crate::{
+    distributions::standard_normal_pair, resource::GlobalEntropy, traits::SeedableEntropySource,
+};
+use rand::Rng;
+
+/// The strategies for recombining two parent genomes with [`GlobalEntropy::crossover`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum CrossoverStrategy {
+    /// Splits both parents at a single random point, with the child taking the genes before
+    /// that point from the first parent, and the genes after it from the second parent. Keeps
+    /// runs of neighbouring genes together.
+    SinglePoint,
+    /// Takes every gene of the child from either parent with equal probability, independently
+    /// of the other genes.
+    Uniform,
+}
+
+/// The error returned by [`GlobalEntropy::crossover`] when the two parent genomes differ in
+/// length, so their genes can't be matched up.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct GenomeLengthError {
+    /// The length of the first parent genome.
+    pub a: usize,
+    /// The length of the second parent genome.
+    pub b: usize,
+}
+
+impl fmt::Display for GenomeLengthError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "genomes of {} and {} genes can't be crossed over",
+            self.a, self.b
+        )
+    }
+}
+
+impl std::error::Error for GenomeLengthError {}
+
+impl<R: SeedableEntropySource + 'static> GlobalEntropy<R> {
+    /// Mutates the genes of `genome` in place, such as for evolving the parameters of agents
+    /// with a genetic algorithm. Each gene is mutated independently with a probability of
+    /// `rate`, by adding a normally distributed perturbation with a standard deviation of
+    /// `strength`, and then clamping it to `0.0..=1.0`. Genes that are not mutated are left
+    /// as they are. For the same RNG state and genome, the same mutations are always applied.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `rate` is not within `0.0..=1.0`, or if `strength` is negative or not finite.
+    ///
+    /// ```
+    /// use bevy_rand::prelude::*;
+    /// use bevy_prng::ChaCha8Rng;
+    /// use rand_core::SeedableRng;
+    ///
+    /// let mut rng = GlobalEntropy::<ChaCha8Rng>::from_seed([1; 32]);
+    ///
+    /// let mut genome = [0.2, 0.5, 0.9, 0.1];
+    ///
+    /// rng.mutate_genome(&mut genome, 0.25, 0.1);
+    ///
+    /// assert!(genome.iter().all(|gene| (0.0..=1.0).contains(gene)));
+    /// ```
+    #[cfg_attr(docsrs, doc(cfg(feature = "rand")))]
+    pub fn mutate_genome(&mut self, genome: &mut [f32], rate: f64, strength: f32) {
+        assert!(
+            (0.0..=1.0).contains(&rate),
+            "mutate_genome called with a rate outside of `0.0..=1.0`"
+        );
+        assert!(
+            strength.is_finite() && strength >= 0.0,
+            "mutate_genome called with a negative or non-finite strength"
+        );
+
+        for gene in genome {
+            if self.gen_bool(rate) {
+                let (perturbation, _) = standard_normal_pair(self);
+
+                *gene = (*gene + perturbation as f32 * strength).clamp(0.0, 1.0);
+            }
+        }
+    }
+
+    /// Recombines the parent genomes `a` and `b` into a child genome with the given
+    /// `strategy`, such as for breeding agents with a genetic algorithm. With
+    /// [`CrossoverStrategy::SinglePoint`], the split point is chosen so the child always
+    /// inherits at least one gene from each parent, except for genomes of fewer than two genes,
+    /// which can't be split and so are copied from `a`. For the same RNG state and parents,
+    /// the same child is always produced.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`GenomeLengthError`] if `a` and `b` differ in length.
+    ///
+    /// ```
+    /// use bevy_rand::prelude::*;
+    /// use bevy_prng::ChaCha8Rng;
+    /// use rand_core::SeedableRng;
+    ///
+    /// let mut rng = GlobalEntropy::<ChaCha8Rng>::from_seed([1; 32]);
+    ///
+    /// let a = [0.0; 8];
+    /// let b = [1.0; 8];
+    ///
+    /// let child = rng.crossover(&a, &b, CrossoverStrategy::SinglePoint).unwrap();
+    ///
+    /// assert_eq!(child[0], 0.0);
+    /// assert_eq!(child[7], 1.0);
+    /// ```
+    #[cfg_attr(docsrs, doc(cfg(feature = "rand")))]
+    pub fn crossover(
+        &mut self,
+        a: &[f32],
+        b: &[f32],
+        strategy: CrossoverStrategy,
+    ) -> Result<Vec<f32>, GenomeLengthError> {
+        if a.len() != b.len() {
+            return Err(GenomeLengthError {
+                a: a.len(),
+                b: b.len(),
+            });
+        }
+
+        let child = match strategy {
+            CrossoverStrategy::SinglePoint if a.len() < 2 => a.to_vec(),
+            CrossoverStrategy::SinglePoint => {
+                let point = self.gen_range(1..a.len());
+
+                a[..point].iter().chain(&b[point..]).copied().collect()
+            }
+            CrossoverStrategy::Uniform => a
+                .iter()
+                .zip(b)
+                .map(|(&a, &b)| if self.gen_bool(0.5) { a } else { b })
+                .collect(),
+        };
+
+        Ok(child)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy_prng::ChaCha8Rng;
+    use rand_core::SeedableRng;
+
+    use super::*;
+
+    #[test]
+    fn zero_rate_keeps_genome() {
+        let mut rng = GlobalEntropy::<ChaCha8Rng>::from_seed([3; 32]);
+
+        let original: Vec<f32> = (0..50).map(|gene| gene as f32 / 50.0).collect();
+        let mut genome = original.clone();
+
+        rng.mutate_genome(&mut genome, 0.0, 0.5);
+
+        assert_eq!(genome, original);
+    }
+
+    #[test]
+    fn full_rate_shifts_every_gene() {
+        let mut rng = GlobalEntropy::<ChaCha8Rng>::from_seed([3; 32]);
+
+        let mut genome = [0.5; 100];
+
+        rng.mutate_genome(&mut genome, 1.0, 0.05);
+
+        assert!(
+            genome.iter().all(|&gene| gene != 0.5),
+            "every gene should be mutated"
+        );
+        assert!(genome.iter().all(|gene| (0.0..=1.0).contains(gene)));
+
+        // Strong mutations should be clamped to the valid range.
+        let mut edges = [0.0, 1.0, 0.0, 1.0];
+
+        rng.mutate_genome(&mut edges, 1.0, 10.0);
+
+        assert!(edges.iter().all(|gene| (0.0..=1.0).contains(gene)));
+    }
+
+    #[test]
+    fn mutation_deterministic() {
+        let mut rng1 = GlobalEntropy::<ChaCha8Rng>::from_seed([3; 32]);
+        let mut rng2 = GlobalEntropy::<ChaCha8Rng>::from_seed([3; 32]);
+
+        let mut genome1 = [0.3; 20];
+        let mut genome2 = [0.3; 20];
+
+        rng1.mutate_genome(&mut genome1, 0.5, 0.2);
+        rng2.mutate_genome(&mut genome2, 0.5, 0.2);
+
+        assert_eq!(genome1, genome2);
+        assert_ne!(genome1, [0.3; 20], "some genes should be mutated");
+    }
+
+    #[test]
+    fn single_point_inherits_from_both() {
+        let mut rng = GlobalEntropy::<ChaCha8Rng>::from_seed([3; 32]);
+
+        let a = [0.0; 10];
+        let b = [1.0; 10];
+
+        for _ in 0..100 {
+            let child = rng
+                .crossover(&a, &b, CrossoverStrategy::SinglePoint)
+                .unwrap();
+
+            let point = child.iter().position(|&gene| gene == 1.0).unwrap();
+
+            assert!(
+                (1..10).contains(&point),
+                "the child should inherit genes from both parents"
+            );
+            assert!(
+                child[point..].iter().all(|&gene| gene == 1.0),
+                "the genes after the split should come from the second parent"
+            );
+        }
+
+        assert_eq!(
+            rng.crossover(&[0.5], &[1.0], CrossoverStrategy::SinglePoint),
+            Ok(vec![0.5])
+        );
+    }
+
+    #[test]
+    fn uniform_inherits_from_both() {
+        let mut rng = GlobalEntropy::<ChaCha8Rng>::from_seed([3; 32]);
+
+        let a: Vec<f32> = (0..1000).map(|gene| gene as f32).collect();
+        let b: Vec<f32> = a.iter().map(|gene| -gene - 1.0).collect();
+
+        let child = rng.crossover(&a, &b, CrossoverStrategy::Uniform).unwrap();
+
+        assert!(
+            child
+                .iter()
+                .enumerate()
+                .all(|(index, &gene)| gene == a[index] || gene == b[index]),
+            "every gene should come from either parent at the same position"
+        );
+
+        let from_a = child.iter().filter(|&&gene| gene >= 0.0).count();
+
+        assert!(
+            (450..550).contains(&from_a),
+            "about half of the genes should come from each parent: {from_a}"
+        );
+    }
+
+    #[test]
+    fn crossover_deterministic() {
+        let mut rng1 = GlobalEntropy::<ChaCha8Rng>::from_seed([3; 32]);
+        let mut rng2 = GlobalEntropy::<ChaCha8Rng>::from_seed([3; 32]);
+
+        let a = [0.1, 0.2, 0.3, 0.4, 0.5, 0.6];
+        let b = [0.9, 0.8, 0.7, 0.6, 0.5, 0.4];
+
+        for strategy in [CrossoverStrategy::SinglePoint, CrossoverStrategy::Uniform] {
+            assert_eq!(
+                rng1.crossover(&a, &b, strategy),
+                rng2.crossover(&a, &b, strategy)
+            );
+        }
+    }
+
+    #[test]
+    fn crossover_length_mismatch() {
+        let mut rng = GlobalEntropy::<ChaCha8Rng>::from_seed([3; 32]);
+
+        assert_eq!(
+            rng.crossover(&[0.0; 3], &[1.0; 4], CrossoverStrategy::Uniform),
+            Err(GenomeLengthError { a: 3, b: 4 })
+        );
+    }
+}
@@ -0,0 +1,837 @@
+use std::f32::consts::{PI, SQRT_2, TAU};
+
+use crate::{resource::GlobalEntropy, traits::SeedableEntropySource};
+use bevy::{
+    math::{Quat, Rect, Vec2, Vec3},
+    transform::components::Transform,
+};
+use rand::Rng;
+
+fn in_region(region: &Rect, point: Vec2) -> bool {
+    // Exclude the max edges, so points always map into a valid grid cell.
+    point.x >= region.min.x
+        && point.y >= region.min.y
+        && point.x < region.max.x
+        && point.y < region.max.y
+}
+
+/// Moves every site towards the centroid of its Voronoi cell within `region`, for `iterations`
+/// rounds of Lloyd's algorithm. The cells are approximated by assigning a grid of sample points
+/// to their nearest sites, with [`LLOYD_SAMPLES_PER_SITE`] samples per site.
+fn lloyd_relax(region: &Rect, sites: &mut [Vec2], iterations: usize) {
+    if sites.is_empty() {
+        return;
+    }
+
+    // Scale the sample grid to the region, so the samples stay roughly square.
+    let spacing =
+        (region.width() * region.height() / (sites.len() * LLOYD_SAMPLES_PER_SITE) as f32).sqrt();
+    let columns = (region.width() / spacing).ceil().max(1.0) as usize;
+    let rows = (region.height() / spacing).ceil().max(1.0) as usize;
+    let cell = Vec2::new(
+        region.width() / columns as f32,
+        region.height() / rows as f32,
+    );
+
+    for _ in 0..iterations {
+        let mut sums = vec![(Vec2::ZERO, 0u32); sites.len()];
+
+        for row in 0..rows {
+            for column in 0..columns {
+                let sample = region.min + (Vec2::new(column as f32, row as f32) + 0.5) * cell;
+
+                let nearest = (0..sites.len())
+                    .min_by(|&a, &b| {
+                        sites[a]
+                            .distance_squared(sample)
+                            .total_cmp(&sites[b].distance_squared(sample))
+                    })
+                    .unwrap();
+
+                sums[nearest].0 += sample;
+                sums[nearest].1 += 1;
+            }
+        }
+
+        // Sites without any samples keep their place.
+        for (site, (sum, count)) in sites.iter_mut().zip(sums) {
+            if count > 0 {
+                *site = sum / count as f32;
+            }
+        }
+    }
+}
+
+/// The amount of sample points per site used to approximate Voronoi cells in [`lloyd_relax`].
+const LLOYD_SAMPLES_PER_SITE: usize = 64;
+
+/// Samples a rotation uniformly over all orientations, via Shoemake's method of mapping three
+/// uniform values onto a unit quaternion.
+fn random_rotation(rng: &mut impl Rng) -> Quat {
+    let (u1, u2, u3): (f32, f32, f32) = (rng.gen(), rng.gen(), rng.gen());
+
+    let (a, b) = ((1.0 - u1).sqrt(), u1.sqrt());
+    let (sin2, cos2) = (TAU * u2).sin_cos();
+    let (sin3, cos3) = (TAU * u3).sin_cos();
+
+    Quat::from_xyzw(a * sin2, a * cos2, b * sin3, b * cos3).normalize()
+}
+
+/// Samples a direction uniformly over the spherical cap around the +Z axis whose polar angle
+/// has a cosine of at least `min_cos`, via Archimedes' hat-box theorem, under which the height
+/// of a uniform point on a sphere is itself uniform. A `min_cos` of `-1.0` covers the whole
+/// sphere.
+fn random_cap_dir(rng: &mut impl Rng, min_cos: f32) -> Vec3 {
+    let z = 1.0 - rng.gen::<f32>() * (1.0 - min_cos);
+    let (sin, cos) = (TAU * rng.gen::<f32>()).sin_cos();
+    let radius = (1.0 - z * z).max(0.0).sqrt();
+
+    Vec3::new(radius * cos, radius * sin, z)
+}
+
+impl<R: SeedableEntropySource + 'static> GlobalEntropy<R> {
+    /// Generates evenly scattered points within `region` via Poisson-disk sampling, using
+    /// Bridson's algorithm. No two points are closer to each other than `min_dist`, while
+    /// avoiding the clustering and gaps that plain uniform placement produces, making it
+    /// suitable for scattering objects like trees or rocks. `k` is the number of candidates
+    /// tried around each point before it is considered done, with `30` being the usual choice.
+    /// Higher values pack points more tightly at the cost of more work.
+    ///
+    /// The algorithm runs in `O(n * k)` time for `n` generated points, and allocates a
+    /// background grid with a cell per `min_dist² / 2` units of area of the region. Returns
+    /// no points if the region is empty.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `min_dist` is not a positive, finite number.
+    ///
+    /// ```
+    /// use bevy::math::{Rect, Vec2};
+    /// use bevy_rand::prelude::*;
+    /// use bevy_prng::ChaCha8Rng;
+    /// use rand_core::SeedableRng;
+    ///
+    /// let mut rng = GlobalEntropy::<ChaCha8Rng>::from_seed([1; 32]);
+    ///
+    /// let points = rng.poisson_disk_2d(Rect::new(0.0, 0.0, 100.0, 100.0), 10.0, 30);
+    ///
+    /// assert!(!points.is_empty());
+    /// ```
+    #[cfg_attr(docsrs, doc(cfg(feature = "math")))]
+    pub fn poisson_disk_2d(&mut self, region: Rect, min_dist: f32, k: usize) -> Vec<Vec2> {
+        assert!(
+            min_dist.is_finite() && min_dist > 0.0,
+            "poisson_disk_2d called with a non-positive `min_dist`"
+        );
+
+        if region.is_empty() {
+            return Vec::new();
+        }
+
+        let cell_size = min_dist / SQRT_2;
+        let size = region.size();
+        let columns = (size.x / cell_size).ceil() as usize;
+        let rows = (size.y / cell_size).ceil() as usize;
+
+        let cell_of = |point: Vec2| {
+            let offset = (point - region.min) / cell_size;
+
+            (
+                (offset.x as usize).min(columns - 1),
+                (offset.y as usize).min(rows - 1),
+            )
+        };
+
+        // Each cell can contain at most one point, as its diagonal is `min_dist`.
+        let mut grid: Vec<Option<usize>> = vec![None; columns * rows];
+        let mut points = Vec::new();
+        let mut active = Vec::new();
+
+        let first = Vec2::new(
+            self.gen_range(region.min.x..region.max.x),
+            self.gen_range(region.min.y..region.max.y),
+        );
+        let (column, row) = cell_of(first);
+
+        grid[row * columns + column] = Some(0);
+        points.push(first);
+        active.push(0);
+
+        let min_dist_squared = min_dist * min_dist;
+
+        while !active.is_empty() {
+            let active_index = self.gen_range(0..active.len());
+            let origin = points[active[active_index]];
+
+            let mut found = false;
+
+            for _ in 0..k {
+                // Sample uniformly by area within the annulus of `min_dist..2 * min_dist`
+                let angle = self.gen_range(0.0..TAU);
+                let distance = self
+                    .gen_range(min_dist_squared..4.0 * min_dist_squared)
+                    .sqrt();
+                let candidate = origin + Vec2::from_angle(angle) * distance;
+
+                if !in_region(&region, candidate) {
+                    continue;
+                }
+
+                let (column, row) = cell_of(candidate);
+
+                let too_close = (row.saturating_sub(2)..(row + 3).min(rows)).any(|row| {
+                    (column.saturating_sub(2)..(column + 3).min(columns)).any(|column| {
+                        grid[row * columns + column].is_some_and(|index| {
+                            points[index].distance_squared(candidate) < min_dist_squared
+                        })
+                    })
+                });
+
+                if !too_close {
+                    grid[row * columns + column] = Some(points.len());
+                    active.push(points.len());
+                    points.push(candidate);
+                    found = true;
+                    break;
+                }
+            }
+
+            if !found {
+                active.swap_remove(active_index);
+            }
+        }
+
+        points
+    }
+
+    /// Samples a point uniformly over the combined area of `regions`, such as for placing
+    /// spawns across a set of walkable areas. Each region is chosen with a likelihood that is
+    /// proportional to its area, and then a point is sampled uniformly within it. Overlapping
+    /// regions are not merged, so their shared area is more likely to be sampled. Returns `None`
+    /// if none of the regions have any area.
+    ///
+    /// ```
+    /// use bevy::math::Rect;
+    /// use bevy_rand::prelude::*;
+    /// use bevy_prng::ChaCha8Rng;
+    /// use rand_core::SeedableRng;
+    ///
+    /// let mut rng = GlobalEntropy::<ChaCha8Rng>::from_seed([1; 32]);
+    ///
+    /// let regions = [Rect::new(0.0, 0.0, 10.0, 10.0), Rect::new(20.0, 0.0, 40.0, 5.0)];
+    ///
+    /// let point = rng.sample_weighted_regions(&regions).unwrap();
+    ///
+    /// assert!(regions.iter().any(|region| region.contains(point)));
+    /// ```
+    #[cfg_attr(docsrs, doc(cfg(feature = "math")))]
+    pub fn sample_weighted_regions(&mut self, regions: &[Rect]) -> Option<Vec2> {
+        let region =
+            *self.choose_weighted_by(regions, |region| region.width() * region.height())?;
+
+        Some(Vec2::new(
+            self.gen_range(region.min.x..region.max.x),
+            self.gen_range(region.min.y..region.max.y),
+        ))
+    }
+
+    /// Generates a random [`Transform`], such as for scattering props. The translation is
+    /// sampled uniformly per axis within the inclusive box spanned by `pos_range`, and the
+    /// uniform scale within the inclusive range of `scale_range`. If `rotate` is `true`, the
+    /// rotation is sampled uniformly over all orientations, otherwise it is left as the identity.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the minimum of either range is greater than its maximum on any axis.
+    ///
+    /// ```
+    /// use bevy::math::Vec3;
+    /// use bevy_rand::prelude::*;
+    /// use bevy_prng::ChaCha8Rng;
+    /// use rand_core::SeedableRng;
+    ///
+    /// let mut rng = GlobalEntropy::<ChaCha8Rng>::from_seed([1; 32]);
+    ///
+    /// let transform = rng.gen_transform(
+    ///     (Vec3::new(-10.0, 0.0, -10.0), Vec3::new(10.0, 0.0, 10.0)),
+    ///     (0.5, 1.5),
+    ///     true,
+    /// );
+    ///
+    /// assert_eq!(transform.translation.y, 0.0);
+    /// ```
+    #[cfg_attr(docsrs, doc(cfg(feature = "math")))]
+    pub fn gen_transform(
+        &mut self,
+        pos_range: (Vec3, Vec3),
+        scale_range: (f32, f32),
+        rotate: bool,
+    ) -> Transform {
+        let (min, max) = pos_range;
+
+        let translation = Vec3::new(
+            self.gen_range(min.x..=max.x),
+            self.gen_range(min.y..=max.y),
+            self.gen_range(min.z..=max.z),
+        );
+        let scale = self.gen_range(scale_range.0..=scale_range.1);
+        let rotation = if rotate {
+            random_rotation(self)
+        } else {
+            Quat::IDENTITY
+        };
+
+        Transform {
+            translation,
+            rotation,
+            scale: Vec3::splat(scale),
+        }
+    }
+
+    /// Generates a direction uniformly distributed within a cone of `half_angle` radians around
+    /// `axis`, such as for jittering the directions of particles or bullet spread. Directions
+    /// are sampled uniformly over the area of the spherical cap the cone cuts out of the unit
+    /// sphere, rather than uniformly over the angle, which would cluster them around the axis.
+    /// `axis` doesn't need to be normalized, while the returned direction always is.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `axis` is zero or not finite, or if `half_angle` is not within `0.0..=PI`.
+    ///
+    /// ```
+    /// use bevy::math::Vec3;
+    /// use bevy_rand::prelude::*;
+    /// use bevy_prng::ChaCha8Rng;
+    /// use rand_core::SeedableRng;
+    ///
+    /// let mut rng = GlobalEntropy::<ChaCha8Rng>::from_seed([1; 32]);
+    ///
+    /// let dir = rng.gen_cone_dir(Vec3::Y, 0.2);
+    ///
+    /// assert!(dir.angle_between(Vec3::Y) <= 0.2 + 1e-4);
+    /// ```
+    #[cfg_attr(docsrs, doc(cfg(feature = "math")))]
+    pub fn gen_cone_dir(&mut self, axis: Vec3, half_angle: f32) -> Vec3 {
+        let Some(axis) = axis.try_normalize() else {
+            panic!("gen_cone_dir called with a zero or non-finite axis");
+        };
+        assert!(
+            (0.0..=PI).contains(&half_angle),
+            "gen_cone_dir called with a half angle outside of `0.0..=PI`"
+        );
+
+        let dir = random_cap_dir(self, half_angle.cos());
+
+        (Quat::from_rotation_arc(Vec3::Z, axis) * dir).normalize()
+    }
+
+    /// Generates a direction uniformly distributed over the unit sphere, such as for random
+    /// impulses or spawn directions. The returned vector is always normalized, so can be used as
+    /// a direction without normalizing it again. `bevy` has no dedicated direction types yet,
+    /// so the direction is returned as a [`Vec3`].
+    ///
+    /// ```
+    /// use bevy_rand::prelude::*;
+    /// use bevy_prng::ChaCha8Rng;
+    /// use rand_core::SeedableRng;
+    ///
+    /// let mut rng = GlobalEntropy::<ChaCha8Rng>::from_seed([1; 32]);
+    ///
+    /// let dir = rng.gen_dir3();
+    ///
+    /// assert!(dir.is_normalized());
+    /// ```
+    #[cfg_attr(docsrs, doc(cfg(feature = "math")))]
+    pub fn gen_dir3(&mut self) -> Vec3 {
+        random_cap_dir(self, -1.0).normalize()
+    }
+
+    /// Generates a direction uniformly distributed over the unit circle, such as for random
+    /// movement in 2D. The returned vector is always normalized, so can be used as a direction
+    /// without normalizing it again. `bevy` has no dedicated direction types yet, so the
+    /// direction is returned as a [`Vec2`].
+    ///
+    /// ```
+    /// use bevy_rand::prelude::*;
+    /// use bevy_prng::ChaCha8Rng;
+    /// use rand_core::SeedableRng;
+    ///
+    /// let mut rng = GlobalEntropy::<ChaCha8Rng>::from_seed([1; 32]);
+    ///
+    /// let dir = rng.gen_dir2();
+    ///
+    /// assert!(dir.is_normalized());
+    /// ```
+    #[cfg_attr(docsrs, doc(cfg(feature = "math")))]
+    pub fn gen_dir2(&mut self) -> Vec2 {
+        let (sin, cos) = (TAU * self.gen::<f32>()).sin_cos();
+
+        Vec2::new(cos, sin)
+    }
+
+    /// Generates a rotational random walk of `steps` rotations, such as for procedural camera
+    /// shake. Starting from [`Quat::IDENTITY`], every step rotates the previous rotation around
+    /// a uniformly random axis by a random angle of up to `max_step` radians, so consecutive
+    /// rotations stay close to each other. Every rotation is normalized, so the walk doesn't
+    /// drift off the unit sphere over many steps. Returns an empty `Vec` if `steps` is zero.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `max_step` is negative or not finite.
+    ///
+    /// ```
+    /// use bevy_rand::prelude::*;
+    /// use bevy_prng::ChaCha8Rng;
+    /// use rand_core::SeedableRng;
+    ///
+    /// let mut rng = GlobalEntropy::<ChaCha8Rng>::from_seed([1; 32]);
+    ///
+    /// let shake = rng.gen_random_walk_quat(30, 0.05);
+    ///
+    /// assert_eq!(shake.len(), 30);
+    /// ```
+    #[cfg_attr(docsrs, doc(cfg(feature = "math")))]
+    pub fn gen_random_walk_quat(&mut self, steps: usize, max_step: f32) -> Vec<Quat> {
+        assert!(
+            max_step.is_finite() && max_step >= 0.0,
+            "gen_random_walk_quat called with a negative or non-finite max step"
+        );
+
+        let mut rotation = Quat::IDENTITY;
+
+        (0..steps)
+            .map(|_| {
+                let axis = random_cap_dir(self, -1.0);
+                let angle = self.gen_range(0.0..=max_step);
+
+                rotation = (Quat::from_axis_angle(axis, angle) * rotation).normalize();
+
+                rotation
+            })
+            .collect()
+    }
+
+    /// Generates `count` points distributed uniformly within `region`, for use as the sites of
+    /// a Voronoi diagram, such as the centres of biomes on a map. Points are sampled within the
+    /// half-open area of the region, excluding its max edges. Returns no points if the region
+    /// is empty.
+    ///
+    /// For the same RNG state and region, the same points are always generated. Uniform sites
+    /// can produce very uneven cells, so see [`GlobalEntropy::gen_relaxed_voronoi_sites`] for
+    /// more evenly spaced sites.
+    ///
+    /// ```
+    /// use bevy::math::Rect;
+    /// use bevy_rand::prelude::*;
+    /// use bevy_prng::ChaCha8Rng;
+    /// use rand_core::SeedableRng;
+    ///
+    /// let mut rng = GlobalEntropy::<ChaCha8Rng>::from_seed([1; 32]);
+    ///
+    /// let region = Rect::new(0.0, 0.0, 256.0, 256.0);
+    ///
+    /// let sites = rng.gen_voronoi_sites(region, 12);
+    ///
+    /// assert_eq!(sites.len(), 12);
+    /// assert!(sites.iter().all(|&site| region.contains(site)));
+    /// ```
+    #[cfg_attr(docsrs, doc(cfg(feature = "math")))]
+    pub fn gen_voronoi_sites(&mut self, region: Rect, count: usize) -> Vec<Vec2> {
+        if region.width() <= 0.0 || region.height() <= 0.0 {
+            return Vec::new();
+        }
+
+        (0..count)
+            .map(|_| {
+                Vec2::new(
+                    self.gen_range(region.min.x..region.max.x),
+                    self.gen_range(region.min.y..region.max.y),
+                )
+            })
+            .collect()
+    }
+
+    /// Generates `count` Voronoi sites within `region` like [`GlobalEntropy::gen_voronoi_sites`],
+    /// and then evens out their spacing with `iterations` rounds of Lloyd relaxation, moving
+    /// every site to the centroid of its cell. A few iterations are usually enough to remove
+    /// the tiny and huge cells of uniform sites, while more iterations tend towards a regular,
+    /// honeycomb-like layout. Zero iterations returns the uniform sites as they are.
+    ///
+    /// The cells are approximated by a grid of sample points over the region, so every
+    /// iteration takes `O(count²)` time.
+    ///
+    /// ```
+    /// use bevy::math::Rect;
+    /// use bevy_rand::prelude::*;
+    /// use bevy_prng::ChaCha8Rng;
+    /// use rand_core::SeedableRng;
+    ///
+    /// let mut rng = GlobalEntropy::<ChaCha8Rng>::from_seed([1; 32]);
+    ///
+    /// let region = Rect::new(0.0, 0.0, 256.0, 256.0);
+    ///
+    /// let sites = rng.gen_relaxed_voronoi_sites(region, 12, 3);
+    ///
+    /// assert!(sites.iter().all(|&site| region.contains(site)));
+    /// ```
+    #[cfg_attr(docsrs, doc(cfg(feature = "math")))]
+    pub fn gen_relaxed_voronoi_sites(
+        &mut self,
+        region: Rect,
+        count: usize,
+        iterations: usize,
+    ) -> Vec<Vec2> {
+        let mut sites = self.gen_voronoi_sites(region, count);
+
+        lloyd_relax(&region, &mut sites, iterations);
+
+        sites
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy_prng::ChaCha8Rng;
+    use rand_core::SeedableRng;
+
+    use super::*;
+
+    #[test]
+    fn poisson_disk_respects_min_dist() {
+        let mut rng = GlobalEntropy::<ChaCha8Rng>::from_seed([8; 32]);
+
+        let region = Rect::new(-50.0, -25.0, 50.0, 25.0);
+        let min_dist = 5.0;
+
+        let points = rng.poisson_disk_2d(region, min_dist, 30);
+
+        assert!(
+            points.len() > 20,
+            "too few points generated: {}",
+            points.len()
+        );
+
+        for (i, a) in points.iter().enumerate() {
+            assert!(in_region(&region, *a), "{a} is outside of the region");
+
+            for b in &points[i + 1..] {
+                assert!(
+                    a.distance_squared(*b) >= min_dist * min_dist,
+                    "{a} and {b} are too close"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn poisson_disk_deterministic() {
+        let mut rng1 = GlobalEntropy::<ChaCha8Rng>::from_seed([8; 32]);
+        let mut rng2 = GlobalEntropy::<ChaCha8Rng>::from_seed([8; 32]);
+
+        let region = Rect::new(0.0, 0.0, 30.0, 30.0);
+
+        assert_eq!(
+            rng1.poisson_disk_2d(region, 3.0, 30),
+            rng2.poisson_disk_2d(region, 3.0, 30)
+        );
+    }
+
+    #[test]
+    fn poisson_disk_empty_region() {
+        let mut rng = GlobalEntropy::<ChaCha8Rng>::from_seed([8; 32]);
+
+        assert!(rng
+            .poisson_disk_2d(Rect::new(0.0, 0.0, 0.0, 10.0), 1.0, 30)
+            .is_empty());
+    }
+
+    #[test]
+    fn weighted_regions_track_area() {
+        let mut rng = GlobalEntropy::<ChaCha8Rng>::from_seed([8; 32]);
+
+        let regions = [
+            Rect::new(0.0, 0.0, 10.0, 10.0),
+            Rect::new(20.0, 0.0, 50.0, 10.0),
+            Rect::new(0.0, 20.0, 5.0, 20.0),
+        ];
+
+        let mut counts = [0u32; 3];
+
+        for _ in 0..4000 {
+            let point = rng.sample_weighted_regions(&regions).unwrap();
+
+            let index = regions
+                .iter()
+                .position(|region| region.contains(point))
+                .unwrap();
+
+            counts[index] += 1;
+        }
+
+        assert_eq!(counts[2], 0, "regions without area should never be sampled");
+        assert!(
+            (900..1100).contains(&counts[0]) && (2900..3100).contains(&counts[1]),
+            "samples should be spread by area: {counts:?}"
+        );
+    }
+
+    #[test]
+    fn weighted_regions_deterministic() {
+        let mut rng1 = GlobalEntropy::<ChaCha8Rng>::from_seed([8; 32]);
+        let mut rng2 = GlobalEntropy::<ChaCha8Rng>::from_seed([8; 32]);
+
+        let regions = [
+            Rect::new(0.0, 0.0, 1.0, 1.0),
+            Rect::new(-4.0, -4.0, -2.0, -2.0),
+        ];
+
+        for _ in 0..10 {
+            assert_eq!(
+                rng1.sample_weighted_regions(&regions),
+                rng2.sample_weighted_regions(&regions)
+            );
+        }
+
+        assert_eq!(rng1.sample_weighted_regions(&[]), None);
+    }
+
+    #[test]
+    fn transform_within_ranges() {
+        let mut rng = GlobalEntropy::<ChaCha8Rng>::from_seed([8; 32]);
+
+        let (min, max) = (Vec3::new(-5.0, 0.0, 2.0), Vec3::new(5.0, 1.0, 2.0));
+
+        for _ in 0..100 {
+            let transform = rng.gen_transform((min, max), (0.5, 2.0), true);
+
+            assert!(
+                transform.translation.cmpge(min).all() && transform.translation.cmple(max).all(),
+                "{} is outside of the position range",
+                transform.translation
+            );
+            assert!((0.5..=2.0).contains(&transform.scale.x));
+            assert_eq!(transform.scale, Vec3::splat(transform.scale.x));
+            assert!(transform.rotation.is_normalized());
+        }
+
+        let unrotated = rng.gen_transform((min, max), (1.0, 1.0), false);
+
+        assert_eq!(unrotated.rotation, Quat::IDENTITY);
+        assert_eq!(unrotated.scale, Vec3::ONE);
+    }
+
+    #[test]
+    fn transform_deterministic() {
+        let mut rng1 = GlobalEntropy::<ChaCha8Rng>::from_seed([8; 32]);
+        let mut rng2 = GlobalEntropy::<ChaCha8Rng>::from_seed([8; 32]);
+
+        let range = (Vec3::splat(-1.0), Vec3::splat(1.0));
+
+        assert_eq!(
+            rng1.gen_transform(range, (1.0, 3.0), true),
+            rng2.gen_transform(range, (1.0, 3.0), true)
+        );
+    }
+
+    #[test]
+    fn cone_dir_within_angle() {
+        let mut rng = GlobalEntropy::<ChaCha8Rng>::from_seed([8; 32]);
+
+        for (axis, half_angle) in [
+            (Vec3::Z, 0.1),
+            (Vec3::NEG_Z, 0.5),
+            (Vec3::new(1.0, 2.0, -3.0), 1.0),
+            (Vec3::X, PI),
+        ] {
+            for _ in 0..500 {
+                let dir = rng.gen_cone_dir(axis, half_angle);
+
+                assert!(dir.is_normalized());
+                assert!(
+                    dir.angle_between(axis) <= half_angle + 1e-3,
+                    "{dir} is outside of the cone around {axis}"
+                );
+            }
+        }
+
+        assert!(rng.gen_cone_dir(Vec3::Y, 0.0).abs_diff_eq(Vec3::Y, 1e-5));
+    }
+
+    #[test]
+    fn cone_dir_uniform_over_cap() {
+        let mut rng = GlobalEntropy::<ChaCha8Rng>::from_seed([8; 32]);
+
+        let half_angle = PI / 2.0;
+        let samples = 10_000;
+
+        // Half of the area of a hemisphere lies above the height of 0.5, while
+        // two thirds of its angular range do.
+        let upper = (0..samples)
+            .filter(|_| rng.gen_cone_dir(Vec3::Z, half_angle).z > 0.5)
+            .count();
+
+        assert!(
+            (4700..5300).contains(&upper),
+            "directions should be spread uniformly over the cap: {upper}"
+        );
+    }
+
+    #[test]
+    fn cone_dir_deterministic() {
+        let mut rng1 = GlobalEntropy::<ChaCha8Rng>::from_seed([8; 32]);
+        let mut rng2 = GlobalEntropy::<ChaCha8Rng>::from_seed([8; 32]);
+
+        for _ in 0..10 {
+            assert_eq!(
+                rng1.gen_cone_dir(Vec3::X, 0.3),
+                rng2.gen_cone_dir(Vec3::X, 0.3)
+            );
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn cone_dir_zero_axis() {
+        let mut rng = GlobalEntropy::<ChaCha8Rng>::from_seed([8; 32]);
+
+        rng.gen_cone_dir(Vec3::ZERO, 0.5);
+    }
+
+    #[test]
+    fn random_walk_quat_steps() {
+        let mut rng = GlobalEntropy::<ChaCha8Rng>::from_seed([8; 32]);
+
+        let max_step = 0.1;
+        let path = rng.gen_random_walk_quat(200, max_step);
+
+        assert_eq!(path.len(), 200);
+        assert!(path.iter().all(|rotation| rotation.is_normalized()));
+
+        for (previous, next) in std::iter::once(&Quat::IDENTITY)
+            .chain(path.iter())
+            .zip(path.iter())
+        {
+            assert!(
+                previous.angle_between(*next) <= max_step + 1e-3,
+                "each step should rotate by at most the max step"
+            );
+        }
+
+        assert!(rng.gen_random_walk_quat(0, max_step).is_empty());
+    }
+
+    #[test]
+    fn random_walk_quat_deterministic() {
+        let mut rng1 = GlobalEntropy::<ChaCha8Rng>::from_seed([8; 32]);
+        let mut rng2 = GlobalEntropy::<ChaCha8Rng>::from_seed([8; 32]);
+
+        assert_eq!(
+            rng1.gen_random_walk_quat(20, 0.5),
+            rng2.gen_random_walk_quat(20, 0.5)
+        );
+    }
+
+    #[test]
+    fn dirs_normalized() {
+        let mut rng = GlobalEntropy::<ChaCha8Rng>::from_seed([8; 32]);
+
+        let mut sum3 = Vec3::ZERO;
+        let mut sum2 = Vec2::ZERO;
+
+        for _ in 0..10_000 {
+            let dir3 = rng.gen_dir3();
+            let dir2 = rng.gen_dir2();
+
+            assert!(dir3.is_normalized(), "{dir3} is not normalized");
+            assert!(dir2.is_normalized(), "{dir2} is not normalized");
+
+            sum3 += dir3;
+            sum2 += dir2;
+        }
+
+        // Uniform directions cancel out, so their mean is close to the origin.
+        assert!(
+            (sum3 / 10_000.0).length() < 0.05 && (sum2 / 10_000.0).length() < 0.05,
+            "directions should be spread uniformly"
+        );
+    }
+
+    #[test]
+    fn dirs_deterministic() {
+        let mut rng1 = GlobalEntropy::<ChaCha8Rng>::from_seed([8; 32]);
+        let mut rng2 = GlobalEntropy::<ChaCha8Rng>::from_seed([8; 32]);
+
+        for _ in 0..10 {
+            assert_eq!(rng1.gen_dir3(), rng2.gen_dir3());
+            assert_eq!(rng1.gen_dir2(), rng2.gen_dir2());
+        }
+    }
+
+    fn min_spacing(sites: &[Vec2]) -> f32 {
+        sites
+            .iter()
+            .enumerate()
+            .flat_map(|(i, a)| sites[i + 1..].iter().map(|b| a.distance(*b)))
+            .fold(f32::INFINITY, f32::min)
+    }
+
+    #[test]
+    fn voronoi_sites_within_region() {
+        let mut rng = GlobalEntropy::<ChaCha8Rng>::from_seed([8; 32]);
+
+        let region = Rect::new(-100.0, 20.0, 60.0, 50.0);
+
+        let sites = rng.gen_voronoi_sites(region, 500);
+
+        assert_eq!(sites.len(), 500);
+        assert!(sites.iter().all(|&site| in_region(&region, site)));
+
+        let relaxed = rng.gen_relaxed_voronoi_sites(region, 50, 5);
+
+        assert!(relaxed.iter().all(|&site| in_region(&region, site)));
+        assert!(rng
+            .gen_voronoi_sites(Rect::new(0.0, 0.0, 10.0, 0.0), 10)
+            .is_empty());
+    }
+
+    #[test]
+    fn voronoi_sites_deterministic() {
+        let mut rng1 = GlobalEntropy::<ChaCha8Rng>::from_seed([8; 32]);
+        let mut rng2 = GlobalEntropy::<ChaCha8Rng>::from_seed([8; 32]);
+
+        let region = Rect::new(0.0, 0.0, 100.0, 100.0);
+
+        assert_eq!(
+            rng1.gen_voronoi_sites(region, 20),
+            rng2.gen_voronoi_sites(region, 20)
+        );
+        assert_eq!(
+            rng1.gen_relaxed_voronoi_sites(region, 20, 3),
+            rng2.gen_relaxed_voronoi_sites(region, 20, 3)
+        );
+    }
+
+    #[test]
+    fn relaxation_evens_out_sites() {
+        let mut rng1 = GlobalEntropy::<ChaCha8Rng>::from_seed([8; 32]);
+        let mut rng2 = GlobalEntropy::<ChaCha8Rng>::from_seed([8; 32]);
+        let mut rng3 = GlobalEntropy::<ChaCha8Rng>::from_seed([8; 32]);
+
+        let region = Rect::new(0.0, 0.0, 100.0, 100.0);
+
+        let uniform = rng1.gen_voronoi_sites(region, 40);
+
+        assert_eq!(
+            rng2.gen_relaxed_voronoi_sites(region, 40, 0),
+            uniform,
+            "zero iterations should leave the sites uniform"
+        );
+
+        let relaxed = rng3.gen_relaxed_voronoi_sites(region, 40, 5);
+
+        assert!(
+            min_spacing(&relaxed) > 2.0 * min_spacing(&uniform),
+            "relaxed sites should be spaced further apart"
+        );
+    }
+}
@@ -0,0 +1,425 @@
+use std::collections::HashSet;
+
+use crate::{resource::GlobalEntropy, traits::SeedableEntropySource};
+use rand::{seq::SliceRandom, Rng};
+
+/// Returns the undirected edge between `a` and `b` in a canonical form, for lookups.
+fn undirected(a: usize, b: usize) -> (usize, usize) {
+    (a.min(b), a.max(b))
+}
+
+/// A disjoint-set forest over `0..len`, for tracking which nodes are already connected.
+struct DisjointSet {
+    parents: Vec<usize>,
+}
+
+impl DisjointSet {
+    fn new(len: usize) -> Self {
+        Self {
+            parents: (0..len).collect(),
+        }
+    }
+
+    fn find(&mut self, mut node: usize) -> usize {
+        while self.parents[node] != node {
+            // Path halving, keeping lookups close to constant time.
+            self.parents[node] = self.parents[self.parents[node]];
+            node = self.parents[node];
+        }
+
+        node
+    }
+
+    /// Joins the sets of both nodes, returning `false` if they were already in the same set.
+    fn union(&mut self, a: usize, b: usize) -> bool {
+        let (a, b) = (self.find(a), self.find(b));
+
+        if a == b {
+            return false;
+        }
+
+        self.parents[a] = b;
+
+        true
+    }
+}
+
+impl<R: SeedableEntropySource + 'static> GlobalEntropy<R> {
+    /// Picks a random spanning tree of the graph with `node_count` nodes and the given undirected
+    /// `edges`, via a randomised Kruskal's algorithm: the edges are shuffled, and then every edge
+    /// connecting two not yet connected parts of the graph is kept. Carving the kept edges out of
+    /// a grid graph produces a perfect maze. If the graph is not connected, a spanning tree of
+    /// every connected part is returned instead, so a connected graph always produces exactly
+    /// `node_count - 1` edges.
+    ///
+    /// For the same RNG state and graph, the same tree is always picked.
+    ///
+    /// # Panics
+    ///
+    /// Panics if an edge refers to a node that is not within `0..node_count`.
+    ///
+    /// ```
+    /// use bevy_rand::prelude::*;
+    /// use bevy_prng::ChaCha8Rng;
+    /// use rand_core::SeedableRng;
+    ///
+    /// let mut rng = GlobalEntropy::<ChaCha8Rng>::from_seed([1; 32]);
+    ///
+    /// // A square with one diagonal.
+    /// let edges = [(0, 1), (1, 2), (2, 3), (3, 0), (0, 2)];
+    ///
+    /// let tree = rng.random_spanning_tree(4, &edges);
+    ///
+    /// assert_eq!(tree.len(), 3);
+    /// ```
+    #[cfg_attr(docsrs, doc(cfg(feature = "rand")))]
+    pub fn random_spanning_tree(
+        &mut self,
+        node_count: usize,
+        edges: &[(usize, usize)],
+    ) -> Vec<(usize, usize)> {
+        assert!(
+            edges.iter().all(|&(a, b)| a < node_count && b < node_count),
+            "edges must only connect nodes within 0..{node_count}"
+        );
+
+        let mut shuffled = edges.to_vec();
+        shuffled.shuffle(self);
+
+        let mut connected = DisjointSet::new(node_count);
+
+        shuffled.retain(|&(a, b)| connected.union(a, b));
+
+        shuffled
+    }
+
+    /// Picks a random maze from the graph with `node_count` nodes and the given undirected
+    /// `edges`, like [`GlobalEntropy::random_spanning_tree`], but with "braiding": each dead end
+    /// of the tree, being a node with only a single edge, is given an extra edge with a
+    /// probability of `braid_factor`, creating loops. Extra edges prefer connecting two dead ends
+    /// at once, so fewer edges are needed to remove them. A `braid_factor` of `0.0` produces a
+    /// perfect maze without any loops, and `1.0` removes every dead end that has an unused edge
+    /// to another node.
+    ///
+    /// For the same RNG state and graph, the same maze is always picked.
+    ///
+    /// # Panics
+    ///
+    /// Panics if an edge refers to a node that is not within `0..node_count`, or if
+    /// `braid_factor` is not within `0.0..=1.0`.
+    ///
+    /// ```
+    /// use bevy_rand::prelude::*;
+    /// use bevy_prng::ChaCha8Rng;
+    /// use rand_core::SeedableRng;
+    ///
+    /// let mut rng = GlobalEntropy::<ChaCha8Rng>::from_seed([1; 32]);
+    ///
+    /// // A square with one diagonal.
+    /// let edges = [(0, 1), (1, 2), (2, 3), (3, 0), (0, 2)];
+    ///
+    /// let maze = rng.random_braided_maze(4, &edges, 1.0);
+    ///
+    /// assert!(maze.len() > 3);
+    /// ```
+    #[cfg_attr(docsrs, doc(cfg(feature = "rand")))]
+    pub fn random_braided_maze(
+        &mut self,
+        node_count: usize,
+        edges: &[(usize, usize)],
+        braid_factor: f64,
+    ) -> Vec<(usize, usize)> {
+        assert!(
+            (0.0..=1.0).contains(&braid_factor),
+            "random_braided_maze called with a braid factor outside of `0.0..=1.0`"
+        );
+
+        let mut maze = self.random_spanning_tree(node_count, edges);
+
+        if braid_factor == 0.0 {
+            return maze;
+        }
+
+        let mut carved: HashSet<(usize, usize)> =
+            maze.iter().map(|&(a, b)| undirected(a, b)).collect();
+
+        let mut degrees = vec![0; node_count];
+        let mut neighbours = vec![Vec::new(); node_count];
+
+        for &(a, b) in &maze {
+            degrees[a] += 1;
+            degrees[b] += 1;
+        }
+
+        for &(a, b) in edges.iter().filter(|&&(a, b)| a != b) {
+            neighbours[a].push(b);
+            neighbours[b].push(a);
+        }
+
+        let mut dead_ends: Vec<usize> =
+            (0..node_count).filter(|&node| degrees[node] == 1).collect();
+        dead_ends.shuffle(self);
+
+        for node in dead_ends {
+            // Earlier extra edges may have already removed this dead end.
+            if degrees[node] != 1 || !self.gen_bool(braid_factor) {
+                continue;
+            }
+
+            let candidates: Vec<usize> = neighbours[node]
+                .iter()
+                .copied()
+                .filter(|&other| !carved.contains(&undirected(node, other)))
+                .collect();
+            let dead_end_candidates: Vec<usize> = candidates
+                .iter()
+                .copied()
+                .filter(|&other| degrees[other] == 1)
+                .collect();
+
+            let pool = if dead_end_candidates.is_empty() {
+                &candidates
+            } else {
+                &dead_end_candidates
+            };
+
+            if let Some(&other) = pool.choose(self) {
+                carved.insert(undirected(node, other));
+                degrees[node] += 1;
+                degrees[other] += 1;
+                maze.push((node, other));
+            }
+        }
+
+        maze
+    }
+
+    /// Generates a random graph with `nodes` nodes following the Erdős–Rényi model, such as for
+    /// procedural social networks. Each of the `nodes * (nodes - 1) / 2` possible undirected
+    /// edges is included independently with a probability of `p`. Edges are returned as
+    /// `(a, b)` pairs with `a < b`, ordered by `a` and then by `b`.
+    ///
+    /// For the same RNG state, the same graph is always generated.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `p` is not within `0.0..=1.0`.
+    ///
+    /// ```
+    /// use bevy_rand::prelude::*;
+    /// use bevy_prng::ChaCha8Rng;
+    /// use rand_core::SeedableRng;
+    ///
+    /// let mut rng = GlobalEntropy::<ChaCha8Rng>::from_seed([1; 32]);
+    ///
+    /// let graph = rng.gen_random_graph(10, 0.3);
+    ///
+    /// assert!(graph.iter().all(|&(a, b)| a < b && b < 10));
+    /// ```
+    #[cfg_attr(docsrs, doc(cfg(feature = "rand")))]
+    pub fn gen_random_graph(&mut self, nodes: usize, p: f64) -> Vec<(usize, usize)> {
+        assert!(
+            (0.0..=1.0).contains(&p),
+            "gen_random_graph called with a probability outside of `0.0..=1.0`"
+        );
+
+        let mut edges = Vec::new();
+
+        for a in 0..nodes {
+            for b in a + 1..nodes {
+                if self.gen_bool(p) {
+                    edges.push((a, b));
+                }
+            }
+        }
+
+        edges
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy_prng::ChaCha8Rng;
+    use rand_core::SeedableRng;
+
+    use super::*;
+
+    fn grid_edges(width: usize, height: usize) -> Vec<(usize, usize)> {
+        let mut edges = Vec::new();
+
+        for y in 0..height {
+            for x in 0..width {
+                let node = y * width + x;
+
+                if x + 1 < width {
+                    edges.push((node, node + 1));
+                }
+                if y + 1 < height {
+                    edges.push((node, node + width));
+                }
+            }
+        }
+
+        edges
+    }
+
+    #[test]
+    fn valid_spanning_tree() {
+        let mut rng = GlobalEntropy::<ChaCha8Rng>::from_seed([8; 32]);
+
+        let (width, height) = (12, 9);
+        let edges = grid_edges(width, height);
+
+        let tree = rng.random_spanning_tree(width * height, &edges);
+
+        assert_eq!(
+            tree.len(),
+            width * height - 1,
+            "a spanning tree should have one edge less than nodes"
+        );
+        assert!(tree.iter().all(|edge| edges.contains(edge)));
+
+        let mut connected = DisjointSet::new(width * height);
+
+        for &(a, b) in &tree {
+            connected.union(a, b);
+        }
+
+        let root = connected.find(0);
+
+        assert!(
+            (0..width * height).all(|node| connected.find(node) == root),
+            "the tree should connect every node"
+        );
+    }
+
+    #[test]
+    fn spanning_tree_deterministic() {
+        let mut rng1 = GlobalEntropy::<ChaCha8Rng>::from_seed([8; 32]);
+        let mut rng2 = GlobalEntropy::<ChaCha8Rng>::from_seed([8; 32]);
+
+        let edges = grid_edges(10, 10);
+
+        assert_eq!(
+            rng1.random_spanning_tree(100, &edges),
+            rng2.random_spanning_tree(100, &edges)
+        );
+
+        let mut other = GlobalEntropy::<ChaCha8Rng>::from_seed([9; 32]);
+
+        assert_ne!(
+            rng1.random_spanning_tree(100, &edges),
+            other.random_spanning_tree(100, &edges),
+            "different seeds should pick different trees"
+        );
+    }
+
+    #[test]
+    fn disconnected_graph() {
+        let mut rng = GlobalEntropy::<ChaCha8Rng>::from_seed([8; 32]);
+
+        let tree = rng.random_spanning_tree(5, &[(0, 1), (1, 2), (0, 2), (3, 4)]);
+
+        assert_eq!(tree.len(), 3, "each connected part should be spanned");
+    }
+
+    fn dead_ends(node_count: usize, maze: &[(usize, usize)]) -> usize {
+        let mut degrees = vec![0; node_count];
+
+        for &(a, b) in maze {
+            degrees[a] += 1;
+            degrees[b] += 1;
+        }
+
+        degrees.iter().filter(|&&degree| degree == 1).count()
+    }
+
+    #[test]
+    fn unbraided_maze_is_perfect() {
+        let mut rng1 = GlobalEntropy::<ChaCha8Rng>::from_seed([8; 32]);
+        let mut rng2 = GlobalEntropy::<ChaCha8Rng>::from_seed([8; 32]);
+
+        let edges = grid_edges(10, 10);
+
+        assert_eq!(
+            rng1.random_braided_maze(100, &edges, 0.0),
+            rng2.random_spanning_tree(100, &edges),
+            "a braid factor of zero should produce a spanning tree"
+        );
+    }
+
+    #[test]
+    fn braiding_removes_dead_ends() {
+        let edges = grid_edges(20, 20);
+
+        let counts: Vec<usize> = [0.0, 0.5, 1.0]
+            .into_iter()
+            .map(|braid_factor| {
+                let mut rng = GlobalEntropy::<ChaCha8Rng>::from_seed([8; 32]);
+
+                let maze = rng.random_braided_maze(400, &edges, braid_factor);
+
+                assert!(maze.iter().all(|&(a, b)| edges.contains(&undirected(a, b))));
+
+                dead_ends(400, &maze)
+            })
+            .collect();
+
+        assert!(
+            counts[0] > counts[1] && counts[1] > counts[2],
+            "higher braid factors should leave fewer dead ends: {counts:?}"
+        );
+        assert_eq!(counts[2], 0, "full braiding should remove every dead end");
+    }
+
+    #[test]
+    fn braided_maze_deterministic() {
+        let mut rng1 = GlobalEntropy::<ChaCha8Rng>::from_seed([8; 32]);
+        let mut rng2 = GlobalEntropy::<ChaCha8Rng>::from_seed([8; 32]);
+
+        let edges = grid_edges(10, 10);
+
+        assert_eq!(
+            rng1.random_braided_maze(100, &edges, 0.5),
+            rng2.random_braided_maze(100, &edges, 0.5)
+        );
+    }
+
+    #[test]
+    fn random_graph_edge_count() {
+        let mut rng = GlobalEntropy::<ChaCha8Rng>::from_seed([8; 32]);
+
+        let (nodes, p) = (200, 0.1);
+
+        let graph = rng.gen_random_graph(nodes, p);
+
+        let pairs = (nodes * (nodes - 1) / 2) as f64;
+        let expected = p * pairs;
+        let deviation = (pairs * p * (1.0 - p)).sqrt();
+
+        assert!(
+            (graph.len() as f64 - expected).abs() < 4.0 * deviation,
+            "{} edges were generated, expected about {expected}",
+            graph.len()
+        );
+        assert!(graph.iter().all(|&(a, b)| a < b && b < nodes));
+        assert!(
+            graph.windows(2).all(|pair| pair[0] < pair[1]),
+            "edges should be ordered and unique"
+        );
+
+        assert!(rng.gen_random_graph(nodes, 0.0).is_empty());
+        assert_eq!(rng.gen_random_graph(10, 1.0).len(), 45);
+    }
+
+    #[test]
+    fn random_graph_deterministic() {
+        let mut rng1 = GlobalEntropy::<ChaCha8Rng>::from_seed([8; 32]);
+        let mut rng2 = GlobalEntropy::<ChaCha8Rng>::from_seed([8; 32]);
+
+        assert_eq!(
+            rng1.gen_random_graph(50, 0.2),
+            rng2.gen_random_graph(50, 0.2)
+        );
+    }
+}
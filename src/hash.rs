@@ -0,0 +1,118 @@
+use crate::{resource::GlobalEntropy, traits::SeedableEntropySource};
+use bevy::utils::{hashbrown, RandomState};
+use rand_core::RngCore;
+
+impl<R: SeedableEntropySource + 'static> GlobalEntropy<R> {
+    /// Generates an `aHash` [`RandomState`] seeded from the RNG, for building hash maps and sets
+    /// that iterate in a reproducible order. Maps built with the default hasher state are seeded
+    /// randomly per run, so iterating them can break determinism. The standard library's
+    /// `RandomState` can't be seeded at all, hence this returning the `aHash` state used by
+    /// `bevy::utils` instead.
+    ///
+    /// For a fixed RNG seed, the hashes, and therefore the iteration order of maps with the same
+    /// contents, are reproducible between runs of the same build on the same platform. `aHash`
+    /// makes no guarantees of the hashes being stable across versions, platforms or CPU features.
+    ///
+    /// ```
+    /// use std::collections::HashMap;
+    /// use bevy_rand::prelude::*;
+    /// use bevy_prng::ChaCha8Rng;
+    /// use rand_core::SeedableRng;
+    ///
+    /// let mut rng = GlobalEntropy::<ChaCha8Rng>::from_seed([1; 32]);
+    ///
+    /// let mut scores = HashMap::with_hasher(rng.gen_random_state());
+    ///
+    /// scores.insert("player", 10);
+    /// ```
+    #[must_use]
+    pub fn gen_random_state(&mut self) -> RandomState {
+        RandomState::with_seeds(
+            self.next_u64(),
+            self.next_u64(),
+            self.next_u64(),
+            self.next_u64(),
+        )
+    }
+
+    /// Creates an empty `hashbrown` map, as used by `bevy::utils`, with its hasher seeded from
+    /// the RNG via [`GlobalEntropy::gen_random_state`]. Maps created from an identically seeded
+    /// `GlobalEntropy` iterate in the same order, tying the iteration order to the world seed,
+    /// while still varying it between differently seeded worlds. The same caveats about the
+    /// stability of `aHash` apply.
+    ///
+    /// ```
+    /// use bevy_rand::prelude::*;
+    /// use bevy_prng::ChaCha8Rng;
+    /// use rand_core::SeedableRng;
+    ///
+    /// let mut rng = GlobalEntropy::<ChaCha8Rng>::from_seed([1; 32]);
+    ///
+    /// let mut scores = rng.deterministic_hashmap();
+    ///
+    /// scores.insert("player", 10);
+    /// ```
+    #[must_use]
+    pub fn deterministic_hashmap<K, V>(&mut self) -> hashbrown::HashMap<K, V, RandomState> {
+        hashbrown::HashMap::with_hasher(self.gen_random_state())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use bevy_prng::ChaCha8Rng;
+    use rand_core::SeedableRng;
+
+    use super::*;
+
+    #[test]
+    fn random_state_iteration_order() {
+        let mut rng1 = GlobalEntropy::<ChaCha8Rng>::from_seed([6; 32]);
+        let mut rng2 = GlobalEntropy::<ChaCha8Rng>::from_seed([6; 32]);
+
+        let mut map1 = HashMap::with_hasher(rng1.gen_random_state());
+        let mut map2 = HashMap::with_hasher(rng2.gen_random_state());
+
+        for key in 0..100u32 {
+            map1.insert(key, key * 2);
+            map2.insert(key, key * 2);
+        }
+
+        assert!(
+            map1.iter().eq(map2.iter()),
+            "maps with identically seeded states should iterate in the same order"
+        );
+
+        let mut map3 = HashMap::with_hasher(rng1.gen_random_state());
+
+        for key in 0..100u32 {
+            map3.insert(key, key * 2);
+        }
+
+        assert!(
+            !map1.keys().eq(map3.keys()),
+            "differently seeded states should iterate in different orders"
+        );
+    }
+
+    #[test]
+    fn deterministic_hashmap_iteration_order() {
+        let mut rng1 = GlobalEntropy::<ChaCha8Rng>::from_seed([6; 32]);
+        let mut rng2 = GlobalEntropy::<ChaCha8Rng>::from_seed([6; 32]);
+
+        let mut map1 = rng1.deterministic_hashmap();
+        let mut map2 = rng2.deterministic_hashmap();
+
+        for key in 0..100u32 {
+            map1.insert(key, key * 2);
+            map2.insert(key, key * 2);
+        }
+
+        assert!(
+            map1.iter().eq(map2.iter()),
+            "maps from identically seeded sources should iterate in the same order"
+        );
+    }
+}
@@ -0,0 +1,171 @@
+use std::fmt;
+
+use crate::{resource::GlobalEntropy, traits::SeedableEntropySource};
+use bevy::render::{render_resource::TextureFormat, texture::Image};
+use rand_core::RngCore;
+
+/// Error returned by [`GlobalEntropy::fill_image`] when the [`Image`] can't be filled.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ImageFillError {
+    /// The [`TextureFormat`] of the image is not one with 8-bit channels.
+    UnsupportedFormat(TextureFormat),
+}
+
+impl fmt::Display for ImageFillError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::UnsupportedFormat(format) => {
+                write!(
+                    f,
+                    "can't fill an image with the unsupported format {format:?}"
+                )
+            }
+        }
+    }
+}
+
+impl std::error::Error for ImageFillError {}
+
+/// Returns the amount of 8-bit channels of a pixel in the given format, and the value of a fully
+/// opaque alpha channel if the last channel is alpha.
+fn channels(format: TextureFormat) -> Option<(usize, Option<u8>)> {
+    match format {
+        TextureFormat::R8Unorm
+        | TextureFormat::R8Snorm
+        | TextureFormat::R8Uint
+        | TextureFormat::R8Sint => Some((1, None)),
+        TextureFormat::Rg8Unorm
+        | TextureFormat::Rg8Snorm
+        | TextureFormat::Rg8Uint
+        | TextureFormat::Rg8Sint => Some((2, None)),
+        TextureFormat::Rgba8Unorm
+        | TextureFormat::Rgba8UnormSrgb
+        | TextureFormat::Rgba8Uint
+        | TextureFormat::Bgra8Unorm
+        | TextureFormat::Bgra8UnormSrgb => Some((4, Some(u8::MAX))),
+        TextureFormat::Rgba8Snorm | TextureFormat::Rgba8Sint => Some((4, Some(i8::MAX as u8))),
+        _ => None,
+    }
+}
+
+impl<R: SeedableEntropySource + 'static> GlobalEntropy<R> {
+    /// Fills the pixel data of an [`Image`] with random colours, such as for generating
+    /// placeholder textures. Every colour channel of every pixel is set to a random byte, while
+    /// alpha channels are set to fully opaque, so the image stays visible. Only formats with
+    /// 8-bit channels are supported, such as [`TextureFormat::Rgba8UnormSrgb`], with any other
+    /// format being left untouched and returning an error.
+    ///
+    /// For the same RNG state and image size, the same pixels are always generated.
+    ///
+    /// ```
+    /// use bevy_rand::prelude::*;
+    /// use bevy_prng::ChaCha8Rng;
+    /// use bevy::render::{
+    ///     render_resource::{Extent3d, TextureDimension, TextureFormat},
+    ///     texture::Image,
+    /// };
+    /// use rand_core::SeedableRng;
+    ///
+    /// let mut rng = GlobalEntropy::<ChaCha8Rng>::from_seed([1; 32]);
+    ///
+    /// let mut image = Image::new_fill(
+    ///     Extent3d { width: 8, height: 8, depth_or_array_layers: 1 },
+    ///     TextureDimension::D2,
+    ///     &[0, 0, 0, 255],
+    ///     TextureFormat::Rgba8UnormSrgb,
+    /// );
+    ///
+    /// assert!(rng.fill_image(&mut image).is_ok());
+    /// ```
+    #[cfg_attr(docsrs, doc(cfg(feature = "image")))]
+    pub fn fill_image(&mut self, image: &mut Image) -> Result<(), ImageFillError> {
+        let format = image.texture_descriptor.format;
+        let (channels, opaque) =
+            channels(format).ok_or(ImageFillError::UnsupportedFormat(format))?;
+
+        self.fill_bytes(&mut image.data);
+
+        if let Some(opaque) = opaque {
+            for pixel in image.data.chunks_exact_mut(channels) {
+                pixel[channels - 1] = opaque;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy::render::{
+        render_resource::{Extent3d, TextureDimension},
+        texture::TextureFormatPixelInfo,
+    };
+    use bevy_prng::ChaCha8Rng;
+    use rand_core::SeedableRng;
+
+    use super::*;
+
+    fn image(format: TextureFormat) -> Image {
+        let size = Extent3d {
+            width: 2,
+            height: 2,
+            depth_or_array_layers: 1,
+        };
+
+        Image::new(
+            size,
+            TextureDimension::D2,
+            vec![0; 4 * format.pixel_size()],
+            format,
+        )
+    }
+
+    #[test]
+    fn fill_rgba_image() {
+        let mut rng = GlobalEntropy::<ChaCha8Rng>::from_seed([6; 32]);
+
+        let mut image = image(TextureFormat::Rgba8UnormSrgb);
+
+        rng.fill_image(&mut image).unwrap();
+
+        assert_eq!(
+            image.data,
+            vec![4, 11, 116, 255, 129, 169, 129, 255, 233, 8, 144, 255, 123, 98, 53, 255]
+        );
+        assert!(
+            image.data.chunks_exact(4).all(|pixel| pixel[3] == u8::MAX),
+            "all pixels should be opaque"
+        );
+    }
+
+    #[test]
+    fn fill_image_deterministic() {
+        let mut rng1 = GlobalEntropy::<ChaCha8Rng>::from_seed([6; 32]);
+        let mut rng2 = GlobalEntropy::<ChaCha8Rng>::from_seed([6; 32]);
+
+        let mut image1 = image(TextureFormat::Rg8Unorm);
+        let mut image2 = image(TextureFormat::Rg8Unorm);
+
+        rng1.fill_image(&mut image1).unwrap();
+        rng2.fill_image(&mut image2).unwrap();
+
+        assert_eq!(image1.data, image2.data);
+    }
+
+    #[test]
+    fn unsupported_format() {
+        let mut rng = GlobalEntropy::<ChaCha8Rng>::from_seed([6; 32]);
+
+        let mut image = image(TextureFormat::Rgba32Float);
+        let untouched = image.data.clone();
+
+        assert_eq!(
+            rng.fill_image(&mut image),
+            Err(ImageFillError::UnsupportedFormat(
+                TextureFormat::Rgba32Float
+            ))
+        );
+        assert_eq!(image.data, untouched, "the image should be left untouched");
+    }
+}
@@ -0,0 +1,166 @@
+use std::marker::PhantomData;
+
+use crate::{plugin::RngSystemSet, resource::GlobalEntropy, traits::SeedableEntropySource};
+use bevy::{
+    asset::{Asset, AssetApp, AssetEvent, Assets},
+    prelude::{App, EventReader, IntoSystemConfigs, Plugin, PreUpdate, Res, ResMut},
+    reflect::TypePath,
+};
+use rand_core::SeedableRng;
+
+/// An asset embedding the seed for a level, or any other unit of content, for tying the
+/// determinism of a [`GlobalEntropy`] resource to the content being loaded. With the
+/// [`LevelSeedPlugin`], the [`GlobalEntropy`] is reseeded from the [`LevelSeed`] as soon as it has
+/// finished loading, so the same level always produces the same random outcomes.
+///
+/// No asset loader is provided for [`LevelSeed`], as how the seed is stored depends on the format
+/// of the level data. Either register a loader for it, or have the loader of the level add it as
+/// a labeled sub-asset of the level.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Asset, TypePath)]
+pub struct LevelSeed {
+    seed: u64,
+}
+
+impl LevelSeed {
+    /// Create a new level seed asset.
+    #[inline]
+    #[must_use]
+    pub fn new(seed: u64) -> Self {
+        Self { seed }
+    }
+
+    /// Returns the embedded seed.
+    #[inline]
+    #[must_use]
+    pub fn seed(&self) -> u64 {
+        self.seed
+    }
+}
+
+/// Plugin for reseeding a [`GlobalEntropy`] resource whenever a [`LevelSeed`] asset has finished
+/// loading along with all of its dependencies. The seed is expanded into a full seed for the PRNG
+/// with [`SeedableRng::seed_from_u64`]. If several [`LevelSeed`]s finish loading in the same
+/// frame, the last one to do so wins. Requires the `AssetPlugin` to be added beforehand.
+///
+/// ```
+/// use bevy::prelude::*;
+/// use bevy::asset::AssetPlugin;
+/// use bevy_rand::prelude::*;
+/// use bevy_rand::level_seed::{LevelSeed, LevelSeedPlugin};
+/// use bevy_prng::ChaCha8Rng;
+///
+/// #[derive(Resource)]
+/// struct CurrentLevel(Handle<LevelSeed>);
+///
+/// fn load_level(mut commands: Commands, assets: Res<AssetServer>) {
+///     commands.insert_resource(CurrentLevel(assets.load("levels/forest.seed")));
+/// }
+///
+/// App::new()
+///     .add_plugins((
+///         MinimalPlugins,
+///         AssetPlugin::default(),
+///         EntropyPlugin::<ChaCha8Rng>::with_seed([1; 32]),
+///         LevelSeedPlugin::<ChaCha8Rng>::default(),
+///     ))
+///     .add_systems(Startup, load_level);
+/// ```
+pub struct LevelSeedPlugin<R: SeedableEntropySource + 'static> {
+    _source: PhantomData<R>,
+}
+
+impl<R: SeedableEntropySource + 'static> LevelSeedPlugin<R> {
+    /// Creates a new plugin instance for reseeding the [`GlobalEntropy`] of `R`.
+    #[inline]
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            _source: PhantomData,
+        }
+    }
+}
+
+impl<R: SeedableEntropySource + 'static> Default for LevelSeedPlugin<R> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<R: SeedableEntropySource + 'static> Plugin for LevelSeedPlugin<R> {
+    fn build(&self, app: &mut App) {
+        // The asset type is shared between the plugins of all PRNGs, so must only be set up once.
+        if !app.world.contains_resource::<Assets<LevelSeed>>() {
+            app.init_asset::<LevelSeed>();
+        }
+
+        app.add_systems(PreUpdate, reseed_on_level_load::<R>.in_set(RngSystemSet));
+    }
+}
+
+fn reseed_on_level_load<R: SeedableEntropySource + 'static>(
+    mut events: EventReader<AssetEvent<LevelSeed>>,
+    levels: Res<Assets<LevelSeed>>,
+    mut rng: ResMut<GlobalEntropy<R>>,
+) {
+    let loaded = events
+        .read()
+        .filter_map(|event| match event {
+            AssetEvent::LoadedWithDependencies { id } => levels.get(*id),
+            _ => None,
+        })
+        .last();
+
+    if let Some(level) = loaded {
+        *rng = GlobalEntropy::seed_from_u64(level.seed());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy::{asset::AssetPlugin, prelude::MinimalPlugins};
+    use bevy_prng::ChaCha8Rng;
+    use rand_core::RngCore;
+
+    use crate::plugin::EntropyPlugin;
+
+    use super::*;
+
+    #[test]
+    fn reseeds_on_level_load() {
+        let mut app = App::new();
+
+        app.add_plugins((
+            MinimalPlugins,
+            AssetPlugin::default(),
+            EntropyPlugin::<ChaCha8Rng>::with_seed([2; 32]),
+            LevelSeedPlugin::<ChaCha8Rng>::default(),
+        ));
+
+        let handle = app
+            .world
+            .resource_mut::<Assets<LevelSeed>>()
+            .add(LevelSeed::new(42));
+
+        app.update();
+
+        assert_eq!(
+            app.world.resource::<GlobalEntropy<ChaCha8Rng>>(),
+            &GlobalEntropy::from_seed([2; 32]),
+            "adding the asset alone should not reseed"
+        );
+
+        app.world
+            .send_event(AssetEvent::LoadedWithDependencies { id: handle.id() });
+
+        app.update();
+
+        let mut expected = GlobalEntropy::<ChaCha8Rng>::seed_from_u64(42);
+        let mut rng = app.world.resource_mut::<GlobalEntropy<ChaCha8Rng>>();
+
+        assert_eq!(
+            rng.next_u64(),
+            expected.next_u64(),
+            "the global source should be reseeded from the level"
+        );
+    }
+}
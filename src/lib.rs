@@ -2,16 +2,111 @@
 #![cfg_attr(docsrs, feature(doc_cfg))]
 #![cfg_attr(docsrs, allow(unused_attributes))]
 #![deny(missing_docs)]
+// The seeding paths themselves call what `ci/deterministic_only/clippy.toml` disallows for users.
+#![cfg_attr(feature = "deterministic_only", allow(clippy::disallowed_methods))]
 #![doc = include_str!("../README.md")]
 
+/// Constant time sampling of indices in proportion to a histogram of counts.
+#[cfg(feature = "rand")]
+#[cfg_attr(docsrs, doc(cfg(feature = "rand")))]
+pub mod alias_table;
+/// Random choices that avoid picking the same item twice in a row.
+#[cfg(feature = "rand")]
+#[cfg_attr(docsrs, doc(cfg(feature = "rand")))]
+pub mod anti_repeat;
+#[cfg(all(feature = "asset", feature = "rand"))]
+mod asset;
+/// Precomputed probability checks for repeatedly rolling the same chance.
+#[cfg(feature = "rand")]
+#[cfg_attr(docsrs, doc(cfg(feature = "rand")))]
+pub mod bernoulli_gate;
+#[cfg(feature = "color")]
+mod color;
+/// Extensions on bevy [`Commands`](bevy::prelude::Commands) for deferred operations on RNGs.
+pub mod commands;
 /// Components for integrating [`RngCore`] PRNGs into bevy. Must be newtyped to support [`Reflect`].
 pub mod component;
+#[cfg(feature = "rand")]
+mod distributions;
+/// Genetic operators for mutating and recombining genomes of `f32` genes.
+#[cfg(feature = "rand")]
+#[cfg_attr(docsrs, doc(cfg(feature = "rand")))]
+pub mod genome;
+#[cfg(feature = "math")]
+mod geometry;
+#[cfg(feature = "rand")]
+mod graph;
+mod hash;
+/// Filling of bevy [`Image`](bevy::render::texture::Image)s with random pixels.
+#[cfg(feature = "image")]
+#[cfg_attr(docsrs, doc(cfg(feature = "image")))]
+pub mod image;
+/// Reseeding of [`resource::GlobalEntropy`] from seeds embedded in level assets.
+#[cfg(feature = "asset")]
+#[cfg_attr(docsrs, doc(cfg(feature = "asset")))]
+pub mod level_seed;
+/// Reproducible name generation from syllable tables.
+#[cfg(feature = "rand")]
+#[cfg_attr(docsrs, doc(cfg(feature = "rand")))]
+pub mod name_generator;
+/// Registry of PRNGs identified by name, all derived from a single master seed.
+pub mod named;
+#[cfg(feature = "rand")]
+mod noise;
+/// Plugin for displaying diagnostics of [`resource::GlobalEntropy`] as an on-screen overlay.
+#[cfg(feature = "overlay")]
+#[cfg_attr(docsrs, doc(cfg(feature = "overlay")))]
+pub mod overlay;
+/// Random permutations of index ranges that are computed on the fly.
+pub mod permutation;
+/// Sampling from designer-authored piecewise-linear distributions.
+#[cfg(feature = "rand")]
+#[cfg_attr(docsrs, doc(cfg(feature = "rand")))]
+pub mod piecewise_linear;
+/// Chance rolls that become more likely with every failure, for pity timers on loot drops.
+#[cfg(feature = "rand")]
+#[cfg_attr(docsrs, doc(cfg(feature = "rand")))]
+pub mod pity_roller;
 /// Plugin for integrating [`RngCore`] PRNGs into bevy. Must be newtyped to support [`Reflect`].
 pub mod plugin;
+/// Sending of events at random times following a Poisson process.
+#[cfg(feature = "rand")]
+#[cfg_attr(docsrs, doc(cfg(feature = "rand")))]
+pub mod poisson_emitter;
+/// Pooling of [`component::EntropyComponent`]s for recycling RNG instances.
+pub mod pool;
 /// Prelude for providing all necessary types for easy use.
 pub mod prelude;
+/// Recording of the operations performed on RNGs, for debugging nondeterminism.
+#[cfg(feature = "recorder")]
+#[cfg_attr(docsrs, doc(cfg(feature = "recorder")))]
+pub mod recorder;
+#[cfg(feature = "rand")]
+mod reflect;
 /// Resource for integrating [`RngCore`] PRNGs into bevy. Must be newtyped to support [`Reflect`].
 pub mod resource;
+/// Errors and utilities for seeding PRNGs.
+pub mod seed;
+#[cfg(feature = "rand")]
+mod seq;
+/// Shuffle bags for drawing items with target frequencies while avoiding streaks.
+#[cfg(feature = "rand")]
+#[cfg_attr(docsrs, doc(cfg(feature = "rand")))]
+pub mod shuffle_bag;
+/// Helper systems for driving other systems with random inputs and reseeding RNGs in bulk.
+pub mod systems;
+/// Utilities for setting up deterministic tests with [`bevy_rand`](crate).
+#[cfg(feature = "test_utils")]
+#[cfg_attr(docsrs, doc(cfg(feature = "test_utils")))]
+pub mod test_utils;
+#[cfg(feature = "rand")]
+mod text;
 #[cfg(feature = "thread_local_entropy")]
+#[cfg_attr(feature = "deterministic_only", allow(dead_code))]
 mod thread_local_entropy;
+#[cfg(feature = "rand")]
+mod time;
 mod traits;
+
+#[cfg(feature = "thread_local_entropy")]
+pub use thread_local_entropy::set_fallback_seed_source;
@@ -0,0 +1,195 @@
+use std::ops::RangeInclusive;
+
+use bevy::prelude::{Reflect, ReflectFromReflect};
+use rand::{seq::SliceRandom, Rng};
+use rand_core::RngCore;
+
+#[cfg(feature = "serialize")]
+use bevy::prelude::{ReflectDeserialize, ReflectSerialize};
+
+#[cfg(feature = "serialize")]
+use serde::{Deserialize, Serialize};
+
+/// A generator for reproducible names assembled from syllable tables, for procedurally
+/// naming things like NPCs or places. A name is built from a random number of syllables,
+/// starting with a syllable from the `starts` table, ending with one from the `ends` table,
+/// and filled with syllables from the `middles` table in between. Single syllable names
+/// are drawn from the `starts` table only. The first letter of every name is capitalised.
+///
+/// Given the same RNG state, the same name is always generated.
+///
+/// ```
+/// use bevy_rand::prelude::*;
+/// use bevy_prng::ChaCha8Rng;
+/// use rand_core::SeedableRng;
+///
+/// let generator = NameGenerator::new(
+///     ["ka", "zo", "mi"],
+///     ["ra", "lu"],
+///     ["n", "th", "ra"],
+/// );
+///
+/// let mut rng = GlobalEntropy::<ChaCha8Rng>::from_seed([1; 32]);
+///
+/// let name = generator.generate(&mut rng);
+///
+/// assert!(!name.is_empty());
+/// ```
+#[derive(Debug, Clone, PartialEq, Reflect)]
+#[cfg_attr(feature = "serialize", derive(Serialize, Deserialize))]
+#[cfg_attr(
+    feature = "serialize",
+    reflect(Debug, PartialEq, FromReflect, Serialize, Deserialize)
+)]
+#[cfg_attr(not(feature = "serialize"), reflect(Debug, PartialEq, FromReflect))]
+pub struct NameGenerator {
+    starts: Vec<String>,
+    middles: Vec<String>,
+    ends: Vec<String>,
+    min_syllables: u32,
+    max_syllables: u32,
+}
+
+impl NameGenerator {
+    /// Create a new generator from the given syllable tables, generating names of two to
+    /// three syllables.
+    #[must_use]
+    pub fn new(
+        starts: impl IntoIterator<Item = impl Into<String>>,
+        middles: impl IntoIterator<Item = impl Into<String>>,
+        ends: impl IntoIterator<Item = impl Into<String>>,
+    ) -> Self {
+        Self {
+            starts: starts.into_iter().map(Into::into).collect(),
+            middles: middles.into_iter().map(Into::into).collect(),
+            ends: ends.into_iter().map(Into::into).collect(),
+            min_syllables: 2,
+            max_syllables: 3,
+        }
+    }
+
+    /// Configures the inclusive range of how many syllables a generated name can have.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the range is empty or starts at zero syllables.
+    #[must_use]
+    pub fn with_syllables(mut self, syllables: RangeInclusive<u32>) -> Self {
+        assert!(
+            *syllables.start() > 0 && !syllables.is_empty(),
+            "names must have at least one syllable"
+        );
+
+        self.min_syllables = *syllables.start();
+        self.max_syllables = *syllables.end();
+        self
+    }
+
+    /// Generates a new name from the syllable tables. Empty tables contribute no syllables.
+    pub fn generate(&self, rng: &mut impl RngCore) -> String {
+        let count = rng.gen_range(self.min_syllables..=self.max_syllables);
+
+        let mut name = String::new();
+
+        if let Some(start) = self.starts.choose(rng) {
+            name.push_str(start);
+        }
+
+        if count > 1 {
+            for _ in 0..count - 2 {
+                if let Some(middle) = self.middles.choose(rng) {
+                    name.push_str(middle);
+                }
+            }
+
+            if let Some(end) = self.ends.choose(rng) {
+                name.push_str(end);
+            }
+        }
+
+        capitalise(&name)
+    }
+}
+
+fn capitalise(name: &str) -> String {
+    let mut chars = name.chars();
+
+    chars.next().map_or_else(String::new, |first| {
+        first.to_uppercase().chain(chars).collect()
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+
+    use bevy_prng::ChaCha8Rng;
+    use rand_core::SeedableRng;
+
+    use crate::resource::GlobalEntropy;
+
+    use super::*;
+
+    fn generator() -> NameGenerator {
+        NameGenerator::new(
+            ["ka", "zo", "mi", "el"],
+            ["ra", "lu", "no"],
+            ["n", "th", "ra", "dir"],
+        )
+    }
+
+    #[test]
+    fn stable_name_for_seed() {
+        let mut rng = GlobalEntropy::<ChaCha8Rng>::from_seed([1; 32]);
+
+        assert_eq!(generator().generate(&mut rng), "Kara");
+    }
+
+    #[test]
+    fn names_vary_with_seed() {
+        let generator = generator();
+
+        let names: HashSet<_> = (0..16u8)
+            .map(|seed| generator.generate(&mut GlobalEntropy::<ChaCha8Rng>::from_seed([seed; 32])))
+            .collect();
+
+        assert!(
+            names.len() > 1,
+            "different seeds should produce different names"
+        );
+    }
+
+    #[test]
+    fn syllable_counts() {
+        let mut rng = GlobalEntropy::<ChaCha8Rng>::from_seed([1; 32]);
+
+        let single = NameGenerator::new(["ka"], ["ra"], ["n"]).with_syllables(1..=1);
+
+        assert_eq!(single.generate(&mut rng), "Ka");
+
+        let long = NameGenerator::new(["ka"], ["ra"], ["n"]).with_syllables(4..=4);
+
+        assert_eq!(long.generate(&mut rng), "Kararan");
+    }
+
+    #[test]
+    fn empty_tables() {
+        let mut rng = GlobalEntropy::<ChaCha8Rng>::from_seed([1; 32]);
+
+        let empty = NameGenerator::new([] as [&str; 0], [] as [&str; 0], [] as [&str; 0]);
+
+        assert!(empty.generate(&mut rng).is_empty());
+    }
+
+    #[cfg(feature = "serialize")]
+    #[test]
+    fn serde_roundtrip() {
+        let generator = generator();
+
+        let serialized = ron::to_string(&generator).unwrap();
+
+        let deserialized: NameGenerator = ron::from_str(&serialized).unwrap();
+
+        assert_eq!(generator, deserialized);
+    }
+}
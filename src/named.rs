@@ -0,0 +1,121 @@
+use crate::{seed::mix_seeds, traits::SeedableEntropySource};
+use bevy::{prelude::Resource, utils::HashMap};
+
+/// A registry of PRNGs identified by name, such as one per subsystem like terrain, weather or
+/// loot, all derived from a single master seed. The seed of every named PRNG is derived
+/// deterministically from the master seed and the name alone, so each name always yields the
+/// same stream regardless of which other names were used or in which order they were first
+/// accessed, and streams for different names are independent of each other.
+///
+/// ```
+/// use bevy::prelude::*;
+/// use bevy_rand::prelude::*;
+/// use bevy_prng::ChaCha8Rng;
+/// use rand_core::RngCore;
+///
+/// fn generate_terrain(mut rngs: ResMut<NamedRngs<ChaCha8Rng>>) {
+///     let height = rngs.get_mut("terrain").next_u32() % 100;
+///
+///     println!("Terrain height: {height}");
+/// }
+///
+/// App::new()
+///     .insert_resource(NamedRngs::<ChaCha8Rng>::new([1; 32]))
+///     .add_systems(Update, generate_terrain);
+/// ```
+#[derive(Debug, Resource)]
+pub struct NamedRngs<R: SeedableEntropySource + 'static> {
+    // Stored as bytes, as `R::Seed` is not guaranteed to be `Send + Sync`.
+    master_seed: Box<[u8]>,
+    rngs: HashMap<Box<str>, R>,
+}
+
+impl<R: SeedableEntropySource + 'static> NamedRngs<R> {
+    /// Create a new, empty registry that derives the seeds of its PRNGs from `master_seed`.
+    #[inline]
+    #[must_use]
+    pub fn new(mut master_seed: R::Seed) -> Self {
+        Self {
+            master_seed: master_seed.as_mut().into(),
+            rngs: HashMap::default(),
+        }
+    }
+
+    /// Returns the PRNG for `name`, creating it from the seed derived for `name` on first access.
+    pub fn get_mut(&mut self, name: &str) -> &mut R {
+        let master_seed = &self.master_seed;
+
+        self.rngs
+            .raw_entry_mut()
+            .from_key(name)
+            .or_insert_with(|| {
+                let mut seed = R::Seed::default();
+
+                mix_seeds(&[master_seed, name.as_bytes()], seed.as_mut());
+
+                (name.into(), R::from_seed(seed))
+            })
+            .1
+    }
+
+    /// Returns `true` if the PRNG for `name` has been accessed and so exists in the registry.
+    #[inline]
+    #[must_use]
+    pub fn contains(&self, name: &str) -> bool {
+        self.rngs.contains_key(name)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy_prng::ChaCha8Rng;
+    use rand_core::RngCore;
+
+    use super::*;
+
+    #[test]
+    fn named_streams_reproducible() {
+        let mut rngs1 = NamedRngs::<ChaCha8Rng>::new([4; 32]);
+        let mut rngs2 = NamedRngs::<ChaCha8Rng>::new([4; 32]);
+
+        // Access the names in different orders, as the streams should only depend on the name.
+        let terrain1 = rngs1.get_mut("terrain").next_u64();
+        let weather1 = rngs1.get_mut("weather").next_u64();
+
+        let weather2 = rngs2.get_mut("weather").next_u64();
+        let terrain2 = rngs2.get_mut("terrain").next_u64();
+
+        assert_eq!(terrain1, terrain2);
+        assert_eq!(weather1, weather2);
+
+        assert_eq!(
+            rngs1.get_mut("terrain").next_u64(),
+            rngs2.get_mut("terrain").next_u64(),
+            "the same name should keep returning the same stream"
+        );
+    }
+
+    #[test]
+    fn named_streams_independent() {
+        let mut rngs = NamedRngs::<ChaCha8Rng>::new([4; 32]);
+
+        assert!(!rngs.contains("loot"));
+
+        let loot = rngs.get_mut("loot").clone();
+        let weather = rngs.get_mut("weather").clone();
+
+        assert!(rngs.contains("loot"));
+        assert_ne!(
+            loot, weather,
+            "different names should yield different streams"
+        );
+
+        let mut other = NamedRngs::<ChaCha8Rng>::new([5; 32]);
+
+        assert_ne!(
+            &loot,
+            other.get_mut("loot"),
+            "different master seeds should yield different streams"
+        );
+    }
+}
@@ -0,0 +1,182 @@
+use crate::{resource::GlobalEntropy, traits::SeedableEntropySource};
+use rand::{seq::SliceRandom, Rng};
+
+/// The spacing in pixels between the random lattice points of [`GlobalEntropy::fill_value_noise`].
+const VALUE_NOISE_CELL_SIZE: usize = 16;
+
+fn smoothstep(t: f32) -> f32 {
+    t * t * (3.0 - 2.0 * t)
+}
+
+fn lerp(a: f32, b: f32, t: f32) -> f32 {
+    a + (b - a) * t
+}
+
+impl<R: SeedableEntropySource + 'static> GlobalEntropy<R> {
+    /// Generates a `width * height` buffer of coherent value noise in the range of `0.0..=1.0`,
+    /// for use as a building block of procedural textures and heightmaps. Unlike white noise,
+    /// neighbouring values are correlated: random values are placed on a lattice with a point
+    /// every 16 pixels, and every pixel is smoothly interpolated between the four lattice points
+    /// surrounding it. The buffer is laid out in rows, so the value at `(x, y)` is at index
+    /// `y * width + x`.
+    ///
+    /// For the same RNG state and dimensions, the same noise is always generated.
+    ///
+    /// ```
+    /// use bevy_rand::prelude::*;
+    /// use bevy_prng::ChaCha8Rng;
+    /// use rand_core::SeedableRng;
+    ///
+    /// let mut rng = GlobalEntropy::<ChaCha8Rng>::from_seed([1; 32]);
+    ///
+    /// let noise = rng.fill_value_noise(64, 32);
+    ///
+    /// assert_eq!(noise.len(), 64 * 32);
+    /// ```
+    #[cfg_attr(docsrs, doc(cfg(feature = "rand")))]
+    pub fn fill_value_noise(&mut self, width: usize, height: usize) -> Vec<f32> {
+        if width == 0 || height == 0 {
+            return Vec::new();
+        }
+
+        let columns = (width - 1) / VALUE_NOISE_CELL_SIZE + 2;
+        let rows = (height - 1) / VALUE_NOISE_CELL_SIZE + 2;
+
+        let lattice: Vec<f32> = (0..columns * rows).map(|_| self.gen()).collect();
+
+        let mut noise = Vec::with_capacity(width * height);
+
+        for y in 0..height {
+            let row = y / VALUE_NOISE_CELL_SIZE;
+            let ty = smoothstep((y % VALUE_NOISE_CELL_SIZE) as f32 / VALUE_NOISE_CELL_SIZE as f32);
+
+            for x in 0..width {
+                let column = x / VALUE_NOISE_CELL_SIZE;
+                let tx =
+                    smoothstep((x % VALUE_NOISE_CELL_SIZE) as f32 / VALUE_NOISE_CELL_SIZE as f32);
+
+                let corner = |dx: usize, dy: usize| lattice[(row + dy) * columns + column + dx];
+
+                let top = lerp(corner(0, 0), corner(1, 0), tx);
+                let bottom = lerp(corner(0, 1), corner(1, 1), tx);
+
+                noise.push(lerp(top, bottom, ty));
+            }
+        }
+
+        noise
+    }
+
+    /// Generates a permutation table of the values `0..=255` in a random order, such as the
+    /// table of gradient indices that Perlin and simplex noise implementations are seeded with.
+    /// This allows noise generators to be seeded from the same RNG as the rest of the world,
+    /// so the same world seed always generates the same noise.
+    ///
+    /// ```
+    /// use bevy_rand::prelude::*;
+    /// use bevy_prng::ChaCha8Rng;
+    /// use rand_core::SeedableRng;
+    ///
+    /// let mut rng = GlobalEntropy::<ChaCha8Rng>::from_seed([1; 32]);
+    ///
+    /// let table = rng.gen_permutation_table();
+    ///
+    /// assert!((0..=255).all(|value| table.contains(&value)));
+    /// ```
+    #[cfg_attr(docsrs, doc(cfg(feature = "rand")))]
+    pub fn gen_permutation_table(&mut self) -> [u8; 256] {
+        let mut table = [0; 256];
+
+        for (value, entry) in (0..=u8::MAX).zip(table.iter_mut()) {
+            *entry = value;
+        }
+
+        table.shuffle(self);
+
+        table
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy_prng::ChaCha8Rng;
+    use rand_core::SeedableRng;
+
+    use super::*;
+
+    #[test]
+    fn value_noise_dimensions() {
+        let mut rng = GlobalEntropy::<ChaCha8Rng>::from_seed([9; 32]);
+
+        for (width, height) in [(1, 1), (16, 16), (17, 3), (100, 40)] {
+            let noise = rng.fill_value_noise(width, height);
+
+            assert_eq!(noise.len(), width * height);
+            assert!(noise.iter().all(|value| (0.0..=1.0).contains(value)));
+        }
+
+        assert!(rng.fill_value_noise(0, 10).is_empty());
+    }
+
+    #[test]
+    fn value_noise_deterministic() {
+        let mut rng1 = GlobalEntropy::<ChaCha8Rng>::from_seed([9; 32]);
+        let mut rng2 = GlobalEntropy::<ChaCha8Rng>::from_seed([9; 32]);
+
+        assert_eq!(rng1.fill_value_noise(48, 20), rng2.fill_value_noise(48, 20));
+        assert_ne!(
+            rng1.fill_value_noise(48, 20),
+            rng2.fill_value_noise(20, 48),
+            "different dimensions should produce different noise"
+        );
+    }
+
+    #[test]
+    fn value_noise_is_coherent() {
+        let mut rng = GlobalEntropy::<ChaCha8Rng>::from_seed([9; 32]);
+
+        let width = 64;
+        let noise = rng.fill_value_noise(width, 64);
+
+        // Neighbouring pixels can be at most a fraction of a lattice cell apart, unlike white noise
+        for y in 0..64 {
+            for x in 1..width {
+                let step = (noise[y * width + x] - noise[y * width + x - 1]).abs();
+
+                assert!(step < 0.15, "neighbours at ({x}, {y}) differ by {step}");
+            }
+        }
+    }
+
+    #[test]
+    fn permutation_table_is_valid() {
+        let mut rng = GlobalEntropy::<ChaCha8Rng>::from_seed([9; 32]);
+
+        let table = rng.gen_permutation_table();
+
+        let mut sorted = table;
+        sorted.sort_unstable();
+
+        assert!(
+            sorted
+                .iter()
+                .enumerate()
+                .all(|(index, &value)| index == value as usize),
+            "every value should appear exactly once"
+        );
+        assert_ne!(table, sorted, "the table should be shuffled");
+    }
+
+    #[test]
+    fn permutation_table_deterministic() {
+        let mut rng1 = GlobalEntropy::<ChaCha8Rng>::from_seed([9; 32]);
+        let mut rng2 = GlobalEntropy::<ChaCha8Rng>::from_seed([9; 32]);
+
+        assert_eq!(rng1.gen_permutation_table(), rng2.gen_permutation_table());
+        assert_ne!(
+            rng1.gen_permutation_table(),
+            rng1.gen_permutation_table(),
+            "successive tables should differ"
+        );
+    }
+}
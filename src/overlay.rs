@@ -0,0 +1,236 @@
+use std::{fmt::Write, marker::PhantomData};
+
+use crate::{resource::GlobalEntropy, traits::SeedableEntropySource};
+use bevy::prelude::{
+    App, Commands, Component, Plugin, Query, Res, Resource, Startup, TextBundle, TextStyle, Update,
+    Visibility, With,
+};
+use bevy::text::Text;
+use bevy_prng::SeekableRng;
+use rand_core::RngCore;
+
+/// Plugin for displaying diagnostics of a [`GlobalEntropy`] resource as an on-screen UI text
+/// overlay, for debugging purposes. The overlay shows a redacted fingerprint of the seed, the
+/// current word position within the output stream, and how many bytes have been consumed from
+/// it, updated every frame. Requires the `GlobalEntropy` to be a [`SeekableRng`].
+///
+/// The overlay can be toggled on or off via the [`EntropyOverlay`] resource.
+///
+/// ```
+/// use bevy::prelude::*;
+/// use bevy_rand::prelude::*;
+/// use bevy_rand::overlay::{EntropyOverlay, EntropyOverlayPlugin};
+/// use bevy_prng::ChaCha8Rng;
+///
+/// fn toggle_overlay(keys: Res<Input<KeyCode>>, mut overlay: ResMut<EntropyOverlay>) {
+///     if keys.just_pressed(KeyCode::F3) {
+///         overlay.toggle();
+///     }
+/// }
+///
+/// App::new()
+///     .add_plugins((
+///         EntropyPlugin::<ChaCha8Rng>::with_seed([1; 32]),
+///         EntropyOverlayPlugin::<ChaCha8Rng>::default(),
+///     ))
+///     .add_systems(Update, toggle_overlay);
+/// ```
+pub struct EntropyOverlayPlugin<R: SeedableEntropySource + SeekableRng + 'static> {
+    _source: PhantomData<R>,
+}
+
+impl<R: SeedableEntropySource + SeekableRng + 'static> EntropyOverlayPlugin<R> {
+    /// Creates a new overlay plugin instance for the [`GlobalEntropy`] of `R`.
+    #[inline]
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            _source: PhantomData,
+        }
+    }
+}
+
+impl<R: SeedableEntropySource + SeekableRng + 'static> Default for EntropyOverlayPlugin<R> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<R: SeedableEntropySource + SeekableRng + 'static> Plugin for EntropyOverlayPlugin<R> {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<EntropyOverlay>()
+            .add_systems(Startup, spawn_overlay::<R>)
+            .add_systems(Update, update_overlay::<R>);
+    }
+}
+
+/// Resource for toggling the visibility of the overlays added by [`EntropyOverlayPlugin`].
+/// Overlays are visible by default.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Resource)]
+pub struct EntropyOverlay {
+    visible: bool,
+}
+
+impl EntropyOverlay {
+    /// Returns whether the overlay is currently visible.
+    #[inline]
+    #[must_use]
+    pub fn is_visible(&self) -> bool {
+        self.visible
+    }
+
+    /// Shows or hides the overlay.
+    #[inline]
+    pub fn set_visible(&mut self, visible: bool) {
+        self.visible = visible;
+    }
+
+    /// Toggles the overlay between being visible and hidden.
+    #[inline]
+    pub fn toggle(&mut self) {
+        self.visible = !self.visible;
+    }
+}
+
+impl Default for EntropyOverlay {
+    fn default() -> Self {
+        Self { visible: true }
+    }
+}
+
+/// Marker component for the text entity of an [`EntropyOverlayPlugin`] overlay.
+#[derive(Debug, Component)]
+pub struct EntropyOverlayText<R: SeedableEntropySource + SeekableRng + 'static> {
+    _source: PhantomData<R>,
+}
+
+fn spawn_overlay<R: SeedableEntropySource + SeekableRng + 'static>(mut commands: Commands) {
+    commands.spawn((
+        TextBundle::from_section(String::new(), TextStyle::default()),
+        EntropyOverlayText::<R> {
+            _source: PhantomData,
+        },
+    ));
+}
+
+fn update_overlay<R: SeedableEntropySource + SeekableRng + 'static>(
+    overlay: Res<EntropyOverlay>,
+    rng: Option<Res<GlobalEntropy<R>>>,
+    mut q_text: Query<(&mut Text, &mut Visibility), With<EntropyOverlayText<R>>>,
+) {
+    for (mut text, mut visibility) in &mut q_text {
+        *visibility = if overlay.visible {
+            Visibility::Inherited
+        } else {
+            Visibility::Hidden
+        };
+
+        if let (true, Some(rng)) = (overlay.visible, rng.as_deref()) {
+            let section = &mut text.sections[0].value;
+
+            section.clear();
+
+            write_diagnostics(section, rng);
+        }
+    }
+}
+
+fn write_diagnostics<R: SeedableEntropySource + SeekableRng + 'static>(
+    out: &mut String,
+    rng: &GlobalEntropy<R>,
+) {
+    let mut rewound = rng.branch();
+    let word_pos = rewound.get_word_pos();
+
+    // The first output word of the stream identifies the seed without revealing it.
+    rewound.set_word_pos(0);
+
+    let _ = write!(
+        out,
+        "{}\nseed: #{:08x}\nword position: {word_pos}\nbytes consumed: {}",
+        R::short_type_path(),
+        rewound.next_u32(),
+        word_pos.saturating_mul(4)
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy::prelude::MinimalPlugins;
+    use bevy_prng::ChaCha8Rng;
+
+    use crate::plugin::EntropyPlugin;
+
+    use super::*;
+
+    fn overlay_text(app: &mut App) -> (String, Visibility) {
+        let mut q_text = app
+            .world
+            .query_filtered::<(&Text, &Visibility), With<EntropyOverlayText<ChaCha8Rng>>>();
+
+        let (text, visibility) = q_text.single(&app.world);
+
+        (text.sections[0].value.clone(), *visibility)
+    }
+
+    #[test]
+    fn overlay_reflects_word_position() {
+        let mut app = App::new();
+
+        app.add_plugins((
+            MinimalPlugins,
+            EntropyPlugin::<ChaCha8Rng>::with_seed([2; 32]),
+            EntropyOverlayPlugin::<ChaCha8Rng>::default(),
+        ));
+
+        app.update();
+
+        let (text, _) = overlay_text(&mut app);
+
+        assert!(
+            text.contains("word position: 0\n"),
+            "overlay should show the initial position: {text}"
+        );
+
+        app.world
+            .resource_mut::<GlobalEntropy<ChaCha8Rng>>()
+            .next_u64();
+
+        app.update();
+
+        let (text, _) = overlay_text(&mut app);
+
+        assert!(
+            text.contains("word position: 2\nbytes consumed: 8"),
+            "overlay should follow the position of the RNG: {text}"
+        );
+        assert!(
+            !text.contains("0202"),
+            "overlay should not show the raw seed: {text}"
+        );
+    }
+
+    #[test]
+    fn overlay_toggle() {
+        let mut app = App::new();
+
+        app.add_plugins((
+            MinimalPlugins,
+            EntropyPlugin::<ChaCha8Rng>::with_seed([2; 32]),
+            EntropyOverlayPlugin::<ChaCha8Rng>::default(),
+        ));
+
+        app.update();
+
+        assert_eq!(overlay_text(&mut app).1, Visibility::Inherited);
+
+        app.world.resource_mut::<EntropyOverlay>().toggle();
+        app.update();
+
+        assert_eq!(
+            overlay_text(&mut app).1,
+            Visibility::Hidden,
+            "toggling should hide the overlay"
+        );
+    }
+}
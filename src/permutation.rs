@@ -0,0 +1,167 @@
+use rand_core::RngCore;
+
+const ROUNDS: usize = 4;
+
+/// The round function of the Feistel network, mixing `value` with the round key via the
+/// `SplitMix64` finaliser.
+fn round(value: u64, key: u64, mask: u64) -> u64 {
+    let mut x = value ^ key;
+
+    x = (x ^ (x >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+    x = (x ^ (x >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+
+    (x ^ (x >> 31)) & mask
+}
+
+/// A random permutation of the index range `0..size`, computed on the fly instead of being
+/// materialised, so ranges far too large to shuffle in memory, such as billions of indices, can
+/// still be visited in a random order. Indices are permuted by a balanced Feistel network keyed
+/// from an RNG, which is a bijection over the smallest power of four covering the range, with
+/// outputs that fall outside of the range being cycle-walked back into it.
+///
+/// Permuting an index takes constant memory, and on average less than four passes through the
+/// network. For the same RNG state and size, the same permutation is always produced.
+///
+/// ```
+/// use bevy_rand::prelude::*;
+/// use bevy_prng::ChaCha8Rng;
+/// use rand_core::SeedableRng;
+///
+/// let mut rng = GlobalEntropy::<ChaCha8Rng>::from_seed([1; 32]);
+///
+/// let permutation = Permutation::new(&mut rng, 10_000_000_000);
+///
+/// let first = permutation.apply(0);
+///
+/// assert!(first < 10_000_000_000);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Permutation {
+    size: u64,
+    half_bits: u32,
+    keys: [u64; ROUNDS],
+}
+
+impl Permutation {
+    /// Create a new random permutation of `0..size`, keyed from `rng`.
+    #[must_use]
+    pub fn new(rng: &mut impl RngCore, size: u64) -> Self {
+        let bits = u64::BITS - size.saturating_sub(1).leading_zeros();
+
+        Self {
+            size,
+            half_bits: ((bits + 1) / 2).max(1),
+            keys: std::array::from_fn(|_| rng.next_u64()),
+        }
+    }
+
+    /// Returns the size of the permuted range.
+    #[inline]
+    #[must_use]
+    pub fn size(&self) -> u64 {
+        self.size
+    }
+
+    /// Returns the position that `index` is moved to by the permutation.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is not within `0..size`.
+    #[must_use]
+    pub fn apply(&self, index: u64) -> u64 {
+        assert!(
+            index < self.size,
+            "index {index} is out of the permutation's range of 0..{}",
+            self.size
+        );
+
+        let mut value = index;
+
+        // The network permutes the whole domain, so following any value out of the range
+        // eventually leads back into it.
+        loop {
+            value = self.feistel(value);
+
+            if value < self.size {
+                return value;
+            }
+        }
+    }
+
+    fn feistel(&self, value: u64) -> u64 {
+        let mask = u64::MAX >> (u64::BITS - self.half_bits);
+
+        let mut left = value >> self.half_bits;
+        let mut right = value & mask;
+
+        for key in self.keys {
+            (left, right) = (right, left ^ round(right, key, mask));
+        }
+
+        (left << self.half_bits) | right
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+
+    use bevy_prng::ChaCha8Rng;
+    use rand_core::SeedableRng;
+
+    use crate::resource::GlobalEntropy;
+
+    use super::*;
+
+    #[test]
+    fn bijection() {
+        let mut rng = GlobalEntropy::<ChaCha8Rng>::from_seed([3; 32]);
+
+        for size in [1, 2, 3, 10, 64, 100, 1000] {
+            let permutation = Permutation::new(&mut rng, size);
+
+            let outputs: HashSet<u64> = (0..size).map(|index| permutation.apply(index)).collect();
+
+            assert_eq!(
+                outputs,
+                (0..size).collect(),
+                "a permutation of {size} should be a bijection"
+            );
+        }
+    }
+
+    #[test]
+    fn shuffles_order() {
+        let mut rng = GlobalEntropy::<ChaCha8Rng>::from_seed([3; 32]);
+
+        let permutation = Permutation::new(&mut rng, 100);
+
+        assert!(
+            (0..100).any(|index| permutation.apply(index) != index),
+            "the range should be shuffled"
+        );
+    }
+
+    #[test]
+    fn huge_ranges() {
+        let mut rng = GlobalEntropy::<ChaCha8Rng>::from_seed([3; 32]);
+
+        let permutation = Permutation::new(&mut rng, u64::MAX);
+
+        for index in [0, 1, u64::MAX / 2, u64::MAX - 1] {
+            assert!(permutation.apply(index) < u64::MAX);
+        }
+    }
+
+    #[test]
+    fn deterministic() {
+        let mut rng1 = GlobalEntropy::<ChaCha8Rng>::from_seed([3; 32]);
+        let mut rng2 = GlobalEntropy::<ChaCha8Rng>::from_seed([3; 32]);
+
+        let permutation1 = Permutation::new(&mut rng1, 5000);
+        let permutation2 = Permutation::new(&mut rng2, 5000);
+
+        assert_eq!(permutation1, permutation2);
+        assert!((0..5000).all(|index| permutation1.apply(index) == permutation2.apply(index)));
+    }
+}
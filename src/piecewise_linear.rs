@@ -0,0 +1,176 @@
+use rand::Rng;
+use rand_core::RngCore;
+
+/// A continuous distribution whose density follows a piecewise-linear curve through a set of
+/// control points, such as for designer-authored distributions of spawn distances or loot
+/// values. Each control point is a `(value, weight)` pair, with the density varying linearly
+/// in weight between neighbouring points, and being zero outside of the range covered by the
+/// points. Samples are drawn by inverting the cumulative distribution function, which is
+/// precomputed when building the distribution, so it should be kept around and reused.
+///
+/// For the same RNG state and control points, the same values are always sampled.
+///
+/// ```
+/// use bevy_rand::prelude::*;
+/// use bevy_prng::ChaCha8Rng;
+/// use rand_core::SeedableRng;
+///
+/// let mut rng = GlobalEntropy::<ChaCha8Rng>::from_seed([1; 32]);
+///
+/// // Mostly close by, with a long tail of far away spawns.
+/// let distance = PiecewiseLinearDist::new(vec![(0.0, 0.0), (5.0, 1.0), (50.0, 0.0)]).unwrap();
+///
+/// let sample = distance.sample(&mut rng);
+///
+/// assert!((0.0..=50.0).contains(&sample));
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct PiecewiseLinearDist {
+    points: Vec<(f32, f32)>,
+    cumulative: Vec<f64>,
+}
+
+impl PiecewiseLinearDist {
+    /// Create a new distribution from `(value, weight)` control points, which are sorted by
+    /// value. Returns `None` if there are fewer than two points, if any value or weight is not
+    /// finite, if any weight is negative, or if the curve encloses no area, as there is
+    /// nothing to sample.
+    #[must_use]
+    pub fn new(mut points: Vec<(f32, f32)>) -> Option<Self> {
+        let valid = points.len() >= 2
+            && points
+                .iter()
+                .all(|&(value, weight)| value.is_finite() && weight.is_finite() && weight >= 0.0);
+
+        if !valid {
+            return None;
+        }
+
+        points.sort_by(|a, b| a.0.total_cmp(&b.0));
+
+        // The area under every segment, accumulated, forms the unnormalised CDF at each point.
+        let mut total = 0.0;
+        let cumulative: Vec<f64> = points
+            .windows(2)
+            .map(|segment| {
+                let ((x0, w0), (x1, w1)) = (segment[0], segment[1]);
+
+                total += f64::from(x1 - x0) * f64::from(w0 + w1) / 2.0;
+
+                total
+            })
+            .collect();
+
+        (total > 0.0).then_some(Self { points, cumulative })
+    }
+
+    /// Returns the control points of the distribution, sorted by value.
+    #[inline]
+    #[must_use]
+    pub fn points(&self) -> &[(f32, f32)] {
+        &self.points
+    }
+
+    /// Samples a value following the density of the curve.
+    pub fn sample(&self, rng: &mut impl RngCore) -> f32 {
+        let total = self.cumulative[self.cumulative.len() - 1];
+        let target = rng.gen::<f64>() * total;
+
+        // Segments without any area are never chosen, as their cumulative area doesn't exceed
+        // that of the segment before them.
+        let segment = self
+            .cumulative
+            .partition_point(|&area| area <= target)
+            .min(self.cumulative.len() - 1);
+
+        let before = segment
+            .checked_sub(1)
+            .map_or(0.0, |previous| self.cumulative[previous]);
+
+        let ((x0, w0), (x1, w1)) = (self.points[segment], self.points[segment + 1]);
+        let (w0, width) = (f64::from(w0), f64::from(x1 - x0));
+        let slope = (f64::from(w1) - w0) / width;
+        let area = target - before;
+
+        // Solves `w0 * t + slope * t^2 / 2 = area` for the offset `t` into the segment, in a
+        // form that stays stable for flat segments.
+        let denominator = w0 + (w0 * w0 + 2.0 * slope * area).max(0.0).sqrt();
+        let offset = if denominator > 0.0 {
+            2.0 * area / denominator
+        } else {
+            0.0
+        };
+
+        x0 + offset.clamp(0.0, width) as f32
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy_prng::ChaCha8Rng;
+    use rand_core::SeedableRng;
+
+    use super::*;
+    use crate::resource::GlobalEntropy;
+
+    #[test]
+    fn samples_within_support() {
+        let mut rng = GlobalEntropy::<ChaCha8Rng>::from_seed([9; 32]);
+
+        // The flat zero weight region at the start should never be sampled.
+        let dist = PiecewiseLinearDist::new(vec![(3.0, 0.0), (-2.0, 0.0), (1.0, 0.0), (2.0, 1.0)])
+            .unwrap();
+
+        assert_eq!(
+            dist.points(),
+            &[(-2.0, 0.0), (1.0, 0.0), (2.0, 1.0), (3.0, 0.0)]
+        );
+
+        for _ in 0..10_000 {
+            let sample = dist.sample(&mut rng);
+
+            assert!(
+                (1.0..=3.0).contains(&sample),
+                "{sample} is outside of the support of the curve"
+            );
+        }
+    }
+
+    #[test]
+    fn samples_favour_high_weights() {
+        let mut rng = GlobalEntropy::<ChaCha8Rng>::from_seed([9; 32]);
+
+        // A rising ramp, with three quarters of its area in the upper half.
+        let dist = PiecewiseLinearDist::new(vec![(0.0, 0.0), (1.0, 1.0)]).unwrap();
+
+        let upper = (0..10_000).filter(|_| dist.sample(&mut rng) > 0.5).count();
+
+        assert!(
+            (7_200..7_800).contains(&upper),
+            "{upper} samples were in the upper half, expected about 7500"
+        );
+    }
+
+    #[test]
+    fn samples_deterministic() {
+        let mut rng1 = GlobalEntropy::<ChaCha8Rng>::from_seed([9; 32]);
+        let mut rng2 = GlobalEntropy::<ChaCha8Rng>::from_seed([9; 32]);
+
+        let dist = PiecewiseLinearDist::new(vec![(0.0, 1.0), (2.0, 3.0), (5.0, 0.5)]).unwrap();
+
+        let samples1: Vec<f32> = (0..100).map(|_| dist.sample(&mut rng1)).collect();
+        let samples2: Vec<f32> = (0..100).map(|_| dist.sample(&mut rng2)).collect();
+
+        assert_eq!(samples1, samples2);
+    }
+
+    #[test]
+    fn invalid_curves() {
+        assert!(PiecewiseLinearDist::new(vec![]).is_none());
+        assert!(PiecewiseLinearDist::new(vec![(0.0, 1.0)]).is_none());
+        assert!(PiecewiseLinearDist::new(vec![(0.0, 0.0), (1.0, 0.0)]).is_none());
+        assert!(PiecewiseLinearDist::new(vec![(0.0, 1.0), (0.0, 1.0)]).is_none());
+        assert!(PiecewiseLinearDist::new(vec![(0.0, -1.0), (1.0, 2.0)]).is_none());
+        assert!(PiecewiseLinearDist::new(vec![(0.0, 1.0), (f32::NAN, 1.0)]).is_none());
+    }
+}
@@ -0,0 +1,180 @@
+use rand::Rng;
+use rand_core::RngCore;
+
+/// A roller for chances with a "pity" mechanic, such as for rare loot drops, where every
+/// failed roll makes the next roll more likely to succeed. The consecutive failures are
+/// tracked, raising the chance of a roll by a fixed increment for each of them, and are reset
+/// once a roll succeeds. This puts a cap on how long an unlucky streak can last, as the chance
+/// eventually reaches certainty.
+///
+/// Given the same RNG state and chances, the same sequence of outcomes is always rolled.
+///
+/// ```
+/// use bevy_rand::prelude::*;
+/// use bevy_prng::ChaCha8Rng;
+/// use rand_core::SeedableRng;
+///
+/// let mut rng = GlobalEntropy::<ChaCha8Rng>::from_seed([1; 32]);
+///
+/// let mut legendary = PityRoller::new();
+///
+/// // A 1% drop chance, rising by 10% with every miss, so a drop takes at most 11 tries.
+/// let drops = (0..11).any(|_| legendary.roll(0.01, 0.1, &mut rng));
+///
+/// assert!(drops);
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct PityRoller {
+    failures: u32,
+}
+
+impl PityRoller {
+    /// Create a new roller with no failures.
+    #[inline]
+    #[must_use]
+    pub fn new() -> Self {
+        Self { failures: 0 }
+    }
+
+    /// Returns the amount of consecutive failed rolls since the last success.
+    #[inline]
+    #[must_use]
+    pub fn failures(&self) -> u32 {
+        self.failures
+    }
+
+    /// Returns the chance of the next roll succeeding, being `base_chance` raised by
+    /// `increment` for every consecutive failure, capped at `1.0`.
+    #[inline]
+    #[must_use]
+    pub fn chance(&self, base_chance: f32, increment: f32) -> f32 {
+        (base_chance + increment * self.failures as f32).min(1.0)
+    }
+
+    /// Rolls with a chance of `base_chance` raised by `increment` for every consecutive failure
+    /// so far, returning `true` on a success. A success resets the failures, while a failure
+    /// raises the chance of the next roll.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `base_chance` is not within `0.0..=1.0`, or if `increment` is negative or not
+    /// finite.
+    pub fn roll(&mut self, base_chance: f32, increment: f32, rng: &mut impl RngCore) -> bool {
+        assert!(
+            (0.0..=1.0).contains(&base_chance),
+            "PityRoller::roll called with a base chance outside of `0.0..=1.0`"
+        );
+        assert!(
+            increment.is_finite() && increment >= 0.0,
+            "PityRoller::roll called with a negative or non-finite increment"
+        );
+
+        let success = rng.gen_bool(f64::from(self.chance(base_chance, increment)));
+
+        if success {
+            self.failures = 0;
+        } else {
+            self.failures = self.failures.saturating_add(1);
+        }
+
+        success
+    }
+
+    /// Forgets the consecutive failures, so the next roll has the base chance again.
+    #[inline]
+    pub fn reset(&mut self) {
+        self.failures = 0;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy_prng::ChaCha8Rng;
+    use rand_core::SeedableRng;
+
+    use crate::resource::GlobalEntropy;
+
+    use super::*;
+
+    #[test]
+    fn chance_rises_with_failures() {
+        let mut rng = GlobalEntropy::<ChaCha8Rng>::from_seed([2; 32]);
+
+        let (base, increment) = (0.25, 0.25);
+        let mut roller = PityRoller::new();
+
+        // Attempts and successes, by the amount of failures before the roll.
+        let mut attempts = [0u32; 4];
+        let mut successes = [0u32; 4];
+
+        for _ in 0..20_000 {
+            let failures = roller.failures() as usize;
+
+            assert!(
+                failures < 4,
+                "the chance should be certain after 3 failures"
+            );
+
+            attempts[failures] += 1;
+
+            if roller.roll(base, increment, &mut rng) {
+                successes[failures] += 1;
+
+                assert_eq!(roller.failures(), 0, "a success should reset the failures");
+            }
+        }
+
+        let rates: Vec<f32> = successes
+            .iter()
+            .zip(attempts)
+            .map(|(&successes, attempts)| successes as f32 / attempts as f32)
+            .collect();
+
+        for (failures, &rate) in rates.iter().enumerate().take(3) {
+            let expected = base + increment * failures as f32;
+
+            assert!(
+                (rate - expected).abs() < 0.03,
+                "after {failures} failures the rate was {rate}, expected about {expected}"
+            );
+        }
+
+        assert_eq!(rates[3], 1.0);
+    }
+
+    #[test]
+    fn deterministic_rolls() {
+        let mut rng1 = GlobalEntropy::<ChaCha8Rng>::from_seed([2; 32]);
+        let mut rng2 = GlobalEntropy::<ChaCha8Rng>::from_seed([2; 32]);
+
+        let mut roller1 = PityRoller::new();
+        let mut roller2 = PityRoller::new();
+
+        let rolls1: Vec<bool> = (0..50)
+            .map(|_| roller1.roll(0.05, 0.05, &mut rng1))
+            .collect();
+        let rolls2: Vec<bool> = (0..50)
+            .map(|_| roller2.roll(0.05, 0.05, &mut rng2))
+            .collect();
+
+        assert_eq!(rolls1, rolls2);
+        assert_eq!(roller1, roller2);
+    }
+
+    #[test]
+    fn chance_capped_and_reset() {
+        let mut rng = GlobalEntropy::<ChaCha8Rng>::from_seed([2; 32]);
+
+        let mut roller = PityRoller::new();
+
+        assert!(!roller.roll(0.0, 0.6, &mut rng));
+        assert_eq!(roller.chance(0.0, 0.6), 0.6);
+        assert!(!roller.roll(0.0, 0.0, &mut rng));
+        assert_eq!(roller.chance(0.0, 0.6), 1.0);
+
+        roller.reset();
+
+        assert_eq!(roller.failures(), 0);
+        assert_eq!(roller.chance(0.25, 0.6), 0.25);
+    }
+}
@@ -1,12 +1,43 @@
-use crate::{component::EntropyComponent, resource::GlobalEntropy, traits::SeedableEntropySource};
-use bevy::prelude::{App, Plugin};
-use rand_core::SeedableRng;
+use crate::{
+    commands::add_pending_reseed_system,
+    component::EntropyComponent,
+    resource::{GlobalEntropy, InitialSeed, LazyGlobalEntropy, RngConfig},
+    seed::is_weak_seed,
+    traits::SeedableEntropySource,
+};
+#[cfg(feature = "deterministic_only")]
+use bevy::{
+    ecs::reflect::{ReflectComponentFns, ReflectResourceFns},
+    prelude::{AppTypeRegistry, ReflectComponent, ReflectResource},
+    reflect::{FromReflect, Reflect},
+};
+use bevy::{
+    log::warn,
+    prelude::{App, Plugin, SystemSet},
+    reflect::TypePath,
+};
+use rand_core::{RngCore, SeedableRng};
+#[cfg(feature = "deterministic_only")]
+use std::any::TypeId;
 
 /// Plugin for integrating a PRNG that implements `RngCore` into
 /// the bevy engine, registering types for a global resource and
 /// entropy components.
 ///
-/// ```
+/// If a [`GlobalEntropy`] resource already exists in the world when the
+/// plugin is built, such as when the `World` has been retained across a
+/// hot-reload, it is preserved as is instead of being replaced with a freshly
+/// seeded one, so the RNG continues deterministically from its existing state.
+/// This can be turned off with [`EntropyPlugin::preserve_on_reload`].
+///
+/// Whenever the plugin seeds a new [`GlobalEntropy`] resource, the seed it used is recorded
+/// in an [`InitialSeed`] resource, so that runs seeded from entropy can be reproduced.
+///
+/// The plugin also applies reseeds queued with
+/// [`RngCommandsExt::queue_reseed`](crate::commands::RngCommandsExt::queue_reseed) before the
+/// [`GlobalEntropy`] resource existed, once it or a [`LazyGlobalEntropy`] does.
+///
+/// ```no_run
 /// use bevy::prelude::*;
 /// use bevy_rand::prelude::*;
 /// use rand_core::RngCore;
@@ -28,6 +59,10 @@ use rand_core::SeedableRng;
 /// ```
 pub struct EntropyPlugin<R: SeedableEntropySource + 'static> {
     seed: Option<R::Seed>,
+    burn_in: u64,
+    warn_on_weak_seed: bool,
+    lazy: bool,
+    preserve_on_reload: bool,
 }
 
 impl<R: SeedableEntropySource + 'static> EntropyPlugin<R>
@@ -35,18 +70,115 @@ where
     R::Seed: Send + Sync + Copy,
 {
     /// Creates a new plugin instance configured for randomised,
-    /// non-deterministic seeding of the global entropy resource.
+    /// non-deterministic seeding of the global entropy resource,
+    /// unless a [`crate::resource::RngConfig`] resource has been
+    /// inserted beforehand to provide the seed.
+    ///
+    /// # Panics
+    ///
+    /// With the `deterministic_only` feature, building the plugin panics unless a
+    /// [`crate::resource::RngConfig`] resource provides the seed.
     #[inline]
     #[must_use]
     pub fn new() -> Self {
-        Self { seed: None }
+        Self {
+            seed: None,
+            burn_in: 0,
+            warn_on_weak_seed: cfg!(debug_assertions),
+            lazy: false,
+            preserve_on_reload: true,
+        }
     }
 
     /// Configures the plugin instance to have a set seed for the
     /// global entropy resource.
     #[inline]
     pub fn with_seed(seed: R::Seed) -> Self {
-        Self { seed: Some(seed) }
+        Self {
+            seed: Some(seed),
+            burn_in: 0,
+            warn_on_weak_seed: cfg!(debug_assertions),
+            lazy: false,
+            preserve_on_reload: true,
+        }
+    }
+
+    /// Creates a new plugin instance that defers seeding from entropy until the RNG is first
+    /// used, for apps that may never need randomness. Instead of a [`GlobalEntropy`] resource,
+    /// an uninitialised [`LazyGlobalEntropy`] resource is inserted, which has to be accessed by
+    /// systems in place of [`GlobalEntropy`]. With the `deterministic_only` feature, the first
+    /// use of the RNG panics.
+    ///
+    /// ```
+    /// use bevy::prelude::*;
+    /// use bevy_rand::prelude::*;
+    /// use bevy_prng::ChaCha8Rng;
+    ///
+    /// App::new()
+    ///     .add_plugins(EntropyPlugin::<ChaCha8Rng>::lazy());
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn lazy() -> Self {
+        Self {
+            seed: None,
+            burn_in: 0,
+            warn_on_weak_seed: cfg!(debug_assertions),
+            lazy: true,
+            preserve_on_reload: true,
+        }
+    }
+
+    /// Configures the plugin instance to discard `n` outputs from the global entropy
+    /// resource right after it has been seeded. See [`GlobalEntropy::burn_in`] for
+    /// which PRNGs benefit from this.
+    ///
+    /// ```
+    /// use bevy::prelude::*;
+    /// use bevy_rand::prelude::*;
+    /// use bevy_prng::ChaCha8Rng;
+    ///
+    /// App::new()
+    ///     .add_plugins(EntropyPlugin::<ChaCha8Rng>::with_seed([1; 32]).with_burn_in(16));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn with_burn_in(mut self, n: u64) -> Self {
+        self.burn_in = n;
+        self
+    }
+
+    /// Configures whether the plugin logs a warning when built with a seed that is all zeroes
+    /// or otherwise low in entropy, such as a short seed padded out with zeroes, which usually
+    /// indicates a configuration mistake. Some PRNGs also produce poor quality output from such
+    /// seeds. The check only applies to seeds given via [`EntropyPlugin::with_seed`], and is
+    /// enabled by default in debug builds.
+    #[inline]
+    #[must_use]
+    pub fn warn_on_weak_seed(mut self, enabled: bool) -> Self {
+        self.warn_on_weak_seed = enabled;
+        self
+    }
+
+    /// Configures whether the plugin preserves an existing [`GlobalEntropy`] (or
+    /// [`LazyGlobalEntropy`]) resource when built, such as when the `World` has been retained
+    /// across a hot-reload, so the RNG continues from its existing state instead of being reset.
+    /// Enabled by default. When disabled, the existing resource is replaced with a freshly
+    /// seeded one, restarting the stream as if the app had been started anew.
+    ///
+    /// ```
+    /// use bevy::prelude::*;
+    /// use bevy_rand::prelude::*;
+    /// use bevy_prng::ChaCha8Rng;
+    ///
+    /// App::new()
+    ///     .add_plugins(EntropyPlugin::<ChaCha8Rng>::with_seed([1; 32]).preserve_on_reload(false));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn preserve_on_reload(mut self, enabled: bool) -> Self {
+        self.preserve_on_reload = enabled;
+        self
     }
 }
 
@@ -64,13 +196,504 @@ where
     R::Seed: Send + Sync + Copy,
 {
     fn build(&self, app: &mut App) {
-        app.register_type::<GlobalEntropy<R>>()
+        // The PRNG itself must be registered as well, so that its reflected serialization
+        // data is available when the wrappers are serialized via `DynamicScene`s.
+        app.register_type::<R>()
+            .register_type::<GlobalEntropy<R>>()
             .register_type::<EntropyComponent<R>>();
 
-        if let Some(seed) = self.seed {
-            app.insert_resource(GlobalEntropy::<R>::from_seed(seed));
+        #[cfg(feature = "deterministic_only")]
+        reflect_without_entropy::<R>(app);
+
+        // Reseeds queued before the resource existed are applied once it does, which may be
+        // long after the plugin is built, such as when the resource gets inserted later on.
+        add_pending_reseed_system::<R>(app);
+
+        if self.lazy {
+            if !self.preserve_on_reload || !app.world.contains_resource::<LazyGlobalEntropy<R>>() {
+                app.insert_resource(LazyGlobalEntropy::<R>::new(self.burn_in));
+            }
+
+            return;
+        }
+
+        // Preserve any existing state, so not to reset the stream.
+        if self.preserve_on_reload && app.world.contains_resource::<GlobalEntropy<R>>() {
+            return;
+        }
+
+        let seed = if let Some(mut seed) = self.seed {
+            if self.warn_on_weak_seed && is_weak_seed(seed.as_mut()) {
+                warn!(
+                    "{} was seeded with a weak, low entropy seed, which is likely a mistake.",
+                    GlobalEntropy::<R>::short_type_path()
+                );
+            }
+
+            seed
+        } else if let Some(config) = app.world.get_resource::<RngConfig<R>>() {
+            config.seed()
         } else {
-            app.init_resource::<GlobalEntropy<R>>();
+            // Source the seed up front rather than seeding from entropy directly, so that it
+            // can be recorded.
+            let mut seed = R::Seed::default();
+
+            GlobalEntropy::<R>::from_entropy().fill_bytes(seed.as_mut());
+
+            seed
+        };
+
+        app.insert_resource(GlobalEntropy::<R>::from_seed(seed))
+            .insert_resource(InitialSeed::<R>::new(seed));
+
+        if self.burn_in > 0 {
+            app.world
+                .resource_mut::<GlobalEntropy<R>>()
+                .burn_in(self.burn_in);
+        }
+    }
+}
+
+/// Extension methods on [`App`] for setting up RNG resources without [`EntropyPlugin`].
+pub trait RngAppExt {
+    /// Initialises the [`GlobalEntropy`] resource of `R` like `init_resource`, but seeded from
+    /// a [`RngConfig`] resource if one exists, else from entropy. An existing [`GlobalEntropy`]
+    /// is left untouched. This allows seeding to be wired through configuration resources in
+    /// apps that set up their resources directly. With the `deterministic_only` feature, this
+    /// panics if neither resource exists.
+    ///
+    /// ```
+    /// use bevy::prelude::*;
+    /// use bevy_rand::prelude::*;
+    /// use bevy_prng::ChaCha8Rng;
+    ///
+    /// App::new()
+    ///     .insert_resource(RngConfig::<ChaCha8Rng>::new([1; 32]))
+    ///     .init_global_entropy::<ChaCha8Rng>();
+    /// ```
+    fn init_global_entropy<R: SeedableEntropySource + 'static>(&mut self) -> &mut Self;
+}
+
+impl RngAppExt for App {
+    fn init_global_entropy<R: SeedableEntropySource + 'static>(&mut self) -> &mut Self {
+        if !self.world.contains_resource::<GlobalEntropy<R>>() {
+            let rng = match self.world.get_resource::<RngConfig<R>>() {
+                Some(config) => GlobalEntropy::<R>::from_seed(config.seed()),
+                None => GlobalEntropy::<R>::from_entropy(),
+            };
+
+            self.insert_resource(rng);
         }
+
+        self
+    }
+}
+
+/// Replaces the reflected insertion of [`GlobalEntropy`] and [`EntropyComponent`], so that
+/// loading them from a scene builds them from the reflected value directly, instead of applying
+/// it onto a `Default` instance that would have to be seeded from entropy first.
+#[cfg(feature = "deterministic_only")]
+fn reflect_without_entropy<R: SeedableEntropySource + 'static>(app: &mut App)
+where
+    R::Seed: Send + Sync + Copy,
+{
+    let registry = app.world.resource::<AppTypeRegistry>().clone();
+    let mut registry = registry.write();
+
+    if let Some(registration) = registry.get_mut(TypeId::of::<GlobalEntropy<R>>()) {
+        let mut fns = ReflectResourceFns::new::<GlobalEntropy<R>>();
+
+        fns.insert = |world, reflected| {
+            world.insert_resource(from_reflected::<GlobalEntropy<R>>(reflected));
+        };
+        fns.apply_or_insert = |world, reflected| {
+            if let Some(mut resource) = world.get_resource_mut::<GlobalEntropy<R>>() {
+                resource.apply(reflected);
+            } else {
+                world.insert_resource(from_reflected::<GlobalEntropy<R>>(reflected));
+            }
+        };
+
+        if let Some(reflect) = registration
+            .data::<ReflectResource>()
+            .map(|data| data.new(fns))
+        {
+            registration.insert(reflect);
+        }
+    }
+
+    if let Some(registration) = registry.get_mut(TypeId::of::<EntropyComponent<R>>()) {
+        let mut fns = ReflectComponentFns::new::<EntropyComponent<R>>();
+
+        fns.insert = |entity, reflected| {
+            entity.insert(from_reflected::<EntropyComponent<R>>(reflected));
+        };
+        fns.apply_or_insert = |entity, reflected| {
+            if let Some(mut component) = entity.get_mut::<EntropyComponent<R>>() {
+                component.apply(reflected);
+            } else {
+                entity.insert(from_reflected::<EntropyComponent<R>>(reflected));
+            }
+        };
+
+        registration.insert(ReflectComponent::new(fns));
+    }
+}
+
+/// Builds `T` from a complete reflected value.
+///
+/// # Panics
+///
+/// If the reflected value doesn't describe a whole `T`, as the missing state can't be filled in
+/// from entropy with the `deterministic_only` feature.
+#[cfg(feature = "deterministic_only")]
+fn from_reflected<T: FromReflect + TypePath>(reflected: &dyn Reflect) -> T {
+    T::from_reflect(reflected).unwrap_or_else(|| {
+        panic!(
+            "reflected value is not a complete {}, which can't be filled in from entropy with the `deterministic_only` feature",
+            T::short_type_path()
+        )
+    })
+}
+
+/// The [`SystemSet`] that all built-in systems of `bevy_rand` which mutate RNG state belong to,
+/// such as the reseeding system of the `LevelSeedPlugin`. User systems that
+/// draw from the RNGs can be ordered `.before` or `.after` it, and user systems that mutate RNG
+/// state can be placed `.in_set` it, so all RNG usage happens in a deterministic order.
+///
+/// ```
+/// use bevy::prelude::*;
+/// use bevy_rand::prelude::*;
+/// use bevy_prng::ChaCha8Rng;
+/// use rand_core::RngCore;
+///
+/// fn roll_dice(mut rng: ResMut<GlobalEntropy<ChaCha8Rng>>) {
+///     println!("Rolled {}", rng.next_u32() % 6 + 1);
+/// }
+///
+/// App::new()
+///     .add_plugins(EntropyPlugin::<ChaCha8Rng>::with_seed([1; 32]))
+///     .add_systems(PreUpdate, roll_dice.after(RngSystemSet));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, SystemSet)]
+pub struct RngSystemSet;
+
+#[cfg(test)]
+mod tests {
+    use bevy::prelude::First;
+    use bevy_prng::ChaCha8Rng;
+    use rand_core::RngCore;
+
+    use super::*;
+
+    #[test]
+    fn plugin_burn_in() {
+        let mut app = App::new();
+
+        app.add_plugins(EntropyPlugin::<ChaCha8Rng>::with_seed([2; 32]).with_burn_in(4));
+
+        let mut expected = GlobalEntropy::<ChaCha8Rng>::from_seed([2; 32]);
+
+        expected.burn_in(4);
+
+        let mut rng = app.world.resource_mut::<GlobalEntropy<ChaCha8Rng>>();
+
+        assert_eq!(rng.as_ref(), &expected);
+        assert_eq!(rng.next_u32(), expected.next_u32());
+    }
+
+    #[cfg(not(feature = "deterministic_only"))]
+    #[test]
+    fn records_entropy_seed() {
+        let mut app = App::new();
+
+        app.add_plugins(EntropyPlugin::<ChaCha8Rng>::default());
+
+        let seed = app.world.resource::<InitialSeed<ChaCha8Rng>>().seed();
+
+        let mut expected = GlobalEntropy::<ChaCha8Rng>::from_seed(seed);
+
+        assert_eq!(
+            app.world
+                .resource_mut::<GlobalEntropy<ChaCha8Rng>>()
+                .next_u64(),
+            expected.next_u64(),
+            "the recorded seed should reproduce the output of the global rng"
+        );
+    }
+
+    #[test]
+    fn records_initial_seed() {
+        let mut app = App::new();
+
+        app.add_plugins(EntropyPlugin::<ChaCha8Rng>::with_seed([2; 32]).with_burn_in(4));
+
+        assert_eq!(
+            app.world.resource::<InitialSeed<ChaCha8Rng>>().seed(),
+            [2; 32],
+            "the seed should be recorded before any burn in"
+        );
+
+        let mut app = App::new();
+
+        app.insert_resource(RngConfig::<ChaCha8Rng>::new([3; 32]))
+            .add_plugins(EntropyPlugin::<ChaCha8Rng>::default());
+
+        assert_eq!(
+            app.world.resource::<InitialSeed<ChaCha8Rng>>().as_bytes(),
+            &[3; 32]
+        );
+    }
+
+    #[cfg(not(feature = "deterministic_only"))]
+    #[test]
+    fn lazy_seeding() {
+        let mut app = App::new();
+
+        app.add_plugins(EntropyPlugin::<ChaCha8Rng>::lazy());
+
+        assert!(!app.world.contains_resource::<GlobalEntropy<ChaCha8Rng>>());
+
+        let mut lazy = app.world.resource_mut::<LazyGlobalEntropy<ChaCha8Rng>>();
+
+        assert!(
+            !lazy.is_initialized(),
+            "the RNG should not be seeded before first use"
+        );
+
+        lazy.next_u32();
+
+        assert!(
+            lazy.is_initialized(),
+            "the RNG should be seeded on first use"
+        );
+
+        let mut expected = lazy.get().clone();
+
+        assert_eq!(lazy.next_u64(), expected.next_u64());
+        assert_eq!(lazy.get(), &expected, "the RNG should only be seeded once");
+    }
+
+    #[test]
+    fn preserves_existing_resource() {
+        let mut app = App::new();
+
+        let plugin = EntropyPlugin::<ChaCha8Rng>::with_seed([2; 32]).with_burn_in(4);
+
+        plugin.build(&mut app);
+
+        let mut expected = app.world.resource::<GlobalEntropy<ChaCha8Rng>>().clone();
+
+        // Advance the existing state, as would happen while the app is running
+        app.world
+            .resource_mut::<GlobalEntropy<ChaCha8Rng>>()
+            .next_u32();
+        expected.next_u32();
+
+        let systems = app.get_schedule(First).unwrap().graph().systems().count();
+
+        // Simulate the plugin being built again, such as after a reload
+        plugin.build(&mut app);
+
+        assert_eq!(
+            app.get_schedule(First).unwrap().graph().systems().count(),
+            systems,
+            "building again should not add any systems"
+        );
+
+        let mut rng = app.world.resource_mut::<GlobalEntropy<ChaCha8Rng>>();
+
+        assert_eq!(
+            rng.as_ref(),
+            &expected,
+            "the existing resource should be left untouched"
+        );
+        assert_eq!(rng.next_u32(), expected.next_u32());
+    }
+
+    #[test]
+    fn replaces_existing_resource_without_preserving() {
+        let mut app = App::new();
+
+        let plugin = EntropyPlugin::<ChaCha8Rng>::with_seed([2; 32]).preserve_on_reload(false);
+
+        plugin.build(&mut app);
+
+        app.world
+            .resource_mut::<GlobalEntropy<ChaCha8Rng>>()
+            .next_u32();
+
+        let systems = app.get_schedule(First).unwrap().graph().systems().count();
+
+        plugin.build(&mut app);
+
+        assert_eq!(
+            app.world.resource::<GlobalEntropy<ChaCha8Rng>>(),
+            &GlobalEntropy::<ChaCha8Rng>::from_seed([2; 32]),
+            "the existing resource should be replaced with a freshly seeded one"
+        );
+        assert_eq!(
+            app.get_schedule(First).unwrap().graph().systems().count(),
+            systems,
+            "building again should not add any systems"
+        );
+    }
+
+    #[test]
+    fn warns_on_weak_seed() {
+        use std::sync::{
+            atomic::{AtomicUsize, Ordering},
+            Arc,
+        };
+
+        use bevy::utils::tracing::{
+            span, subscriber::with_default, Event, Level, Metadata, Subscriber,
+        };
+
+        struct WarningCounter(Arc<AtomicUsize>);
+
+        impl Subscriber for WarningCounter {
+            fn enabled(&self, _: &Metadata<'_>) -> bool {
+                true
+            }
+
+            fn new_span(&self, _: &span::Attributes<'_>) -> span::Id {
+                span::Id::from_u64(1)
+            }
+
+            fn record(&self, _: &span::Id, _: &span::Record<'_>) {}
+
+            fn record_follows_from(&self, _: &span::Id, _: &span::Id) {}
+
+            fn event(&self, event: &Event<'_>) {
+                if *event.metadata().level() == Level::WARN {
+                    self.0.fetch_add(1, Ordering::SeqCst);
+                }
+            }
+
+            fn enter(&self, _: &span::Id) {}
+
+            fn exit(&self, _: &span::Id) {}
+        }
+
+        let warnings = Arc::new(AtomicUsize::new(0));
+
+        let mut app = App::new();
+
+        with_default(WarningCounter(warnings.clone()), || {
+            EntropyPlugin::<ChaCha8Rng>::with_seed([0; 32])
+                .warn_on_weak_seed(true)
+                .build(&mut app);
+        });
+
+        assert_eq!(
+            warnings.load(Ordering::SeqCst),
+            1,
+            "a zero seed should emit a warning"
+        );
+
+        let mut expected = GlobalEntropy::<ChaCha8Rng>::from_seed([0; 32]);
+
+        assert_eq!(
+            app.world
+                .resource_mut::<GlobalEntropy<ChaCha8Rng>>()
+                .next_u32(),
+            expected.next_u32(),
+            "a weak seed should still be used"
+        );
+
+        let mut app = App::new();
+
+        with_default(WarningCounter(warnings.clone()), || {
+            EntropyPlugin::<ChaCha8Rng>::with_seed([1; 32])
+                .warn_on_weak_seed(true)
+                .build(&mut app);
+        });
+
+        assert_eq!(
+            warnings.load(Ordering::SeqCst),
+            1,
+            "repeated non-zero bytes should not emit a warning"
+        );
+
+        let mut app = App::new();
+
+        with_default(WarningCounter(warnings.clone()), || {
+            EntropyPlugin::<ChaCha8Rng>::with_seed([0; 32])
+                .warn_on_weak_seed(false)
+                .build(&mut app);
+        });
+
+        assert_eq!(
+            warnings.load(Ordering::SeqCst),
+            1,
+            "disabled checks should not emit a warning"
+        );
+    }
+
+    #[test]
+    fn orders_after_system_set() {
+        use bevy::prelude::{Commands, IntoSystemConfigs, Res, ResMut, Resource, Startup};
+
+        use crate::commands::RngCommandsExt;
+
+        #[derive(Default, Resource)]
+        struct Observed(Option<GlobalEntropy<ChaCha8Rng>>);
+
+        let mut app = App::new();
+
+        app.init_resource::<Observed>()
+            .add_systems(
+                First,
+                (|rng: Option<Res<GlobalEntropy<ChaCha8Rng>>>, mut observed: ResMut<Observed>| {
+                    observed.0 = rng.map(|rng| rng.clone());
+                })
+                .after(RngSystemSet),
+            )
+            .add_plugins(EntropyPlugin::<ChaCha8Rng>::with_seed([1; 32]))
+            .add_systems(Startup, |mut commands: Commands| {
+                commands.queue_reseed::<ChaCha8Rng>([7; 32]);
+            });
+
+        // With the resource missing, the reseed is held back until it exists again, to then be
+        // applied by the built-in system within the set.
+        app.world.remove_resource::<GlobalEntropy<ChaCha8Rng>>();
+        app.update();
+
+        assert!(app.world.resource::<Observed>().0.is_none());
+
+        app.insert_resource(GlobalEntropy::<ChaCha8Rng>::from_seed([1; 32]));
+        app.update();
+
+        assert_eq!(
+            app.world.resource::<Observed>().0,
+            Some(GlobalEntropy::<ChaCha8Rng>::from_seed([7; 32])),
+            "systems after the set should observe the reseed applied within it"
+        );
+    }
+
+    #[test]
+    fn init_global_entropy_from_config() {
+        let mut app = App::new();
+
+        app.insert_resource(RngConfig::<ChaCha8Rng>::new([4; 32]))
+            .init_global_entropy::<ChaCha8Rng>();
+
+        assert_eq!(
+            app.world.resource::<GlobalEntropy<ChaCha8Rng>>(),
+            &GlobalEntropy::<ChaCha8Rng>::from_seed([4; 32]),
+            "GlobalEntropy should be seeded from the RngConfig"
+        );
+
+        // Initialising again should neither reseed nor replace the existing resource.
+        app.world
+            .resource_mut::<GlobalEntropy<ChaCha8Rng>>()
+            .next_u32();
+
+        let expected = app.world.resource::<GlobalEntropy<ChaCha8Rng>>().clone();
+
+        app.init_global_entropy::<ChaCha8Rng>();
+
+        assert_eq!(app.world.resource::<GlobalEntropy<ChaCha8Rng>>(), &expected);
     }
 }
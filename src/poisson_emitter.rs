@@ -0,0 +1,192 @@
+use std::marker::PhantomData;
+
+use crate::{resource::GlobalEntropy, traits::SeedableEntropySource};
+use bevy::{
+    prelude::{Component, Entity, Event, EventWriter, Query, Res, ResMut},
+    time::Time,
+};
+use rand::Rng;
+
+/// A component for sending `E` events at random times, following a Poisson process with an
+/// average of `rate` events per second, such as for ambient sounds like birds chirping. The
+/// times between events are exponentially distributed, so events arrive independently of each
+/// other, rather than at suspiciously regular intervals. Emitters are driven by the
+/// [`emit_poisson_events`] system, which has to be added for the events to be sent.
+///
+/// ```
+/// use bevy::prelude::*;
+/// use bevy_rand::prelude::*;
+/// use bevy_rand::poisson_emitter::{emit_poisson_events, PoissonEmitter};
+/// use bevy_prng::ChaCha8Rng;
+///
+/// #[derive(Event, Default)]
+/// struct Chirp;
+///
+/// fn spawn_bird(mut commands: Commands) {
+///     commands.spawn(PoissonEmitter::<Chirp>::new(0.5));
+/// }
+///
+/// App::new()
+///     .add_plugins(EntropyPlugin::<ChaCha8Rng>::with_seed([1; 32]))
+///     .add_event::<Chirp>()
+///     .add_systems(Startup, spawn_bird)
+///     .add_systems(Update, emit_poisson_events::<ChaCha8Rng, Chirp>);
+/// ```
+#[derive(Debug, Component)]
+pub struct PoissonEmitter<E: Event> {
+    rate: f64,
+    until_next: Option<f64>,
+    _event: PhantomData<fn() -> E>,
+}
+
+impl<E: Event> PoissonEmitter<E> {
+    /// Create a new emitter sending an average of `rate` events per second. A `rate` of zero
+    /// never sends any events.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `rate` is negative or not finite.
+    #[inline]
+    #[must_use]
+    pub fn new(rate: f64) -> Self {
+        assert!(
+            rate.is_finite() && rate >= 0.0,
+            "PoissonEmitter::new called with a negative or non-finite rate"
+        );
+
+        Self {
+            rate,
+            until_next: None,
+            _event: PhantomData,
+        }
+    }
+
+    /// Returns the average amount of events sent per second.
+    #[inline]
+    #[must_use]
+    pub fn rate(&self) -> f64 {
+        self.rate
+    }
+
+    /// Advances the emitter by `delta` seconds, returning how many events arrived in that time.
+    fn advance(&mut self, delta: f64, rng: &mut impl Rng) -> usize {
+        if self.rate == 0.0 {
+            return 0;
+        }
+
+        // Sampling from `0.0..1.0` and flipping it avoids taking the logarithm of zero.
+        let mut sample_gap = || -(1.0 - rng.gen::<f64>()).ln() / self.rate;
+
+        let mut until_next = self.until_next.unwrap_or_else(&mut sample_gap) - delta;
+        let mut arrivals = 0;
+
+        while until_next <= 0.0 {
+            arrivals += 1;
+            until_next += sample_gap();
+        }
+
+        self.until_next = Some(until_next);
+
+        arrivals
+    }
+}
+
+/// A system that advances every [`PoissonEmitter`] of `E` by the time elapsed since the last
+/// frame, sending a default `E` event for every event that arrived in that time. The times
+/// between events are drawn from the [`GlobalEntropy`] of `R`, with the emitters being advanced
+/// in the order of their entities, so for the same seed and frame times, the same events are
+/// sent on the same frames.
+pub fn emit_poisson_events<R: SeedableEntropySource + 'static, E: Event + Default>(
+    time: Res<Time>,
+    mut rng: ResMut<GlobalEntropy<R>>,
+    mut q_emitters: Query<(Entity, &mut PoissonEmitter<E>)>,
+    mut events: EventWriter<E>,
+) {
+    let delta = time.delta_seconds_f64();
+
+    let mut emitters: Vec<_> = q_emitters.iter_mut().collect();
+
+    emitters.sort_unstable_by_key(|(entity, _)| *entity);
+
+    for (_, mut emitter) in emitters {
+        for _ in 0..emitter.advance(delta, rng.as_mut()) {
+            events.send_default();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use bevy::prelude::{App, EventReader, IntoSystemConfigs, Resource, Update};
+    use bevy_prng::ChaCha8Rng;
+    use rand_core::SeedableRng;
+
+    use super::*;
+
+    #[derive(Event, Default)]
+    struct Chirp;
+
+    #[derive(Default, Resource)]
+    struct Chirps(usize);
+
+    /// Runs a single emitter of `rate` for `seconds` at 60 frames per second, returning the
+    /// amount of events sent on every frame.
+    fn simulate(rate: f64, seconds: u32) -> Vec<usize> {
+        let mut app = App::new();
+
+        app.insert_resource(GlobalEntropy::<ChaCha8Rng>::from_seed([6; 32]))
+            .init_resource::<Time>()
+            .init_resource::<Chirps>()
+            .add_event::<Chirp>()
+            .add_systems(
+                Update,
+                (
+                    emit_poisson_events::<ChaCha8Rng, Chirp>,
+                    |mut events: EventReader<Chirp>, mut chirps: ResMut<Chirps>| {
+                        chirps.0 = events.read().count();
+                    },
+                )
+                    .chain(),
+            );
+
+        app.world.spawn(PoissonEmitter::<Chirp>::new(rate));
+
+        (0..seconds * 60)
+            .map(|_| {
+                app.world
+                    .resource_mut::<Time>()
+                    .advance_by(Duration::from_secs_f64(1.0 / 60.0));
+                app.update();
+
+                app.world.resource::<Chirps>().0
+            })
+            .collect()
+    }
+
+    #[test]
+    fn count_approximates_rate() {
+        let (rate, seconds) = (4.0, 500);
+
+        let count: usize = simulate(rate, seconds).iter().sum();
+
+        let expected = rate * f64::from(seconds);
+
+        // The standard deviation of the count is the square root of the expected count.
+        assert!(
+            (count as f64 - expected).abs() < 4.0 * expected.sqrt(),
+            "{count} events were sent, expected about {expected}"
+        );
+    }
+
+    #[test]
+    fn emits_deterministically() {
+        assert_eq!(simulate(2.0, 10), simulate(2.0, 10));
+    }
+
+    #[test]
+    fn zero_rate_never_emits() {
+        assert!(simulate(0.0, 10).iter().all(|&chirps| chirps == 0));
+    }
+}
@@ -0,0 +1,180 @@
+use crate::{component::EntropyComponent, seed::mix_seeds, traits::SeedableEntropySource};
+use bevy::prelude::Resource;
+use rand_core::SeedableRng;
+
+/// A pool of [`EntropyComponent`]s, for recycling RNG instances between short-lived entities
+/// instead of creating new ones for every spawn. Every checked out component is reseeded
+/// deterministically from the pool's base seed and a counter of how many components have been
+/// checked out so far, so the `n`th checkout from pools with the same base seed always yields
+/// the same RNG state, regardless of whether the instance was recycled or newly created.
+///
+/// ```
+/// use bevy::prelude::*;
+/// use bevy_rand::prelude::*;
+/// use bevy_prng::ChaCha8Rng;
+///
+/// #[derive(Component)]
+/// struct Particle;
+///
+/// fn spawn_particle(mut commands: Commands, mut pool: ResMut<RngPool<ChaCha8Rng>>) {
+///     commands.spawn((Particle, pool.checkout()));
+/// }
+///
+/// fn recycle_particle(world: &mut World, entity: Entity) {
+///     if let Some(rng) = world.entity_mut(entity).take::<EntropyComponent<ChaCha8Rng>>() {
+///         world.resource_mut::<RngPool<ChaCha8Rng>>().checkin(rng);
+///     }
+///
+///     world.despawn(entity);
+/// }
+///
+/// App::new()
+///     .insert_resource(RngPool::<ChaCha8Rng>::new(42))
+///     .add_systems(Update, spawn_particle);
+/// ```
+#[derive(Debug, Resource)]
+pub struct RngPool<R: SeedableEntropySource + 'static> {
+    available: Vec<EntropyComponent<R>>,
+    base_seed: u64,
+    counter: u64,
+}
+
+impl<R: SeedableEntropySource + 'static> RngPool<R> {
+    /// Create a new, empty pool that derives the seeds of checked out components
+    /// from `base_seed`.
+    #[inline]
+    #[must_use]
+    pub fn new(base_seed: u64) -> Self {
+        Self {
+            available: Vec::new(),
+            base_seed,
+            counter: 0,
+        }
+    }
+
+    /// Checks out a component from the pool, recycling a previously checked in one if
+    /// available, else creating a new one. The component is always reseeded with the
+    /// next deterministic seed of the pool.
+    pub fn checkout(&mut self) -> EntropyComponent<R> {
+        let seed = self.next_seed();
+
+        match self.available.pop() {
+            Some(mut rng) => {
+                rng.reseed(seed);
+                rng
+            }
+            None => EntropyComponent::from_seed(seed),
+        }
+    }
+
+    fn next_seed(&mut self) -> R::Seed {
+        let mut seed = R::Seed::default();
+
+        // Mixed rather than offset, so pools with nearby base seeds don't share any states.
+        mix_seeds(
+            &[&self.base_seed.to_le_bytes(), &self.counter.to_le_bytes()],
+            seed.as_mut(),
+        );
+
+        self.counter = self.counter.wrapping_add(1);
+
+        seed
+    }
+
+    /// Checks a component back into the pool, for it to be recycled by a future checkout.
+    #[inline]
+    pub fn checkin(&mut self, rng: EntropyComponent<R>) {
+        self.available.push(rng);
+    }
+
+    /// Returns how many components are available for recycling.
+    #[inline]
+    pub fn available(&self) -> usize {
+        self.available.len()
+    }
+
+    /// Returns how many components have been checked out from the pool in total.
+    #[inline]
+    pub fn checked_out(&self) -> u64 {
+        self.counter
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy_prng::ChaCha8Rng;
+    use rand_core::RngCore;
+
+    use super::*;
+
+    #[test]
+    fn checkin_is_recycled() {
+        let mut pool = RngPool::<ChaCha8Rng>::new(7);
+
+        let rng = pool.checkout();
+
+        assert_eq!(pool.available(), 0);
+
+        pool.checkin(rng);
+
+        assert_eq!(pool.available(), 1);
+
+        let _rng = pool.checkout();
+
+        assert_eq!(
+            pool.available(),
+            0,
+            "the checked in instance should be reused"
+        );
+        assert_eq!(pool.checked_out(), 2);
+    }
+
+    #[test]
+    fn reproducible_seeds() {
+        let mut recycling = RngPool::<ChaCha8Rng>::new(7);
+        let mut fresh = RngPool::<ChaCha8Rng>::new(7);
+
+        let mut first = recycling.checkout();
+
+        assert_eq!(first, fresh.checkout());
+
+        // Advance the instance before recycling it, its state must not leak into the next checkout
+        first.next_u64();
+        recycling.checkin(first);
+
+        let mut recycled = recycling.checkout();
+        let mut new = fresh.checkout();
+
+        assert_eq!(
+            recycled, new,
+            "recycled instances should be reseeded the same as new ones"
+        );
+        assert_eq!(recycled.next_u32(), new.next_u32());
+    }
+
+    #[test]
+    fn checkouts_are_distinct() {
+        let mut pool = RngPool::<ChaCha8Rng>::new(7);
+
+        assert_ne!(pool.checkout(), pool.checkout());
+        assert_ne!(
+            RngPool::<ChaCha8Rng>::new(7).checkout(),
+            RngPool::<ChaCha8Rng>::new(8).checkout(),
+            "different base seeds should produce different instances"
+        );
+    }
+
+    #[test]
+    fn nearby_base_seeds_do_not_overlap() {
+        let mut a = RngPool::<ChaCha8Rng>::new(7);
+        let mut b = RngPool::<ChaCha8Rng>::new(8);
+
+        let a: Vec<_> = (0..16).map(|_| a.checkout()).collect();
+        let b: Vec<_> = (0..16).map(|_| b.checkout()).collect();
+
+        assert!(
+            a.iter().all(|rng| !b.contains(rng)),
+            "pools with nearby base seeds should share no checkout states"
+        );
+    }
+}
@@ -1,4 +1,24 @@
+#[cfg(feature = "rand")]
+pub use crate::alias_table::AliasTable;
+#[cfg(feature = "rand")]
+pub use crate::anti_repeat::AntiRepeatChooser;
+#[cfg(feature = "rand")]
+pub use crate::bernoulli_gate::BernoulliGate;
+pub use crate::commands::RngCommandsExt;
 pub use crate::component::EntropyComponent;
-pub use crate::plugin::EntropyPlugin;
-pub use crate::resource::GlobalEntropy;
+#[cfg(feature = "rand")]
+pub use crate::genome::CrossoverStrategy;
+#[cfg(feature = "rand")]
+pub use crate::name_generator::NameGenerator;
+pub use crate::named::NamedRngs;
+pub use crate::permutation::Permutation;
+#[cfg(feature = "rand")]
+pub use crate::piecewise_linear::PiecewiseLinearDist;
+#[cfg(feature = "rand")]
+pub use crate::pity_roller::PityRoller;
+pub use crate::plugin::{EntropyPlugin, RngAppExt, RngSystemSet};
+pub use crate::pool::RngPool;
+pub use crate::resource::{GlobalEntropy, InitialSeed, LazyGlobalEntropy, RngConfig};
+#[cfg(feature = "rand")]
+pub use crate::shuffle_bag::PseudoShuffleBag;
 pub use crate::traits::SeedableEntropySource;
@@ -0,0 +1,166 @@
+use rand_core::RngCore;
+
+/// A single operation performed on an RNG, as logged by [`RngRecorder`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum RngOp {
+    /// A call to [`RngCore::next_u32`].
+    NextU32,
+    /// A call to [`RngCore::next_u64`].
+    NextU64,
+    /// A call to [`RngCore::fill_bytes`], with the amount of bytes requested.
+    FillBytes(usize),
+    /// A call to [`RngCore::try_fill_bytes`], with the amount of bytes requested.
+    TryFillBytes(usize),
+}
+
+/// A wrapper around an [`RngCore`] that logs every operation performed on it, for debugging
+/// nondeterminism. Exporting and diffing the logs of two runs pinpoints where the usage of the
+/// RNG starts to diverge between them. The wrapped RNG can also be a mutable reference, so a
+/// [`crate::resource::GlobalEntropy`] can be recorded within a single system without having to
+/// replace the resource.
+///
+/// ```
+/// use bevy_rand::prelude::*;
+/// use bevy_rand::recorder::{RngOp, RngRecorder};
+/// use bevy_prng::ChaCha8Rng;
+/// use rand_core::{RngCore, SeedableRng};
+///
+/// let mut rng = GlobalEntropy::<ChaCha8Rng>::from_seed([1; 32]);
+///
+/// let mut recorder = RngRecorder::new(&mut rng);
+///
+/// recorder.next_u32();
+/// recorder.fill_bytes(&mut [0; 16]);
+///
+/// assert_eq!(recorder.export(), vec![RngOp::NextU32, RngOp::FillBytes(16)]);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct RngRecorder<R: RngCore> {
+    rng: R,
+    log: Vec<RngOp>,
+}
+
+impl<R: RngCore> RngRecorder<R> {
+    /// Create a new recorder wrapping the given RNG, with an empty log.
+    #[inline]
+    #[must_use]
+    pub fn new(rng: R) -> Self {
+        Self {
+            rng,
+            log: Vec::new(),
+        }
+    }
+
+    /// Returns a copy of the log of all operations recorded so far, in the order they were
+    /// performed.
+    #[inline]
+    #[must_use]
+    pub fn export(&self) -> Vec<RngOp> {
+        self.log.clone()
+    }
+
+    /// Clears the log, such as at the start of every frame.
+    #[inline]
+    pub fn clear(&mut self) {
+        self.log.clear();
+    }
+
+    /// Returns a reference to the wrapped RNG.
+    #[inline]
+    pub fn inner(&self) -> &R {
+        &self.rng
+    }
+
+    /// Unwraps the recorder, returning the wrapped RNG and discarding the log.
+    #[inline]
+    pub fn into_inner(self) -> R {
+        self.rng
+    }
+}
+
+impl<R: RngCore> RngCore for RngRecorder<R> {
+    #[inline]
+    fn next_u32(&mut self) -> u32 {
+        self.log.push(RngOp::NextU32);
+        self.rng.next_u32()
+    }
+
+    #[inline]
+    fn next_u64(&mut self) -> u64 {
+        self.log.push(RngOp::NextU64);
+        self.rng.next_u64()
+    }
+
+    #[inline]
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        self.log.push(RngOp::FillBytes(dest.len()));
+        self.rng.fill_bytes(dest);
+    }
+
+    #[inline]
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), rand_core::Error> {
+        self.log.push(RngOp::TryFillBytes(dest.len()));
+        self.rng.try_fill_bytes(dest)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy_prng::ChaCha8Rng;
+    use rand_core::SeedableRng;
+
+    use crate::resource::GlobalEntropy;
+
+    use super::*;
+
+    #[test]
+    fn records_operations() {
+        let mut rng = GlobalEntropy::<ChaCha8Rng>::from_seed([5; 32]);
+        let mut expected = rng.clone();
+
+        let mut recorder = RngRecorder::new(&mut rng);
+
+        assert_eq!(recorder.next_u64(), expected.next_u64());
+        assert_eq!(recorder.next_u32(), expected.next_u32());
+
+        let mut bytes = [0; 7];
+        recorder.fill_bytes(&mut bytes);
+        recorder.try_fill_bytes(&mut bytes[..3]).unwrap();
+        recorder.next_u32();
+
+        assert_eq!(
+            recorder.export(),
+            vec![
+                RngOp::NextU64,
+                RngOp::NextU32,
+                RngOp::FillBytes(7),
+                RngOp::TryFillBytes(3),
+                RngOp::NextU32,
+            ],
+            "the exact sequence of operations should be recorded"
+        );
+
+        recorder.clear();
+
+        assert!(recorder.export().is_empty());
+    }
+
+    #[test]
+    fn diverging_logs() {
+        let mut run1 = RngRecorder::new(GlobalEntropy::<ChaCha8Rng>::from_seed([5; 32]));
+        let mut run2 = RngRecorder::new(GlobalEntropy::<ChaCha8Rng>::from_seed([5; 32]));
+
+        run1.next_u32();
+        run1.next_u32();
+        run2.next_u32();
+        run2.next_u64();
+
+        let divergence = run1
+            .export()
+            .iter()
+            .zip(run2.export().iter())
+            .position(|(a, b)| a != b);
+
+        assert_eq!(divergence, Some(1));
+    }
+}
@@ -0,0 +1,237 @@
+use std::any::TypeId;
+
+use crate::{resource::GlobalEntropy, traits::SeedableEntropySource};
+use bevy::prelude::ReflectDefault;
+use bevy::reflect::{
+    DynamicEnum, DynamicStruct, DynamicTuple, DynamicVariant, EnumInfo, Reflect, ReflectMut,
+    TypeInfo, TypeRegistry, VariantInfo,
+};
+use rand::Rng;
+
+fn default_value(registry: &TypeRegistry, type_id: TypeId) -> Option<Box<dyn Reflect>> {
+    registry
+        .get_type_data::<ReflectDefault>(type_id)
+        .map(ReflectDefault::default)
+}
+
+impl<R: SeedableEntropySource + 'static> GlobalEntropy<R> {
+    /// Chooses the index of a random variant of the reflected enum described by `info`, with
+    /// every variant being equally likely. Returns `None` if the enum has no variants.
+    ///
+    /// ```
+    /// use bevy::reflect::{Reflect, TypeInfo, Typed};
+    /// use bevy_rand::prelude::*;
+    /// use bevy_prng::ChaCha8Rng;
+    /// use rand_core::SeedableRng;
+    ///
+    /// #[derive(Reflect)]
+    /// enum Weather {
+    ///     Sunny,
+    ///     Rain,
+    ///     Snow,
+    /// }
+    ///
+    /// let mut rng = GlobalEntropy::<ChaCha8Rng>::from_seed([1; 32]);
+    ///
+    /// let TypeInfo::Enum(info) = Weather::type_info() else { unreachable!() };
+    ///
+    /// assert!(rng.random_variant(info).is_some_and(|index| index < 3));
+    /// ```
+    #[cfg_attr(docsrs, doc(cfg(feature = "rand")))]
+    pub fn random_variant(&mut self, info: &EnumInfo) -> Option<usize> {
+        let variants = info.variant_len();
+
+        (variants > 0).then(|| self.gen_range(0..variants))
+    }
+
+    /// Sets the reflected enum `value` to a random variant of itself, with every variant being
+    /// equally likely, for purposes like chaos testing. The fields of the chosen variant are
+    /// set to their default values, so the types of all fields must be registered in `registry`
+    /// with [`ReflectDefault`]. Returns the index of the chosen variant, or `None` if `value` is
+    /// not an enum, has no variants, or the chosen variant has a field that can't be defaulted,
+    /// in which case `value` is left unchanged.
+    ///
+    /// ```
+    /// use bevy::reflect::{Reflect, TypeRegistry};
+    /// use bevy_rand::prelude::*;
+    /// use bevy_prng::ChaCha8Rng;
+    /// use rand_core::SeedableRng;
+    ///
+    /// #[derive(Debug, Reflect)]
+    /// enum Weather {
+    ///     Sunny,
+    ///     Rain(f32),
+    ///     Wind { speed: u32 },
+    /// }
+    ///
+    /// let mut rng = GlobalEntropy::<ChaCha8Rng>::from_seed([1; 32]);
+    /// let registry = TypeRegistry::new();
+    ///
+    /// let mut weather = Weather::Sunny;
+    ///
+    /// assert!(rng.set_random_variant(&mut weather, &registry).is_some());
+    /// ```
+    #[cfg_attr(docsrs, doc(cfg(feature = "rand")))]
+    pub fn set_random_variant(
+        &mut self,
+        value: &mut dyn Reflect,
+        registry: &TypeRegistry,
+    ) -> Option<usize> {
+        let Some(TypeInfo::Enum(info)) = value.get_represented_type_info() else {
+            return None;
+        };
+
+        let index = self.random_variant(info)?;
+
+        let variant = match info.variant_at(index)? {
+            VariantInfo::Unit(_) => DynamicVariant::Unit,
+            VariantInfo::Tuple(variant) => {
+                let mut tuple = DynamicTuple::default();
+
+                for field in variant.iter() {
+                    tuple.insert_boxed(default_value(registry, field.type_id())?);
+                }
+
+                DynamicVariant::Tuple(tuple)
+            }
+            VariantInfo::Struct(variant) => {
+                let mut fields = DynamicStruct::default();
+
+                for field in variant.iter() {
+                    fields.insert_boxed(field.name(), default_value(registry, field.type_id())?);
+                }
+
+                DynamicVariant::Struct(fields)
+            }
+        };
+
+        let ReflectMut::Enum(target) = value.reflect_mut() else {
+            return None;
+        };
+
+        target.apply(&DynamicEnum::new_with_index(
+            index,
+            info.variant_at(index)?.name(),
+            variant,
+        ));
+
+        Some(index)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy::reflect::{Enum, Typed};
+    use bevy_prng::ChaCha8Rng;
+    use rand_core::SeedableRng;
+
+    use super::*;
+
+    #[derive(Debug, PartialEq, Reflect)]
+    enum Weather {
+        Sunny,
+        Rain(f32),
+        Wind { speed: u32, gusty: bool },
+    }
+
+    #[derive(Reflect)]
+    enum Never {}
+
+    fn weather_info() -> &'static EnumInfo {
+        match Weather::type_info() {
+            TypeInfo::Enum(info) => info,
+            _ => unreachable!(),
+        }
+    }
+
+    #[test]
+    fn random_variant_deterministic() {
+        let mut rng1 = GlobalEntropy::<ChaCha8Rng>::from_seed([6; 32]);
+        let mut rng2 = GlobalEntropy::<ChaCha8Rng>::from_seed([6; 32]);
+
+        let chosen: Vec<_> = (0..20)
+            .map(|_| rng1.random_variant(weather_info()).unwrap())
+            .collect();
+
+        assert!(chosen.iter().all(|&index| index < 3));
+        assert!(
+            chosen.iter().any(|&index| index != chosen[0]),
+            "different variants should be chosen"
+        );
+
+        for index in chosen {
+            assert_eq!(rng2.random_variant(weather_info()), Some(index));
+        }
+    }
+
+    #[test]
+    fn set_random_variant_defaults_fields() {
+        let mut rng = GlobalEntropy::<ChaCha8Rng>::from_seed([6; 32]);
+        let registry = TypeRegistry::new();
+
+        for _ in 0..20 {
+            let mut weather = Weather::Rain(4.0);
+
+            let index = rng.set_random_variant(&mut weather, &registry).unwrap();
+
+            assert_eq!(weather.variant_index(), index);
+            assert_eq!(
+                weather,
+                vec![
+                    Weather::Sunny,
+                    Weather::Rain(0.0),
+                    Weather::Wind {
+                        speed: 0,
+                        gusty: false
+                    }
+                ]
+                .swap_remove(index),
+                "fields of the chosen variant should be defaulted"
+            );
+        }
+    }
+
+    #[test]
+    fn set_random_variant_deterministic() {
+        let mut rng1 = GlobalEntropy::<ChaCha8Rng>::from_seed([6; 32]);
+        let mut rng2 = GlobalEntropy::<ChaCha8Rng>::from_seed([6; 32]);
+        let registry = TypeRegistry::new();
+
+        for _ in 0..10 {
+            let mut weather1 = Weather::Sunny;
+            let mut weather2 = Weather::Sunny;
+
+            rng1.set_random_variant(&mut weather1, &registry);
+            rng2.set_random_variant(&mut weather2, &registry);
+
+            assert_eq!(weather1, weather2);
+        }
+    }
+
+    #[test]
+    fn no_variant_to_choose() {
+        let mut rng = GlobalEntropy::<ChaCha8Rng>::from_seed([6; 32]);
+
+        let TypeInfo::Enum(info) = Never::type_info() else {
+            unreachable!()
+        };
+
+        assert_eq!(rng.random_variant(info), None);
+
+        let mut weather = Weather::Sunny;
+        let empty = TypeRegistry::empty();
+
+        // Keep trying until a variant with fields is chosen, which can't be defaulted
+        while rng.set_random_variant(&mut weather, &empty).is_some() {}
+
+        assert_eq!(
+            weather,
+            Weather::Sunny,
+            "the value should be left unchanged"
+        );
+
+        let mut not_an_enum = 5u32;
+
+        assert_eq!(rng.set_random_variant(&mut not_an_enum, &empty), None);
+    }
+}
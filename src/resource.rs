@@ -1,154 +1,1922 @@
-use std::fmt::Debug;
+#[cfg(debug_assertions)]
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::{
+    fmt::Debug,
+    marker::PhantomData,
+    ops::{Deref, DerefMut},
+};
 
-use crate::traits::SeedableEntropySource;
-use bevy::prelude::{Reflect, ReflectFromReflect, ReflectResource, Resource};
+use crate::{
+    seed::{fnv1a_64_iter, mix_seeds, SeedError, SeedHasher},
+    traits::SeedableEntropySource,
+};
+use bevy::{
+    log::warn,
+    math::IVec2,
+    prelude::{Entity, Reflect, ReflectFromReflect, ReflectResource, Resource},
+    reflect::TypePath,
+};
+use bevy_prng::{SeekableRng, ValidatedSeed};
 use rand_core::{RngCore, SeedableRng};
 
-#[cfg(feature = "thread_local_entropy")]
+#[cfg(all(feature = "thread_local_entropy", not(feature = "deterministic_only")))]
 use crate::thread_local_entropy::ThreadLocalEntropy;
 
+#[cfg(feature = "deterministic_only")]
+use crate::seed::deny_entropy_seeding;
+
 #[cfg(feature = "serialize")]
 use bevy::prelude::{ReflectDeserialize, ReflectSerialize};
 
-#[cfg(feature = "serialize")]
-use serde::{Deserialize, Serialize};
+#[cfg(feature = "serialize")]
+use serde::{Deserialize, Serialize};
+
+/// The count of calls to [`GlobalEntropy::clone_tracked`], shared by every [`GlobalEntropy`] as
+/// statics can't be generic.
+#[cfg(debug_assertions)]
+static TRACKED_CLONES: AtomicUsize = AtomicUsize::new(0);
+
+/// A Global [`RngCore`] instance, meant for use as a Resource. Gets
+/// created automatically with [`crate::plugin::EntropyPlugin`], or
+/// can be created and added manually. When created via the plugin without
+/// a seed, or via [`crate::plugin::RngAppExt::init_global_entropy`], it is
+/// seeded from a [`RngConfig`] resource if one exists.
+///
+/// # Example
+///
+/// ```
+/// use bevy::prelude::*;
+/// use bevy_rand::prelude::*;
+/// use rand_core::RngCore;
+/// use bevy_prng::ChaCha8Rng;
+///
+/// fn print_random_value(mut rng: ResMut<GlobalEntropy<ChaCha8Rng>>) {
+///   println!("Random value: {}", rng.next_u32());
+/// }
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Resource, Reflect)]
+#[cfg_attr(feature = "serialize", derive(Serialize, Deserialize))]
+#[cfg_attr(
+    feature = "serialize",
+    serde(bound(deserialize = "R: for<'a> Deserialize<'a>"))
+)]
+#[cfg_attr(
+    feature = "serialize",
+    reflect(Debug, PartialEq, Resource, FromReflect, Serialize, Deserialize)
+)]
+#[cfg_attr(
+    not(feature = "serialize"),
+    reflect(Debug, PartialEq, Resource, FromReflect)
+)]
+pub struct GlobalEntropy<R: SeedableEntropySource + 'static>(R);
+
+impl<R: SeedableEntropySource + 'static> GlobalEntropy<R> {
+    /// Create a new resource from a `RngCore` instance.
+    #[inline]
+    #[must_use]
+    pub fn new(rng: R) -> Self {
+        Self(rng)
+    }
+
+    /// Create a new resource seeded from any other [`RngCore`] source, such as seeding a
+    /// faster PRNG from a [`GlobalEntropy`] of a different algorithm. Unlike the `From<&mut R>`
+    /// impls, the source doesn't have to be the same PRNG type. The source is advanced by the
+    /// length of the seed, so the same source state always produces the same resource.
+    ///
+    /// ```
+    /// use bevy_rand::prelude::*;
+    /// use bevy_prng::{ChaCha8Rng, Xoshiro256StarStar};
+    /// use rand_core::SeedableRng;
+    ///
+    /// let mut source = GlobalEntropy::<ChaCha8Rng>::from_seed([1; 32]);
+    ///
+    /// let rng = GlobalEntropy::<Xoshiro256StarStar>::from_source(&mut source);
+    /// ```
+    #[must_use]
+    pub fn from_source<S: RngCore>(source: &mut S) -> Self {
+        let mut seed = R::Seed::default();
+
+        source.fill_bytes(seed.as_mut());
+
+        Self::from_seed(seed)
+    }
+}
+
+impl<R: SeedableEntropySource + 'static> GlobalEntropy<R> {
+    /// Create a new resource from a partial seed, such as a `u64` worth of bytes for a PRNG
+    /// that expects a 32 byte seed. The given bytes fill the start of the seed, with the rest
+    /// of the seed padded with zeroes. Returns a [`SeedError::TooLong`] if more bytes are
+    /// given than fit in the seed, rather than silently discarding them.
+    ///
+    /// Note that the resulting RNG only ever has as much entropy as the given bytes, no matter
+    /// the size of the full seed. A short seed greatly reduces the number of distinct streams,
+    /// making them far easier to guess or brute-force, so this is unsuitable wherever the
+    /// output should be unpredictable. Prefer full length seeds, or
+    /// [`SeedableRng::seed_from_u64`], which expands a `u64` across the whole seed.
+    ///
+    /// ```
+    /// use bevy_rand::prelude::*;
+    /// use bevy_prng::ChaCha8Rng;
+    ///
+    /// let rng = GlobalEntropy::<ChaCha8Rng>::from_partial_seed(&42u64.to_le_bytes());
+    ///
+    /// assert!(rng.is_ok());
+    /// ```
+    pub fn from_partial_seed(bytes: &[u8]) -> Result<Self, SeedError> {
+        let mut seed = R::Seed::default();
+        let expected = seed.as_mut().len();
+
+        if bytes.len() > expected {
+            return Err(SeedError::TooLong {
+                expected,
+                actual: bytes.len(),
+            });
+        }
+
+        seed.as_mut()[..bytes.len()].copy_from_slice(bytes);
+
+        Ok(Self::from_seed(seed))
+    }
+
+    /// Deterministically combines two seeds into a new one, such as for combining a world seed
+    /// with a seed chosen by the player. Both seeds are hashed in order with SipHash-2-4, so that
+    /// every bit of the new seed depends on every bit of both seeds. The combination is
+    /// order-sensitive, so `combine_seeds(&a, &b)` is not the same as `combine_seeds(&b, &a)`,
+    /// and is stable across runs and platforms. The mixing is not cryptographically secure, so it
+    /// is not suitable for deriving secrets.
+    ///
+    /// ```
+    /// use bevy_rand::prelude::*;
+    /// use bevy_prng::ChaCha8Rng;
+    /// use rand_core::SeedableRng;
+    ///
+    /// let world_seed = [1; 32];
+    /// let player_seed = [2; 32];
+    ///
+    /// let rng = GlobalEntropy::<ChaCha8Rng>::from_seed(
+    ///     GlobalEntropy::<ChaCha8Rng>::combine_seeds(&world_seed, &player_seed),
+    /// );
+    /// ```
+    #[must_use]
+    pub fn combine_seeds(a: &R::Seed, b: &R::Seed) -> R::Seed
+    where
+        R::Seed: AsRef<[u8]>,
+    {
+        let mut seed = R::Seed::default();
+
+        mix_seeds(&[a.as_ref(), b.as_ref()], seed.as_mut());
+
+        seed
+    }
+
+    /// Deterministically derives the seed of a single session from a master seed and the id of
+    /// the session, such as a recorded timestamp, so that a specific session's RNG can be
+    /// regenerated from logs. Every session id produces a distinct, unrelated seed while keeping
+    /// the master seed as the only secret to store. Like [`GlobalEntropy::combine_seeds`], the
+    /// derivation is stable across runs and platforms, but not cryptographically secure.
+    ///
+    /// ```
+    /// use bevy_rand::prelude::*;
+    /// use bevy_prng::ChaCha8Rng;
+    /// use rand_core::SeedableRng;
+    ///
+    /// let master = [7; 32];
+    /// let session_id = 1_700_000_000;
+    ///
+    /// let rng = GlobalEntropy::<ChaCha8Rng>::from_seed(
+    ///     GlobalEntropy::<ChaCha8Rng>::session_seed(&master, session_id),
+    /// );
+    /// ```
+    #[must_use]
+    pub fn session_seed(master: &R::Seed, session_id: u64) -> R::Seed
+    where
+        R::Seed: AsRef<[u8]>,
+    {
+        let mut seed = R::Seed::default();
+
+        mix_seeds(&[master.as_ref(), &session_id.to_le_bytes()], seed.as_mut());
+
+        seed
+    }
+
+    /// Deterministically derives the seed of a single chunk of a world from a master seed and
+    /// the coordinate of the chunk, such as for generating infinite terrain. A chunk thus always
+    /// regenerates identically, regardless of the order in which chunks are visited, while
+    /// neighbouring coordinates produce distinct, unrelated seeds. Like
+    /// [`GlobalEntropy::combine_seeds`], the derivation is stable across runs and platforms, but
+    /// not cryptographically secure.
+    ///
+    /// ```
+    /// use bevy::math::IVec2;
+    /// use bevy_rand::prelude::*;
+    /// use bevy_prng::ChaCha8Rng;
+    /// use rand_core::SeedableRng;
+    ///
+    /// let world_seed = [7; 32];
+    ///
+    /// let chunk = GlobalEntropy::<ChaCha8Rng>::from_seed(
+    ///     GlobalEntropy::<ChaCha8Rng>::chunk_seed(&world_seed, IVec2::new(-3, 12)),
+    /// );
+    /// ```
+    #[must_use]
+    pub fn chunk_seed(master: &R::Seed, coord: IVec2) -> R::Seed
+    where
+        R::Seed: AsRef<[u8]>,
+    {
+        let mut seed = R::Seed::default();
+
+        mix_seeds(
+            &[
+                master.as_ref(),
+                &coord.x.to_le_bytes(),
+                &coord.y.to_le_bytes(),
+            ],
+            seed.as_mut(),
+        );
+
+        seed
+    }
+
+    /// Reseeds the internal `RngCore` instance with a new seed.
+    #[inline]
+    pub fn reseed(&mut self, seed: R::Seed) {
+        self.0 = R::from_seed(seed);
+    }
+
+    /// Reseeds the internal `RngCore` instance with a seed derived from hashing a string, such
+    /// as a seed phrase entered by a player, with the chosen [`SeedHasher`]. Picking the same
+    /// algorithm as an external tool or engine derives the same seed from the same string,
+    /// allowing seeds to be shared between them.
+    ///
+    /// ```
+    /// use bevy_rand::prelude::*;
+    /// use bevy_rand::seed::SeedHasher;
+    /// use bevy_prng::ChaCha8Rng;
+    /// use rand_core::SeedableRng;
+    ///
+    /// let mut rng1 = GlobalEntropy::<ChaCha8Rng>::from_seed([1; 32]);
+    /// let mut rng2 = GlobalEntropy::<ChaCha8Rng>::from_seed([2; 32]);
+    ///
+    /// rng1.reseed_from_str_with("correct horse battery staple", SeedHasher::Fnv);
+    /// rng2.reseed_from_str_with("correct horse battery staple", SeedHasher::Fnv);
+    ///
+    /// assert_eq!(rng1, rng2);
+    /// ```
+    pub fn reseed_from_str_with(&mut self, s: &str, hasher: SeedHasher) {
+        self.0 = hasher.seed(s.as_bytes());
+    }
+
+    /// Discards `n` `u32` outputs from the RNG, advancing its state. Applying a burn-in right
+    /// after seeding improves the statistical quality of the initial output of PRNGs that
+    /// are sensitive to poorly mixed seeds, such as the `Xoshiro`/`Xoroshiro` families when seeded
+    /// with mostly zero bits, as these take some iterations for the state to recover. PRNGs like
+    /// `ChaCha` or `WyRand` do not benefit from a burn-in. For a fixed seed and burn-in amount,
+    /// the resulting output remains fully reproducible.
+    #[inline]
+    pub fn burn_in(&mut self, n: u64) {
+        for _ in 0..n {
+            self.0.next_u32();
+        }
+    }
+
+    /// Generates a fixed-size array of random bytes, such as for salts or tokens. For the same
+    /// RNG state, a shorter array is always a prefix of a longer one.
+    ///
+    /// ```
+    /// use bevy_rand::prelude::*;
+    /// use bevy_prng::ChaCha8Rng;
+    /// use rand_core::SeedableRng;
+    ///
+    /// let mut rng = GlobalEntropy::<ChaCha8Rng>::from_seed([1; 32]);
+    ///
+    /// let salt: [u8; 16] = rng.gen_bytes();
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn gen_bytes<const N: usize>(&mut self) -> [u8; N] {
+        let mut bytes = [0; N];
+
+        self.0.fill_bytes(&mut bytes);
+
+        bytes
+    }
+
+    /// Fills `dest` with random bytes in fixed-size chunks of `chunk` bytes, each filled by its
+    /// own call to [`RngCore::fill_bytes`], with the last chunk being shorter if `dest` isn't a
+    /// multiple of `chunk`. How many bytes of output a PRNG discards when a fill doesn't end on
+    /// a word boundary differs between algorithms, so splitting variable-length fills into
+    /// fixed chunks keeps the bytes of every chunk stable, no matter the total length.
+    ///
+    /// If `chunk` is a multiple of the PRNG's word size, such as `8` for every `bevy_prng`
+    /// type, and `dest` is a multiple of `chunk`, the result is identical to a single
+    /// [`RngCore::fill_bytes`] call. Otherwise, the output differs from it, as each chunk starts
+    /// on a fresh word.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `chunk` is zero.
+    ///
+    /// ```
+    /// use bevy_rand::prelude::*;
+    /// use bevy_prng::ChaCha8Rng;
+    /// use rand_core::SeedableRng;
+    ///
+    /// let mut rng = GlobalEntropy::<ChaCha8Rng>::from_seed([1; 32]);
+    ///
+    /// let mut buffer = [0; 100];
+    ///
+    /// rng.fill_bytes_chunked(&mut buffer, 16);
+    /// ```
+    pub fn fill_bytes_chunked(&mut self, dest: &mut [u8], chunk: usize) {
+        assert!(
+            chunk > 0,
+            "fill_bytes_chunked called with a chunk size of 0"
+        );
+
+        for part in dest.chunks_mut(chunk) {
+            self.0.fill_bytes(part);
+        }
+    }
+
+    /// Branches the current state of the RNG into a new, independent instance. Unlike forking,
+    /// which derives a new seed from the source and advances it, branching leaves the source
+    /// untouched and returns an instance that will output the exact same stream as the source
+    /// from this point onwards. Both branches can then be advanced independently of each other,
+    /// which is useful for running "what-if" simulations that diverge from the same point.
+    ///
+    /// ```
+    /// use bevy_rand::prelude::*;
+    /// use bevy_prng::ChaCha8Rng;
+    /// use rand_core::{RngCore, SeedableRng};
+    ///
+    /// let mut original = GlobalEntropy::<ChaCha8Rng>::from_seed([1; 32]);
+    /// let mut branch = original.branch();
+    ///
+    /// assert_eq!(original.next_u32(), branch.next_u32());
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn branch(&self) -> Self {
+        Self(self.0.clone())
+    }
+
+    /// Clones the RNG like [`Clone::clone`], but logs a warning, for catching accidental copies
+    /// of a global stream, such as a `.clone()` in a closure, which silently break determinism
+    /// as both copies then produce the same outputs while diverging in how they're used. Use
+    /// this in place of [`Clone::clone`] while hunting for such copies, and
+    /// [`GlobalEntropy::branch`] where a copy is intended.
+    ///
+    /// In debug builds, every call is also counted, with the total count of all tracked clones
+    /// of every [`GlobalEntropy`] being returned by [`GlobalEntropy::tracked_clones`].
+    ///
+    /// ```
+    /// use bevy_rand::prelude::*;
+    /// use bevy_prng::ChaCha8Rng;
+    /// use rand_core::SeedableRng;
+    ///
+    /// let rng = GlobalEntropy::<ChaCha8Rng>::from_seed([1; 32]);
+    ///
+    /// let copy = rng.clone_tracked();
+    ///
+    /// assert_eq!(rng, copy);
+    /// ```
+    #[must_use]
+    pub fn clone_tracked(&self) -> Self {
+        #[cfg(debug_assertions)]
+        TRACKED_CLONES.fetch_add(1, Ordering::Relaxed);
+
+        warn!(
+            "{} was cloned, creating a copy of its stream. Use `branch` if this is intended.",
+            Self::short_type_path()
+        );
+
+        self.branch()
+    }
+
+    /// Returns how many times [`GlobalEntropy::clone_tracked`] has been called on any
+    /// [`GlobalEntropy`] in this process. Always returns `0` in release builds, as the calls are
+    /// only counted in debug builds.
+    #[inline]
+    #[must_use]
+    pub fn tracked_clones() -> usize {
+        #[cfg(debug_assertions)]
+        return TRACKED_CLONES.load(Ordering::Relaxed);
+
+        #[cfg(not(debug_assertions))]
+        return 0;
+    }
+
+    /// Forks two new PRNG instances from the RNG, such as for comparing the outcomes of a
+    /// gameplay experiment with A/B streams. Each child is seeded with its own full length seed
+    /// drawn from the source, so the two streams are as independent of each other as any two
+    /// consecutive forks. For the same state of the source, the same pair is always forked.
+    ///
+    /// ```
+    /// use bevy_rand::prelude::*;
+    /// use bevy_prng::ChaCha8Rng;
+    /// use rand_core::{RngCore, SeedableRng};
+    ///
+    /// let mut rng = GlobalEntropy::<ChaCha8Rng>::from_seed([1; 32]);
+    ///
+    /// let (mut a, mut b) = rng.fork_pair();
+    ///
+    /// assert_ne!(a.next_u32(), b.next_u32());
+    /// ```
+    #[must_use]
+    pub fn fork_pair(&mut self) -> (R, R) {
+        (
+            R::from_seed(self.fork_seed()),
+            R::from_seed(self.fork_seed()),
+        )
+    }
+
+    /// Forks two new PRNG instances from the RNG, with the second being seeded `offset` away
+    /// from the first, such as for A/B streams that should start from related seeds. The seed
+    /// of the first child is drawn from the source, and the seed of the second is the same seed
+    /// with `offset` added to it, treating the seed as a little-endian integer that wraps
+    /// around on overflow. An `offset` of zero forks two identical instances. For the same
+    /// state of the source and offset, the same pair is always forked.
+    ///
+    /// Note that seeds a small offset apart only produce related streams in how they were
+    /// derived, as seeding a PRNG should decorrelate its output from the bits of the seed.
+    ///
+    /// ```
+    /// use bevy_rand::prelude::*;
+    /// use bevy_prng::ChaCha8Rng;
+    /// use rand_core::{RngCore, SeedableRng};
+    ///
+    /// let mut rng = GlobalEntropy::<ChaCha8Rng>::from_seed([1; 32]);
+    ///
+    /// let (mut a, mut b) = rng.fork_correlated(1);
+    ///
+    /// assert_ne!(a.next_u32(), b.next_u32());
+    /// ```
+    #[must_use]
+    pub fn fork_correlated(&mut self, offset: u64) -> (R, R) {
+        let mut first = self.fork_seed();
+        let mut second = R::Seed::default();
+
+        second.as_mut().copy_from_slice(first.as_mut());
+
+        let mut carry = u128::from(offset);
+
+        for byte in second.as_mut() {
+            carry += u128::from(*byte);
+            *byte = carry as u8;
+            carry >>= 8;
+        }
+
+        (R::from_seed(first), R::from_seed(second))
+    }
+
+    /// Draws a new full length seed from the RNG, for seeding another PRNG instance later, such
+    /// as on another thread. For the same state of the source, the same seed is always drawn.
+    ///
+    /// ```
+    /// use bevy_rand::prelude::*;
+    /// use bevy_prng::ChaCha8Rng;
+    /// use rand_core::SeedableRng;
+    ///
+    /// let mut rng = GlobalEntropy::<ChaCha8Rng>::from_seed([1; 32]);
+    ///
+    /// let forked = ChaCha8Rng::from_seed(rng.fork_seed());
+    /// ```
+    #[must_use]
+    pub fn fork_seed(&mut self) -> R::Seed {
+        let mut seed = R::Seed::default();
+
+        self.0.fill_bytes(seed.as_mut());
+
+        seed
+    }
+
+    /// Returns an endless iterator of seeds drawn from the RNG, such as for handing out a seed
+    /// to each task of a pool of deterministic workers. Every item is the same as a call to
+    /// [`GlobalEntropy::fork_seed`], so the sequence of seeds is reproducible for the same state
+    /// of the source. The iterator borrows the RNG mutably, advancing it with each seed taken.
+    ///
+    /// ```
+    /// use bevy_rand::prelude::*;
+    /// use bevy_prng::ChaCha8Rng;
+    /// use rand_core::SeedableRng;
+    ///
+    /// let mut rng = GlobalEntropy::<ChaCha8Rng>::from_seed([1; 32]);
+    ///
+    /// let workers: Vec<_> = rng.seed_stream().take(4).map(ChaCha8Rng::from_seed).collect();
+    /// ```
+    pub fn seed_stream(&mut self) -> impl Iterator<Item = R::Seed> + '_ {
+        std::iter::repeat_with(|| self.fork_seed())
+    }
+
+    /// Pulls `n` `u32` outputs from the RNG and folds them into a 64-bit FNV-1a hash of their
+    /// little-endian bytes, advancing the stream. Comparing the checksum of a fixed seed
+    /// against a known-good value guards against the output of a PRNG silently changing, such
+    /// as after refactors or dependency updates.
+    ///
+    /// ```
+    /// use bevy_rand::prelude::*;
+    /// use bevy_prng::ChaCha8Rng;
+    /// use rand_core::SeedableRng;
+    ///
+    /// let mut rng1 = GlobalEntropy::<ChaCha8Rng>::from_seed([1; 32]);
+    /// let mut rng2 = GlobalEntropy::<ChaCha8Rng>::from_seed([1; 32]);
+    ///
+    /// assert_eq!(rng1.checksum(1000), rng2.checksum(1000));
+    /// ```
+    #[must_use]
+    pub fn checksum(&mut self, n: usize) -> u64 {
+        fnv1a_64_iter((0..n).flat_map(|_| self.0.next_u32().to_le_bytes()))
+    }
+
+    /// Folds over `items` in the order of their entities, passing the RNG to `f` for every
+    /// item, such as for accumulating random outcomes over the results of a [`Query`]. Queries
+    /// iterate in an order that depends on archetypes and storage, which can differ between runs
+    /// even with the same entities, so the items are sorted by [`Entity`] first. For the same
+    /// RNG state and set of entities, the same result is thus always produced, regardless of
+    /// the order of `items`.
+    ///
+    /// [`Query`]: bevy::prelude::Query
+    ///
+    /// ```
+    /// use bevy::prelude::*;
+    /// use bevy_rand::prelude::*;
+    /// use bevy_prng::ChaCha8Rng;
+    /// use rand_core::RngCore;
+    ///
+    /// #[derive(Component)]
+    /// struct Health(u32);
+    ///
+    /// fn total_damage(mut rng: ResMut<GlobalEntropy<ChaCha8Rng>>, query: Query<(Entity, &Health)>) {
+    ///     let damage = rng.fold_sorted(query.iter(), 0, |total, health, rng| {
+    ///         total + rng.next_u32() % (health.0 + 1)
+    ///     });
+    ///
+    ///     println!("Dealt {damage} damage");
+    /// }
+    /// ```
+    pub fn fold_sorted<T, A>(
+        &mut self,
+        items: impl Iterator<Item = (Entity, T)>,
+        init: A,
+        mut f: impl FnMut(A, T, &mut Self) -> A,
+    ) -> A {
+        let mut items: Vec<(Entity, T)> = items.collect();
+
+        items.sort_by_key(|(entity, _)| *entity);
+
+        items
+            .into_iter()
+            .fold(init, |acc, (_, item)| f(acc, item, self))
+    }
+}
+
+#[cfg(feature = "rand")]
+impl<R: SeedableEntropySource + 'static> GlobalEntropy<R> {
+    /// Forks a [`StdRng`](rand::rngs::StdRng) from the RNG, seeded from its output, for
+    /// interop with existing code that is written against `StdRng`. For a fixed seed of the
+    /// source, the forked `StdRng` is fully reproducible.
+    ///
+    /// Note that the algorithm backing `StdRng` is not the same as the `bevy_prng` types, and is
+    /// not guaranteed to remain the same across `rand` versions, so its output may change when
+    /// upgrading `rand`. Prefer forking one of the `bevy_prng` types where stability matters.
+    ///
+    /// ```
+    /// use bevy_rand::prelude::*;
+    /// use bevy_prng::ChaCha8Rng;
+    /// use rand::Rng;
+    /// use rand_core::SeedableRng;
+    ///
+    /// let mut rng = GlobalEntropy::<ChaCha8Rng>::from_seed([1; 32]);
+    ///
+    /// let mut legacy = rng.fork_std();
+    ///
+    /// let roll: u32 = legacy.gen_range(1..=6);
+    /// ```
+    #[cfg_attr(docsrs, doc(cfg(feature = "rand")))]
+    #[must_use]
+    pub fn fork_std(&mut self) -> rand::rngs::StdRng {
+        let mut seed = <rand::rngs::StdRng as SeedableRng>::Seed::default();
+
+        self.0.fill_bytes(seed.as_mut());
+
+        rand::rngs::StdRng::from_seed(seed)
+    }
+}
+
+#[cfg(feature = "wyrand")]
+impl<R: SeedableEntropySource + 'static> GlobalEntropy<R> {
+    /// Forks a cheap [`WyRand`](bevy_prng::WyRand) from the RNG, for throwaway uses like
+    /// per-frame visual jitter where speed matters more than the quality of the output. Seeding
+    /// a `WyRand` only takes a single `u64` of output from the source, and `WyRand` generates
+    /// numbers far faster than the `ChaCha` family.
+    ///
+    /// The fork is still derived deterministically from the source, but is meant for code
+    /// paths where reproducibility across runs is not critical, as it is not a substitute for
+    /// forking a [`crate::component::EntropyComponent`] for entities that should own their RNG.
+    ///
+    /// ```
+    /// use bevy_rand::prelude::*;
+    /// use bevy_prng::ChaCha8Rng;
+    /// use rand_core::{RngCore, SeedableRng};
+    ///
+    /// let mut rng = GlobalEntropy::<ChaCha8Rng>::from_seed([1; 32]);
+    ///
+    /// let mut jitter = rng.fork_fast();
+    ///
+    /// let offset = jitter.next_u32() % 4;
+    /// ```
+    #[cfg_attr(docsrs, doc(cfg(feature = "wyrand")))]
+    #[must_use]
+    pub fn fork_fast(&mut self) -> bevy_prng::WyRand {
+        bevy_prng::WyRand::from_seed(self.0.next_u64().to_le_bytes())
+    }
+}
+
+#[cfg(feature = "half")]
+impl<R: SeedableEntropySource + 'static> GlobalEntropy<R> {
+    /// Generates a half-precision float uniformly within `0.0..1.0`, such as for filling GPU
+    /// buffers of `f16` values. The value is built from the top 11 bits of a `u32` of output,
+    /// matching the 11 bits of precision of an [`f16`](half::f16), so every possible value is
+    /// exactly representable and evenly spaced, and the same bits are produced on every
+    /// platform.
+    ///
+    /// ```
+    /// use bevy_rand::prelude::*;
+    /// use bevy_prng::ChaCha8Rng;
+    /// use rand_core::SeedableRng;
+    /// use half::f16;
+    ///
+    /// let mut rng = GlobalEntropy::<ChaCha8Rng>::from_seed([1; 32]);
+    ///
+    /// let value = rng.gen_f16();
+    ///
+    /// assert!(value >= f16::ZERO && value < f16::ONE);
+    /// ```
+    #[cfg_attr(docsrs, doc(cfg(feature = "half")))]
+    pub fn gen_f16(&mut self) -> half::f16 {
+        const SCALE: f32 = 1.0 / (1 << 11) as f32;
+
+        // Converting to an `f32` and scaling by a power of two are both exact for 11 bits.
+        half::f16::from_f32((self.0.next_u32() >> 21) as f32 * SCALE)
+    }
+}
+
+#[cfg(feature = "uuid")]
+impl<R: SeedableEntropySource + 'static> GlobalEntropy<R> {
+    /// Generates a time-ordered version 7 [`Uuid`](uuid::Uuid) for the timestamp `unix_ms`,
+    /// given in milliseconds since the Unix epoch, with the remaining 74 bits filled from the
+    /// RNG. The timestamp is injected instead of being read from the system clock, so the same
+    /// timestamp and RNG state always produce the same UUID, such as for deterministic tests and
+    /// replays. Only the lower 48 bits of `unix_ms` fit into the UUID, as per RFC 9562.
+    ///
+    /// ```
+    /// use bevy_rand::prelude::*;
+    /// use bevy_prng::ChaCha8Rng;
+    /// use rand_core::SeedableRng;
+    ///
+    /// let mut rng = GlobalEntropy::<ChaCha8Rng>::from_seed([1; 32]);
+    ///
+    /// let earlier = rng.gen_uuid_v7(1_700_000_000_000);
+    /// let later = rng.gen_uuid_v7(1_700_000_000_001);
+    ///
+    /// assert_eq!(earlier.get_version_num(), 7);
+    /// assert!(earlier < later);
+    /// ```
+    #[cfg_attr(docsrs, doc(cfg(feature = "uuid")))]
+    pub fn gen_uuid_v7(&mut self, unix_ms: u64) -> uuid::Uuid {
+        let mut random_bytes = [0; 10];
+
+        self.0.fill_bytes(&mut random_bytes);
+
+        uuid::Builder::from_unix_timestamp_millis(unix_ms, &random_bytes).into_uuid()
+    }
+}
+
+impl<R: SeedableEntropySource + 'static> Default for GlobalEntropy<R> {
+    fn default() -> Self {
+        Self::from_entropy()
+    }
+}
+
+impl<R: SeedableEntropySource + SeekableRng + 'static> GlobalEntropy<R> {
+    /// Returns `true` if both instances will produce the exact same output from their current
+    /// positions onwards, comparing the seed, stream and position of the PRNGs rather than
+    /// their raw state. See [`SeekableRng::stream_eq`] for where this can differ from `==`.
+    #[inline]
+    pub fn stream_eq(&self, other: &Self) -> bool {
+        self.0.stream_eq(&other.0)
+    }
+
+    /// Returns the current position within the output stream, counted in `u32` words.
+    #[inline]
+    pub fn get_word_pos(&self) -> u128 {
+        self.0.get_word_pos()
+    }
+
+    /// Moves to the given position within the output stream, counted in `u32` words.
+    #[inline]
+    pub fn set_word_pos(&mut self, word_pos: u128) {
+        self.0.set_word_pos(word_pos);
+    }
+
+    /// Forks a child RNG scoped to the returned [`ForkGuard`], for precise accounting of how
+    /// much of the stream a piece of work consumes. The child is seeded like with
+    /// [`GlobalEntropy::fork_seed`], and the guard derefs to it. When the guard is dropped, the
+    /// RNG is advanced by as many words as the child consumed, so the RNG ends up in the same
+    /// position as if the work had drawn from it directly. Calling [`ForkGuard::discard`]
+    /// instead drops the child without advancing the RNG any further.
+    ///
+    /// ```
+    /// use bevy_rand::prelude::*;
+    /// use bevy_prng::ChaCha8Rng;
+    /// use rand_core::{RngCore, SeedableRng};
+    ///
+    /// let mut rng = GlobalEntropy::<ChaCha8Rng>::from_seed([1; 32]);
+    ///
+    /// {
+    ///     let mut child = rng.scoped_fork();
+    ///
+    ///     child.next_u32();
+    /// }
+    ///
+    /// // Forking drew 8 words for the seed, and the child consumed 1 more.
+    /// assert_eq!(rng.get_word_pos(), 9);
+    /// ```
+    #[must_use]
+    pub fn scoped_fork(&mut self) -> ForkGuard<'_, R> {
+        let child = R::from_seed(self.fork_seed());
+        let start = child.get_word_pos();
+
+        ForkGuard {
+            parent: self,
+            child,
+            start,
+            discarded: false,
+        }
+    }
+
+    /// Forks a child RNG that continues the stream of this RNG from its current position,
+    /// along with a [`TrackToken`] for committing the child back with
+    /// [`GlobalEntropy::commit`] once the work with it is done, such as when the child has to
+    /// be moved into a task. The child produces exactly the output this RNG would have, and
+    /// committing advances this RNG past everything the child consumed, so the work draws from
+    /// a single linear stream as if it had sampled this RNG directly. This RNG should not be
+    /// drawn from until the child is committed, as it would repeat the output of the child.
+    ///
+    /// ```
+    /// use bevy_rand::prelude::*;
+    /// use bevy_prng::ChaCha8Rng;
+    /// use rand_core::{RngCore, SeedableRng};
+    ///
+    /// let mut rng = GlobalEntropy::<ChaCha8Rng>::from_seed([1; 32]);
+    ///
+    /// let (mut child, token) = rng.fork_tracked();
+    ///
+    /// child.next_u64();
+    ///
+    /// rng.commit(child, token);
+    ///
+    /// assert_eq!(rng.get_word_pos(), 2);
+    /// ```
+    #[must_use]
+    pub fn fork_tracked(&mut self) -> (R, TrackToken) {
+        let child = self.0.clone();
+        let start = child.get_word_pos();
+
+        (child, TrackToken { start })
+    }
+
+    /// Commits a `child` forked by [`GlobalEntropy::fork_tracked`] back into this RNG,
+    /// advancing it by as many words as the child consumed since it was forked.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the child is positioned before where it was forked, such as when it was moved
+    /// backwards in its stream or the `token` belongs to a different fork.
+    pub fn commit(&mut self, child: R, token: TrackToken) {
+        let consumed = child
+            .get_word_pos()
+            .checked_sub(token.start)
+            .expect("commit called with a child positioned before its fork");
+
+        self.set_word_pos(self.get_word_pos() + consumed);
+    }
+}
+
+/// A token recording where a child RNG was forked by [`GlobalEntropy::fork_tracked`], for
+/// committing the child back with [`GlobalEntropy::commit`]. The token can't be copied, so
+/// every fork is committed at most once.
+#[derive(Debug, PartialEq, Eq)]
+pub struct TrackToken {
+    start: u128,
+}
+
+/// A guard over a child RNG forked from a [`GlobalEntropy`] by [`GlobalEntropy::scoped_fork`],
+/// dereferencing to the child. When dropped, the [`GlobalEntropy`] is advanced by as many words
+/// as the child consumed, unless the guard was consumed with [`ForkGuard::discard`].
+#[derive(Debug)]
+pub struct ForkGuard<'a, R: SeedableEntropySource + SeekableRng + 'static> {
+    parent: &'a mut GlobalEntropy<R>,
+    child: R,
+    start: u128,
+    discarded: bool,
+}
+
+impl<R: SeedableEntropySource + SeekableRng + 'static> ForkGuard<'_, R> {
+    /// Returns how many `u32` words the child has consumed so far, being zero if the child was
+    /// moved back before where it was forked.
+    #[inline]
+    #[must_use]
+    pub fn consumed(&self) -> u128 {
+        self.child.get_word_pos().saturating_sub(self.start)
+    }
+
+    /// Drops the child without advancing the [`GlobalEntropy`] by what it consumed.
+    #[inline]
+    pub fn discard(mut self) {
+        self.discarded = true;
+    }
+}
+
+impl<R: SeedableEntropySource + SeekableRng + 'static> Deref for ForkGuard<'_, R> {
+    type Target = R;
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        &self.child
+    }
+}
+
+impl<R: SeedableEntropySource + SeekableRng + 'static> DerefMut for ForkGuard<'_, R> {
+    #[inline]
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.child
+    }
+}
+
+impl<R: SeedableEntropySource + SeekableRng + 'static> Drop for ForkGuard<'_, R> {
+    fn drop(&mut self) {
+        if !self.discarded {
+            let word_pos = self.parent.get_word_pos() + self.consumed();
+
+            self.parent.set_word_pos(word_pos);
+        }
+    }
+}
+
+impl<R: SeedableEntropySource + ValidatedSeed + 'static> GlobalEntropy<R> {
+    /// Reseeds the internal `RngCore` instance with a new seed, after checking that the seed is
+    /// valid for the PRNG with [`ValidatedSeed::is_valid_seed`]. Returns a [`SeedError::Invalid`]
+    /// and leaves the RNG untouched if not, instead of silently producing a degenerate stream.
+    ///
+    /// ```
+    /// use bevy_rand::prelude::*;
+    /// use bevy_rand::seed::SeedError;
+    /// use bevy_prng::ChaCha8Rng;
+    /// use rand_core::SeedableRng;
+    ///
+    /// let mut rng = GlobalEntropy::<ChaCha8Rng>::from_seed([1; 32]);
+    ///
+    /// assert_eq!(rng.try_reseed([2; 32]), Ok(()));
+    /// ```
+    pub fn try_reseed(&mut self, seed: R::Seed) -> Result<(), SeedError> {
+        if !R::is_valid_seed(&seed) {
+            return Err(SeedError::Invalid);
+        }
+
+        self.reseed(seed);
+
+        Ok(())
+    }
+}
+
+impl<R: SeedableEntropySource + 'static> RngCore for GlobalEntropy<R> {
+    #[inline]
+    fn next_u32(&mut self) -> u32 {
+        self.0.next_u32()
+    }
+
+    #[inline]
+    fn next_u64(&mut self) -> u64 {
+        self.0.next_u64()
+    }
+
+    #[inline]
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        self.0.fill_bytes(dest);
+    }
+
+    #[inline]
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), rand_core::Error> {
+        self.0.try_fill_bytes(dest)
+    }
+}
+
+impl<R: SeedableEntropySource + 'static> SeedableRng for GlobalEntropy<R> {
+    type Seed = R::Seed;
+
+    #[inline]
+    fn from_seed(seed: Self::Seed) -> Self {
+        Self::new(R::from_seed(seed))
+    }
+
+    /// Creates a new instance of the RNG seeded via [`ThreadLocalEntropy`]. This method is the recommended way
+    /// to construct non-deterministic PRNGs since it is convenient and secure. It overrides the standard
+    /// [`SeedableRng::from_entropy`] method while the `thread_local_entropy` feature is enabled.
+    ///
+    /// # Panics
+    ///
+    /// If [`ThreadLocalEntropy`] cannot get initialised because `getrandom` is unable to provide secure entropy,
+    /// and no fallback has been registered via [`crate::set_fallback_seed_source`], this method will panic.
+    #[cfg(all(feature = "thread_local_entropy", not(feature = "deterministic_only")))]
+    #[cfg_attr(docsrs, doc(cfg(feature = "thread_local_entropy")))]
+    fn from_entropy() -> Self {
+        let mut seed = Self::Seed::default();
+
+        // Source entropy from thread local user-space RNG instead of
+        // system entropy source to reduce overhead when creating many
+        // rng instances for many resources at once.
+        ThreadLocalEntropy::new().fill_bytes(seed.as_mut());
+
+        Self::from_seed(seed)
+    }
+
+    /// Panics, as seeding from entropy is forbidden with the `deterministic_only` feature. This
+    /// covers every way of seeding from entropy, such as via `Default` or an unseeded
+    /// [`EntropyPlugin`](crate::plugin::EntropyPlugin).
+    #[cfg(feature = "deterministic_only")]
+    #[track_caller]
+    fn from_entropy() -> Self {
+        deny_entropy_seeding::<Self>()
+    }
+}
+
+impl<R: SeedableEntropySource + 'static> From<R> for GlobalEntropy<R> {
+    fn from(value: R) -> Self {
+        Self::new(value)
+    }
+}
+
+impl<R: SeedableEntropySource + 'static> From<&mut R> for GlobalEntropy<R> {
+    fn from(value: &mut R) -> Self {
+        Self::from_rng(value).unwrap()
+    }
+}
+
+/// A configuration resource for seeding a [`GlobalEntropy`] resource when it gets initialised
+/// by [`crate::plugin::EntropyPlugin`] without a seed given to the plugin directly, or by
+/// [`crate::plugin::RngAppExt::init_global_entropy`]. This allows seeding to be wired through
+/// configuration resources, as long as the [`RngConfig`] is inserted before the
+/// [`GlobalEntropy`] is initialised.
+///
+/// ```
+/// use bevy::prelude::*;
+/// use bevy_rand::prelude::*;
+/// use bevy_prng::ChaCha8Rng;
+///
+/// App::new()
+///     .insert_resource(RngConfig::<ChaCha8Rng>::new([1; 32]))
+///     .add_plugins(EntropyPlugin::<ChaCha8Rng>::default());
+/// ```
+#[derive(Debug, Resource)]
+pub struct RngConfig<R: SeedableEntropySource + 'static> {
+    // Stored as bytes, as `R::Seed` is not guaranteed to be `Send + Sync`.
+    seed: Box<[u8]>,
+    _source: PhantomData<R>,
+}
+
+impl<R: SeedableEntropySource + 'static> RngConfig<R> {
+    /// Create a new config with the seed to initialise [`GlobalEntropy`] with.
+    #[inline]
+    #[must_use]
+    pub fn new(mut seed: R::Seed) -> Self {
+        Self {
+            seed: seed.as_mut().into(),
+            _source: PhantomData,
+        }
+    }
+
+    /// Returns the seed the [`GlobalEntropy`] resource will be initialised with.
+    #[must_use]
+    pub fn seed(&self) -> R::Seed {
+        let mut seed = R::Seed::default();
+
+        seed.as_mut().copy_from_slice(&self.seed);
+
+        seed
+    }
+}
+
+/// A resource recording the seed that a [`GlobalEntropy`] resource was initialised with by
+/// [`crate::plugin::EntropyPlugin`], whether given explicitly, via [`RngConfig`], or sourced
+/// from entropy. This allows the seed of a run to be logged or displayed, so that it can be
+/// reproduced later by seeding with it explicitly. Not inserted by
+/// [`crate::plugin::EntropyPlugin::lazy`], as no seed is chosen at startup.
+///
+/// ```
+/// use bevy::prelude::*;
+/// use bevy_rand::prelude::*;
+/// use bevy_prng::ChaCha8Rng;
+///
+/// fn log_seed(seed: Res<InitialSeed<ChaCha8Rng>>) {
+///     info!("Seeded with {:02x?}", seed.as_bytes());
+/// }
+/// ```
+#[derive(Debug, Resource)]
+pub struct InitialSeed<R: SeedableEntropySource + 'static> {
+    // Stored as bytes, as `R::Seed` is not guaranteed to be `Send + Sync`.
+    seed: Box<[u8]>,
+    _source: PhantomData<R>,
+}
+
+impl<R: SeedableEntropySource + 'static> InitialSeed<R> {
+    /// Create a new record of the seed a [`GlobalEntropy`] was initialised with.
+    #[inline]
+    #[must_use]
+    pub fn new(mut seed: R::Seed) -> Self {
+        Self {
+            seed: seed.as_mut().into(),
+            _source: PhantomData,
+        }
+    }
+
+    /// Returns the seed the [`GlobalEntropy`] resource was initialised with.
+    #[must_use]
+    pub fn seed(&self) -> R::Seed {
+        let mut seed = R::Seed::default();
+
+        seed.as_mut().copy_from_slice(&self.seed);
+
+        seed
+    }
+
+    /// Returns the bytes of the seed, such as for logging it.
+    #[inline]
+    #[must_use]
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.seed
+    }
+}
+
+/// A [`GlobalEntropy`] resource that is only seeded from entropy on first use, for apps that
+/// may never need randomness and so shouldn't pay for sourcing entropy at startup. Inserted by
+/// [`crate::plugin::EntropyPlugin::lazy`] instead of a [`GlobalEntropy`] resource, so systems
+/// access it as `ResMut<LazyGlobalEntropy<R>>`. It can be used directly as an [`RngCore`], or
+/// via [`LazyGlobalEntropy::get`] for the methods of [`GlobalEntropy`].
+///
+/// ```
+/// use bevy::prelude::*;
+/// use bevy_rand::prelude::*;
+/// use rand_core::RngCore;
+/// use bevy_prng::ChaCha8Rng;
+///
+/// fn print_random_value(mut rng: ResMut<LazyGlobalEntropy<ChaCha8Rng>>) {
+///   println!("Random value: {}", rng.next_u32());
+/// }
+/// ```
+#[derive(Debug, Resource)]
+pub struct LazyGlobalEntropy<R: SeedableEntropySource + 'static> {
+    rng: Option<GlobalEntropy<R>>,
+    burn_in: u64,
+}
+
+impl<R: SeedableEntropySource + 'static> LazyGlobalEntropy<R> {
+    /// Create a new uninitialised resource, which discards `burn_in` outputs right after it
+    /// gets seeded. See [`GlobalEntropy::burn_in`] for which PRNGs benefit from this.
+    #[inline]
+    #[must_use]
+    pub fn new(burn_in: u64) -> Self {
+        Self { rng: None, burn_in }
+    }
+
+    /// Returns `true` if the RNG has been seeded, which happens on first use.
+    #[inline]
+    #[must_use]
+    pub fn is_initialized(&self) -> bool {
+        self.rng.is_some()
+    }
+
+    /// Returns the [`GlobalEntropy`], seeding it from entropy first if it hasn't been used yet.
+    pub fn get(&mut self) -> &mut GlobalEntropy<R> {
+        let burn_in = self.burn_in;
+
+        self.rng.get_or_insert_with(|| {
+            let mut rng = GlobalEntropy::from_entropy();
+            rng.burn_in(burn_in);
+            rng
+        })
+    }
+
+    /// Reseeds the RNG with `seed` like [`GlobalEntropy::reseed`], seeding it with `seed` instead
+    /// of from entropy if it hasn't been used yet.
+    pub(crate) fn reseed(&mut self, seed: R::Seed) {
+        match self.rng.as_mut() {
+            Some(rng) => rng.reseed(seed),
+            None => self.rng = Some(GlobalEntropy::from_seed(seed)),
+        }
+    }
+}
+
+impl<R: SeedableEntropySource + 'static> Default for LazyGlobalEntropy<R> {
+    fn default() -> Self {
+        Self::new(0)
+    }
+}
+
+impl<R: SeedableEntropySource + 'static> RngCore for LazyGlobalEntropy<R> {
+    #[inline]
+    fn next_u32(&mut self) -> u32 {
+        self.get().next_u32()
+    }
+
+    #[inline]
+    fn next_u64(&mut self) -> u64 {
+        self.get().next_u64()
+    }
+
+    #[inline]
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        self.get().fill_bytes(dest);
+    }
+
+    #[inline]
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), rand_core::Error> {
+        self.get().try_fill_bytes(dest)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy::{prelude::World, reflect::TypePath};
+    use bevy_prng::ChaCha8Rng;
+
+    use super::*;
+
+    #[test]
+    fn type_paths() {
+        assert_eq!(
+            "bevy_rand::resource::GlobalEntropy<bevy_prng::ChaCha8Rng>",
+            GlobalEntropy::<ChaCha8Rng>::type_path()
+        );
+
+        assert_eq!(
+            "GlobalEntropy<ChaCha8Rng>",
+            GlobalEntropy::<ChaCha8Rng>::short_type_path()
+        );
+    }
+
+    #[cfg(not(feature = "deterministic_only"))]
+    #[test]
+    fn init_without_config() {
+        let mut world = World::new();
+
+        world.init_resource::<GlobalEntropy<ChaCha8Rng>>();
+
+        assert!(world.contains_resource::<GlobalEntropy<ChaCha8Rng>>());
+    }
+
+    #[test]
+    fn stream_equality() {
+        let mut rng1 = GlobalEntropy::<ChaCha8Rng>::from_seed([2; 32]);
+        let mut rng2 = GlobalEntropy::<ChaCha8Rng>::from_seed([2; 32]);
+
+        // Reach the same stream position through different calls
+        rng1.next_u64();
+        rng2.next_u32();
+        rng2.next_u32();
+
+        assert!(rng1.stream_eq(&rng2));
+
+        let mut seeked = ChaCha8Rng::from_seed([2; 32]);
+        seeked.set_word_pos(2);
+
+        assert!(
+            rng1.stream_eq(&GlobalEntropy::new(seeked)),
+            "seeking to a position should be output-equivalent to advancing to it"
+        );
+
+        rng2.next_u32();
+
+        assert!(
+            !rng1.stream_eq(&rng2),
+            "different positions should not be output-equivalent"
+        );
+        assert!(!rng1.stream_eq(&GlobalEntropy::from_seed([3; 32])));
+    }
+
+    #[cfg(feature = "rand")]
+    #[test]
+    fn fork_std_reproducible() {
+        let mut rng1 = GlobalEntropy::<ChaCha8Rng>::from_seed([2; 32]);
+        let mut rng2 = GlobalEntropy::<ChaCha8Rng>::from_seed([2; 32]);
+
+        let mut forked1 = rng1.fork_std();
+        let mut forked2 = rng2.fork_std();
+
+        assert_eq!(
+            forked1.next_u64(),
+            forked2.next_u64(),
+            "forked StdRngs should be reproducible for a fixed seed"
+        );
+        assert_ne!(
+            rng1.fork_std().next_u64(),
+            forked1.next_u64(),
+            "successive forks should not match each other"
+        );
+    }
+
+    #[test]
+    fn gen_bytes() {
+        let mut rng1 = GlobalEntropy::<ChaCha8Rng>::from_seed([2; 32]);
+        let mut rng2 = GlobalEntropy::<ChaCha8Rng>::from_seed([2; 32]);
+
+        let short: [u8; 8] = rng1.gen_bytes();
+        let long: [u8; 32] = rng2.gen_bytes();
+
+        assert_eq!(
+            &short,
+            &long[..8],
+            "shorter arrays should be a prefix of longer ones"
+        );
+
+        let mut rng3 = GlobalEntropy::<ChaCha8Rng>::from_seed([2; 32]);
+
+        assert_eq!(long, rng3.gen_bytes::<32>());
+        assert_ne!(
+            rng3.gen_bytes::<32>(),
+            long,
+            "successive arrays should not match"
+        );
+    }
+
+    #[test]
+    fn partial_seeds() {
+        let exact = GlobalEntropy::<ChaCha8Rng>::from_partial_seed(&[3; 32]);
+
+        assert_eq!(exact, Ok(GlobalEntropy::from_seed([3; 32])));
+
+        let mut padded = [0; 32];
+        padded[..8].copy_from_slice(&42u64.to_le_bytes());
+
+        assert_eq!(
+            GlobalEntropy::<ChaCha8Rng>::from_partial_seed(&42u64.to_le_bytes()),
+            Ok(GlobalEntropy::from_seed(padded)),
+            "short seeds should be padded with zeroes"
+        );
+
+        assert_eq!(
+            GlobalEntropy::<ChaCha8Rng>::from_partial_seed(&[3; 33]),
+            Err(SeedError::TooLong {
+                expected: 32,
+                actual: 33
+            }),
+            "long seeds should be rejected"
+        );
+    }
+
+    #[cfg(feature = "half")]
+    #[test]
+    fn f16_within_range() {
+        use half::f16;
+
+        let mut rng = GlobalEntropy::<ChaCha8Rng>::from_seed([2; 32]);
+
+        for _ in 0..10_000 {
+            let value = rng.gen_f16();
+
+            assert!(
+                value >= f16::ZERO && value < f16::ONE,
+                "{value} is out of range"
+            );
+        }
+    }
+
+    #[cfg(feature = "half")]
+    #[test]
+    fn f16_deterministic() {
+        let mut rng1 = GlobalEntropy::<ChaCha8Rng>::from_seed([2; 32]);
+        let mut rng2 = GlobalEntropy::<ChaCha8Rng>::from_seed([2; 32]);
+
+        let values1: Vec<u16> = (0..10).map(|_| rng1.gen_f16().to_bits()).collect();
+        let values2: Vec<u16> = (0..10).map(|_| rng2.gen_f16().to_bits()).collect();
+
+        assert_eq!(values1, values2);
+
+        let mut expected = GlobalEntropy::<ChaCha8Rng>::from_seed([2; 32]);
+
+        assert_eq!(
+            f32::from(half::f16::from_bits(values1[0])),
+            (expected.next_u32() >> 21) as f32 / 2048.0,
+            "the value should be built from the top bits of the output"
+        );
+    }
+
+    #[cfg(feature = "uuid")]
+    #[test]
+    fn uuid_v7_reproducible() {
+        let mut rng1 = GlobalEntropy::<ChaCha8Rng>::from_seed([2; 32]);
+        let mut rng2 = GlobalEntropy::<ChaCha8Rng>::from_seed([2; 32]);
+
+        let unix_ms = 1_700_000_000_000;
+
+        assert_eq!(rng1.gen_uuid_v7(unix_ms), rng2.gen_uuid_v7(unix_ms));
+        assert_ne!(
+            rng1.gen_uuid_v7(unix_ms),
+            rng1.gen_uuid_v7(unix_ms),
+            "the random bits should differ between uuids"
+        );
+    }
+
+    #[cfg(feature = "uuid")]
+    #[test]
+    fn uuid_v7_encodes_timestamp() {
+        let mut rng = GlobalEntropy::<ChaCha8Rng>::from_seed([2; 32]);
+
+        let unix_ms = 0x0123_4567_89ab;
+
+        let uuid = rng.gen_uuid_v7(unix_ms);
+        let bytes = uuid.as_bytes();
+
+        let mut timestamp = [0; 8];
+        timestamp[2..].copy_from_slice(&bytes[..6]);
+
+        assert_eq!(u64::from_be_bytes(timestamp), unix_ms);
+        assert_eq!(uuid.get_version_num(), 7);
+        assert_eq!(uuid.get_variant(), uuid::Variant::RFC4122);
+    }
+
+    #[cfg(feature = "wyrand")]
+    #[test]
+    fn from_other_source() {
+        use bevy_prng::WyRand;
+
+        let mut source1 = GlobalEntropy::<ChaCha8Rng>::from_seed([2; 32]);
+        let mut source2 = GlobalEntropy::<ChaCha8Rng>::from_seed([2; 32]);
+
+        let rng1 = GlobalEntropy::<WyRand>::from_source(&mut source1);
+        let rng2 = GlobalEntropy::<WyRand>::from_source(&mut source2);
+
+        assert_eq!(rng1, rng2, "the same source should produce the same RNG");
+        assert_eq!(source1, source2);
+        assert_ne!(
+            GlobalEntropy::<WyRand>::from_source(&mut source1),
+            rng1,
+            "the source should be advanced"
+        );
+    }
+
+    #[cfg(feature = "wyrand")]
+    #[test]
+    fn fork_fast_deterministic() {
+        let mut rng1 = GlobalEntropy::<ChaCha8Rng>::from_seed([2; 32]);
+        let mut rng2 = GlobalEntropy::<ChaCha8Rng>::from_seed([2; 32]);
+
+        let forked1 = rng1.fork_fast();
+        let forked2 = rng2.fork_fast();
+
+        assert_eq!(
+            forked1, forked2,
+            "forks should be derived deterministically"
+        );
+        assert_eq!(rng1, rng2, "forking should advance the source identically");
+        assert_ne!(
+            rng1.fork_fast(),
+            forked1,
+            "successive forks should not match each other"
+        );
+    }
+
+    #[test]
+    fn try_reseed() {
+        use bevy_prng::Xoshiro256StarStar;
+
+        let mut rng = GlobalEntropy::<Xoshiro256StarStar>::from_seed([2; 32]);
+        let untouched = rng.clone();
+
+        assert_eq!(
+            rng.try_reseed([0; 32]),
+            Err(SeedError::Invalid),
+            "all-zero seeds are invalid for xoshiro"
+        );
+        assert_eq!(rng, untouched, "an invalid seed should not reseed the RNG");
+
+        assert_eq!(rng.try_reseed([3; 32]), Ok(()));
+        assert_eq!(rng, GlobalEntropy::from_seed([3; 32]));
+
+        let mut chacha = GlobalEntropy::<ChaCha8Rng>::from_seed([2; 32]);
+
+        assert_eq!(
+            chacha.try_reseed([0; 32]),
+            Ok(()),
+            "every seed is valid for chacha"
+        );
+    }
+
+    #[test]
+    fn forking_pairs() {
+        let mut rng1 = GlobalEntropy::<ChaCha8Rng>::from_seed([2; 32]);
+        let mut rng2 = GlobalEntropy::<ChaCha8Rng>::from_seed([2; 32]);
+
+        let (a1, b1) = rng1.fork_pair();
+        let (a2, b2) = rng2.fork_pair();
+
+        assert_eq!((&a1, &b1), (&a2, &b2), "pairs should be reproducible");
+        assert_ne!(a1, b1, "the streams of a pair should be distinct");
+        assert_ne!(rng1.fork_pair().0, a1);
+    }
+
+    #[test]
+    fn forking_correlated_pairs() {
+        let mut rng1 = GlobalEntropy::<ChaCha8Rng>::from_seed([2; 32]);
+        let mut rng2 = GlobalEntropy::<ChaCha8Rng>::from_seed([2; 32]);
+
+        let mut source = rng1.clone();
+        let mut seed = [0; 32];
+        source.fill_bytes(&mut seed);
+
+        let (a1, b1) = rng1.fork_correlated(300);
+        let (a2, b2) = rng2.fork_correlated(300);
+
+        assert_eq!((&a1, &b1), (&a2, &b2), "pairs should be reproducible");
+        assert_ne!(a1, b1, "the streams of a pair should be distinct");
+        assert_eq!(a1, ChaCha8Rng::from_seed(seed));
+
+        // Adding 300 carries over into the second byte.
+        let (low, carry) = seed[0].overflowing_add(44);
+        seed[0] = low;
+        seed[1] = seed[1].wrapping_add(1 + u8::from(carry));
+
+        assert_eq!(
+            b1,
+            ChaCha8Rng::from_seed(seed),
+            "the second seed should be offset from the first"
+        );
+
+        let (same1, same2) = rng1.fork_correlated(0);
+
+        assert_eq!(same1, same2, "a zero offset should fork identical streams");
+    }
+
+    #[test]
+    fn seed_stream_matches_forks() {
+        let mut rng1 = GlobalEntropy::<ChaCha8Rng>::from_seed([2; 32]);
+        let mut rng2 = GlobalEntropy::<ChaCha8Rng>::from_seed([2; 32]);
+
+        let streamed: Vec<_> = rng1.seed_stream().take(5).collect();
+        let forked: Vec<_> = (0..5).map(|_| rng2.fork_seed()).collect();
+
+        assert_eq!(
+            streamed, forked,
+            "the stream should yield the same seeds as forking"
+        );
+        assert_eq!(rng1, rng2, "the stream should advance the source");
+        assert_ne!(streamed[0], streamed[1]);
+    }
+
+    #[test]
+    fn checksum_golden() {
+        let mut rng = GlobalEntropy::<ChaCha8Rng>::from_seed([2; 32]);
+
+        assert_eq!(
+            rng.checksum(0),
+            0xcbf2_9ce4_8422_2325,
+            "an empty checksum should be the offset basis"
+        );
+        assert_eq!(rng.checksum(1000), 0x911a_c94c_131f_4ecb);
+        assert_eq!(
+            rng.checksum(1000),
+            0xba70_77c2_1a85_c91a,
+            "the stream should be advanced"
+        );
+    }
 
-/// A Global [`RngCore`] instance, meant for use as a Resource. Gets
-/// created automatically with [`crate::plugin::EntropyPlugin`], or
-/// can be created and added manually.
-///
-/// # Example
-///
-/// ```
-/// use bevy::prelude::*;
-/// use bevy_rand::prelude::*;
-/// use rand_core::RngCore;
-/// use bevy_prng::ChaCha8Rng;
-///
-/// fn print_random_value(mut rng: ResMut<GlobalEntropy<ChaCha8Rng>>) {
-///   println!("Random value: {}", rng.next_u32());
-/// }
-/// ```
-#[derive(Debug, Clone, PartialEq, Eq, Resource, Reflect)]
-#[cfg_attr(feature = "serialize", derive(Serialize, Deserialize))]
-#[cfg_attr(
-    feature = "serialize",
-    serde(bound(deserialize = "R: for<'a> Deserialize<'a>"))
-)]
-#[cfg_attr(
-    feature = "serialize",
-    reflect(Debug, PartialEq, Resource, FromReflect, Serialize, Deserialize)
-)]
-#[cfg_attr(
-    not(feature = "serialize"),
-    reflect(Debug, PartialEq, Resource, FromReflect)
-)]
-pub struct GlobalEntropy<R: SeedableEntropySource + 'static>(R);
+    #[test]
+    fn str_seeds_stable() {
+        let mut rng = GlobalEntropy::<ChaCha8Rng>::from_seed([2; 32]);
 
-impl<R: SeedableEntropySource + 'static> GlobalEntropy<R> {
-    /// Create a new resource from a `RngCore` instance.
-    #[inline]
-    #[must_use]
-    pub fn new(rng: R) -> Self {
-        Self(rng)
+        rng.reseed_from_str_with("bevy", SeedHasher::Sip);
+
+        assert_eq!(
+            rng.next_u64(),
+            14_376_228_524_888_193_522,
+            "sip seeds should match the golden value"
+        );
+
+        rng.reseed_from_str_with("bevy", SeedHasher::Fnv);
+
+        assert_eq!(
+            rng.next_u64(),
+            15_438_527_559_679_862_995,
+            "fnv seeds should match the golden value"
+        );
+
+        let mut other = GlobalEntropy::<ChaCha8Rng>::from_seed([3; 32]);
+
+        other.reseed_from_str_with("bevy", SeedHasher::Fnv);
+        rng.reseed_from_str_with("bevy", SeedHasher::Fnv);
+
+        assert_eq!(rng, other, "the previous state should not affect the seed");
+
+        other.reseed_from_str_with("bevy", SeedHasher::Sip);
+
+        assert_ne!(rng, other, "algorithms should derive different seeds");
     }
-}
 
-impl<R: SeedableEntropySource + 'static> GlobalEntropy<R> {
-    /// Reseeds the internal `RngCore` instance with a new seed.
-    #[inline]
-    pub fn reseed(&mut self, seed: R::Seed) {
-        self.0 = R::from_seed(seed);
+    #[cfg(feature = "blake3")]
+    #[test]
+    fn blake3_str_seeds_stable() {
+        let mut rng = GlobalEntropy::<ChaCha8Rng>::from_seed([2; 32]);
+
+        rng.reseed_from_str_with("bevy", SeedHasher::Blake3);
+
+        let mut seed = [0; 32];
+        seed.copy_from_slice(blake3::hash(b"bevy").as_bytes());
+
+        assert_eq!(
+            rng,
+            GlobalEntropy::<ChaCha8Rng>::from_seed(seed),
+            "the seed should be the hash of the string"
+        );
+        assert_eq!(
+            rng.next_u64(),
+            10_639_221_846_126_258_853,
+            "blake3 seeds should match the golden value"
+        );
     }
-}
 
-impl<R: SeedableEntropySource + 'static> Default for GlobalEntropy<R> {
-    fn default() -> Self {
-        Self::from_entropy()
+    #[cfg(debug_assertions)]
+    #[test]
+    fn tracked_clones_counted() {
+        let rng = GlobalEntropy::<ChaCha8Rng>::from_seed([2; 32]);
+
+        let before = GlobalEntropy::<ChaCha8Rng>::tracked_clones();
+
+        let copy = rng.clone_tracked();
+
+        assert_eq!(copy, rng);
+        assert!(
+            GlobalEntropy::<ChaCha8Rng>::tracked_clones() > before,
+            "tracked clones should be counted"
+        );
+
+        let before = GlobalEntropy::<ChaCha8Rng>::tracked_clones();
+
+        let _ = rng.clone();
+
+        assert_eq!(
+            GlobalEntropy::<ChaCha8Rng>::tracked_clones(),
+            before,
+            "plain clones should not be counted"
+        );
     }
-}
 
-impl<R: SeedableEntropySource + 'static> RngCore for GlobalEntropy<R> {
-    #[inline]
-    fn next_u32(&mut self) -> u32 {
-        self.0.next_u32()
+    #[test]
+    fn scoped_fork_advances_parent() {
+        let mut parent = GlobalEntropy::<ChaCha8Rng>::from_seed([2; 32]);
+        let mut expected = parent.clone();
+
+        {
+            let mut child = parent.scoped_fork();
+
+            child.next_u32();
+            child.next_u64();
+            child.fill_bytes(&mut [0; 12]);
+
+            assert_eq!(child.consumed(), 6);
+        }
+
+        // Forking draws a full seed of 8 words from the parent.
+        expected.set_word_pos(expected.get_word_pos() + 8 + 6);
+
+        assert!(
+            parent.stream_eq(&expected),
+            "the parent should be advanced by what the child consumed"
+        );
+        assert_eq!(parent.next_u32(), expected.next_u32());
     }
 
-    #[inline]
-    fn next_u64(&mut self) -> u64 {
-        self.0.next_u64()
+    #[test]
+    fn scoped_fork_discarded() {
+        let mut parent = GlobalEntropy::<ChaCha8Rng>::from_seed([2; 32]);
+        let mut expected = parent.clone();
+
+        let mut child = parent.scoped_fork();
+
+        child.next_u64();
+        child.discard();
+
+        let _ = expected.fork_seed();
+
+        assert!(
+            parent.stream_eq(&expected),
+            "a discarded child should only advance the parent by the fork"
+        );
+
+        let mut child = parent.scoped_fork();
+        let mut forked = ChaCha8Rng::from_seed(expected.fork_seed());
+
+        assert_eq!(
+            child.next_u64(),
+            forked.next_u64(),
+            "the child should be seeded like a forked seed"
+        );
     }
 
-    #[inline]
-    fn fill_bytes(&mut self, dest: &mut [u8]) {
-        self.0.fill_bytes(dest);
+    #[test]
+    fn scoped_fork_seeked_backwards() {
+        let mut parent = GlobalEntropy::<ChaCha8Rng>::from_seed([2; 32]);
+        let mut expected = parent.clone();
+
+        {
+            let mut child = parent.scoped_fork();
+
+            // Forks of ChaCha start at the beginning of their stream, so the child is treated
+            // as forked further in, to then be moved back before that point.
+            child.start = 16;
+            child.set_word_pos(4);
+
+            assert_eq!(child.consumed(), 0);
+        }
+
+        let _ = expected.fork_seed();
+
+        assert!(
+            parent.stream_eq(&expected),
+            "a child moved backwards should only advance the parent by the fork"
+        );
     }
 
-    #[inline]
-    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), rand_core::Error> {
-        self.0.try_fill_bytes(dest)
+    #[test]
+    fn tracked_fork_keeps_stream_linear() {
+        let mut parent = GlobalEntropy::<ChaCha8Rng>::from_seed([2; 32]);
+        let mut direct = parent.clone();
+
+        let (mut child, token) = parent.fork_tracked();
+
+        let forked: Vec<u32> = (0..5).map(|_| child.next_u32()).collect();
+        let sampled: Vec<u32> = (0..5).map(|_| direct.next_u32()).collect();
+
+        assert_eq!(
+            forked, sampled,
+            "the child should continue the stream of the parent"
+        );
+
+        parent.commit(child, token);
+
+        assert_eq!(parent.get_word_pos(), direct.get_word_pos());
+        assert!(
+            parent.stream_eq(&direct),
+            "the parent should be advanced by what the child consumed"
+        );
+        assert_eq!(parent.next_u64(), direct.next_u64());
     }
-}
 
-impl<R: SeedableEntropySource + 'static> SeedableRng for GlobalEntropy<R> {
-    type Seed = R::Seed;
+    #[test]
+    fn tracked_fork_unused() {
+        let mut parent = GlobalEntropy::<ChaCha8Rng>::from_seed([2; 32]);
+        let expected = parent.clone();
 
-    #[inline]
-    fn from_seed(seed: Self::Seed) -> Self {
-        Self::new(R::from_seed(seed))
+        let (child, token) = parent.fork_tracked();
+
+        parent.commit(child, token);
+
+        assert!(
+            parent.stream_eq(&expected),
+            "an unused child should not advance the parent"
+        );
     }
 
-    /// Creates a new instance of the RNG seeded via [`ThreadLocalEntropy`]. This method is the recommended way
-    /// to construct non-deterministic PRNGs since it is convenient and secure. It overrides the standard
-    /// [`SeedableRng::from_entropy`] method while the `thread_local_entropy` feature is enabled.
-    ///
-    /// # Panics
-    ///
-    /// If [`ThreadLocalEntropy`] cannot get initialised because `getrandom` is unable to provide secure entropy,
-    /// this method will panic.
-    #[cfg(feature = "thread_local_entropy")]
-    #[cfg_attr(docsrs, doc(cfg(feature = "thread_local_entropy")))]
-    fn from_entropy() -> Self {
-        let mut seed = Self::Seed::default();
+    #[test]
+    #[should_panic]
+    fn tracked_fork_rewound_child() {
+        let mut parent = GlobalEntropy::<ChaCha8Rng>::from_seed([2; 32]);
 
-        // Source entropy from thread local user-space RNG instead of
-        // system entropy source to reduce overhead when creating many
-        // rng instances for many resources at once.
-        ThreadLocalEntropy::new().fill_bytes(seed.as_mut());
+        parent.next_u64();
 
-        Self::from_seed(seed)
+        let (mut child, token) = parent.fork_tracked();
+
+        child.set_word_pos(0);
+
+        parent.commit(child, token);
     }
-}
 
-impl<R: SeedableEntropySource + 'static> From<R> for GlobalEntropy<R> {
-    fn from(value: R) -> Self {
-        Self::new(value)
+    #[test]
+    fn fold_sorted_ignores_input_order() {
+        let mut world = World::new();
+
+        let entities: Vec<(Entity, u32)> = (0..20)
+            .map(|value| (world.spawn_empty().id(), value))
+            .collect();
+
+        let mut rng1 = GlobalEntropy::<ChaCha8Rng>::from_seed([2; 32]);
+        let mut rng2 = GlobalEntropy::<ChaCha8Rng>::from_seed([2; 32]);
+
+        let fold = |mut acc: Vec<u32>, value: u32, rng: &mut GlobalEntropy<ChaCha8Rng>| {
+            acc.push(value ^ rng.next_u32());
+            acc
+        };
+
+        let forwards = rng1.fold_sorted(entities.iter().copied(), Vec::new(), fold);
+        let backwards = rng2.fold_sorted(entities.iter().rev().copied(), Vec::new(), fold);
+
+        assert_eq!(forwards, backwards);
+
+        let mut expected = GlobalEntropy::<ChaCha8Rng>::from_seed([2; 32]);
+
+        assert_eq!(
+            forwards,
+            (0..20)
+                .map(|value| value ^ expected.next_u32())
+                .collect::<Vec<_>>(),
+            "items should be folded in the order of their entities"
+        );
+
+        // The fold may also mutate state it captures.
+        let mut visited = Vec::new();
+
+        rng1.fold_sorted(entities.iter().rev().copied(), (), |(), value, _| {
+            visited.push(value);
+        });
+
+        assert_eq!(visited, (0..20).collect::<Vec<_>>());
     }
-}
 
-impl<R: SeedableEntropySource + 'static> From<&mut R> for GlobalEntropy<R> {
-    fn from(value: &mut R) -> Self {
-        Self::from_rng(value).unwrap()
+    #[test]
+    fn chunked_fills() {
+        let mut chunked = GlobalEntropy::<ChaCha8Rng>::from_seed([2; 32]);
+        let mut unchunked = chunked.clone();
+
+        let mut a = [0; 64];
+        let mut b = [0; 64];
+
+        chunked.fill_bytes_chunked(&mut a, 8);
+        unchunked.fill_bytes(&mut b);
+
+        assert_eq!(a, b, "word aligned chunks should match an unchunked fill");
+
+        let mut source = GlobalEntropy::<ChaCha8Rng>::from_seed([2; 32]);
+        let mut a = [0; 14];
+        let mut b = [0; 14];
+
+        source.clone().fill_bytes_chunked(&mut a, 7);
+        source.fill_bytes(&mut b);
+
+        // Each chunk of 7 bytes starts on a fresh 4 byte word, discarding the rest of the last.
+        assert_eq!(a[..7], b[..7]);
+        assert_ne!(
+            a[7..],
+            b[7..],
+            "unaligned chunks should differ from an unchunked fill"
+        );
     }
-}
 
-#[cfg(test)]
-mod tests {
-    use bevy::reflect::TypePath;
-    use bevy_prng::ChaCha8Rng;
+    #[test]
+    fn combining_seeds() {
+        type Rng = GlobalEntropy<ChaCha8Rng>;
 
-    use super::*;
+        let combined = Rng::combine_seeds(&[1; 32], &[2; 32]);
+
+        assert_eq!(
+            combined,
+            Rng::combine_seeds(&[1; 32], &[2; 32]),
+            "combining should be stable"
+        );
+        assert_ne!(
+            combined,
+            Rng::combine_seeds(&[2; 32], &[1; 32]),
+            "combining should be order-sensitive"
+        );
+        assert_ne!(combined, Rng::combine_seeds(&[1; 32], &[3; 32]));
+        assert_ne!(combined, Rng::combine_seeds(&[3; 32], &[2; 32]));
+        assert_eq!(
+            &combined[..8],
+            &[97, 211, 205, 85, 72, 240, 41, 0],
+            "combining should match the golden seed"
+        );
+    }
 
     #[test]
-    fn type_paths() {
+    fn session_seeds() {
+        type Rng = GlobalEntropy<ChaCha8Rng>;
+
         assert_eq!(
-            "bevy_rand::resource::GlobalEntropy<bevy_prng::ChaCha8Rng>",
-            GlobalEntropy::<ChaCha8Rng>::type_path()
+            Rng::session_seed(&[1; 32], 42),
+            Rng::session_seed(&[1; 32], 42),
+            "the same master and session id should reproduce the seed"
+        );
+        assert_ne!(
+            Rng::session_seed(&[1; 32], 42),
+            Rng::session_seed(&[1; 32], 43),
+            "different session ids should produce different seeds"
+        );
+        assert_ne!(
+            Rng::session_seed(&[1; 32], 42),
+            Rng::session_seed(&[2; 32], 42),
+            "different masters should produce different seeds"
         );
+    }
+
+    #[test]
+    fn chunk_seeds() {
+        type Rng = GlobalEntropy<ChaCha8Rng>;
 
         assert_eq!(
-            "GlobalEntropy<ChaCha8Rng>",
-            GlobalEntropy::<ChaCha8Rng>::short_type_path()
+            Rng::chunk_seed(&[1; 32], IVec2::new(4, -2)),
+            Rng::chunk_seed(&[1; 32], IVec2::new(4, -2)),
+            "the same master and coordinate should reproduce the seed"
+        );
+        assert_ne!(
+            Rng::chunk_seed(&[1; 32], IVec2::new(4, -2)),
+            Rng::chunk_seed(&[2; 32], IVec2::new(4, -2)),
+            "different masters should produce different seeds"
+        );
+        assert_ne!(
+            Rng::chunk_seed(&[1; 32], IVec2::new(1, 2)),
+            Rng::chunk_seed(&[1; 32], IVec2::new(2, 1)),
+            "swapped axes should produce different seeds"
+        );
+
+        let origin = Rng::chunk_seed(&[1; 32], IVec2::ZERO);
+
+        for neighbour in [IVec2::X, IVec2::NEG_X, IVec2::Y, IVec2::NEG_Y, IVec2::ONE] {
+            let seed = Rng::chunk_seed(&[1; 32], neighbour);
+
+            let differing: u32 = origin
+                .iter()
+                .zip(seed.iter())
+                .map(|(a, b)| (a ^ b).count_ones())
+                .sum();
+
+            // Unrelated seeds differ in about half of their 256 bits.
+            assert!(
+                (96..=160).contains(&differing),
+                "the seed of {neighbour} differs from the origin in {differing} bits"
+            );
+        }
+    }
+
+    #[test]
+    fn burn_in() {
+        let mut rng1 = GlobalEntropy::<ChaCha8Rng>::from_seed([2; 32]);
+        let mut rng2 = GlobalEntropy::<ChaCha8Rng>::from_seed([2; 32]);
+
+        rng1.burn_in(0);
+
+        assert_eq!(rng1, rng2, "a burn-in of zero should not advance the RNG");
+
+        rng1.burn_in(8);
+
+        assert_ne!(rng1, rng2, "a burn-in should advance the RNG");
+
+        for _ in 0..8 {
+            rng2.next_u32();
+        }
+
+        assert_eq!(
+            rng1, rng2,
+            "a burn-in should be the same as discarding the output"
+        );
+
+        let mut rng3 = GlobalEntropy::<ChaCha8Rng>::from_seed([2; 32]);
+
+        rng3.burn_in(8);
+
+        assert_eq!(
+            rng1.next_u64(),
+            rng3.next_u64(),
+            "post burn-in output should be reproducible"
+        );
+    }
+
+    #[test]
+    fn branching() {
+        let mut rng1 = GlobalEntropy::<ChaCha8Rng>::from_seed([2; 32]);
+
+        rng1.next_u64();
+
+        let mut rng2 = rng1.branch();
+
+        assert_eq!(rng1, rng2, "branches should start from the same state");
+        assert_eq!(
+            rng1.next_u32(),
+            rng2.next_u32(),
+            "branches should output the same stream"
+        );
+
+        // Advancing one branch must not affect the other
+        rng1.next_u64();
+
+        assert_ne!(rng1, rng2, "branches should be advanced independently");
+
+        let mut rng3 = rng2.branch();
+
+        rng2.next_u64();
+        rng3.next_u64();
+
+        assert_eq!(
+            rng2.next_u32(),
+            rng3.next_u32(),
+            "branches advanced identically should continue to match"
         );
     }
 
@@ -174,10 +1942,16 @@ mod tests {
 
         let serialized = to_string(&ser).unwrap();
 
+        #[cfg(not(feature = "human_readable"))]
         assert_eq!(
             &serialized,
             "{\"bevy_rand::resource::GlobalEntropy<bevy_prng::ChaCha8Rng>\":(((seed:(7,7,7,7,7,7,7,7,7,7,7,7,7,7,7,7,7,7,7,7,7,7,7,7,7,7,7,7,7,7,7,7),stream:0,word_pos:1)))}"
         );
+        #[cfg(feature = "human_readable")]
+        assert_eq!(
+            &serialized,
+            "{\"bevy_rand::resource::GlobalEntropy<bevy_prng::ChaCha8Rng>\":(((seed:\"0707070707070707070707070707070707070707070707070707070707070707\",stream:0,word_pos:1)))}"
+        );
 
         let mut deserializer = ron::Deserializer::from_str(&serialized).unwrap();
 
@@ -224,10 +1998,16 @@ mod tests {
 
         let serialized = to_string(&ser).unwrap();
 
+        #[cfg(not(feature = "human_readable"))]
         assert_eq!(
             &serialized,
             "(((seed:(7,7,7,7,7,7,7,7,7,7,7,7,7,7,7,7,7,7,7,7,7,7,7,7,7,7,7,7,7,7,7,7),stream:0,word_pos:1)))"
         );
+        #[cfg(feature = "human_readable")]
+        assert_eq!(
+            &serialized,
+            "(((seed:\"0707070707070707070707070707070707070707070707070707070707070707\",stream:0,word_pos:1)))"
+        );
 
         let mut deserializer = ron::Deserializer::from_str(&serialized).unwrap();
 
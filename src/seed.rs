@@ -0,0 +1,307 @@
+use std::fmt;
+
+use rand_core::SeedableRng;
+
+/// Errors that can occur when constructing or validating a seed for a PRNG.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SeedError {
+    /// The provided seed bytes are longer than the seed of the PRNG, so cannot be used
+    /// without discarding some of them.
+    TooLong {
+        /// The length in bytes of the seed of the PRNG.
+        expected: usize,
+        /// The length in bytes of the provided seed.
+        actual: usize,
+    },
+    /// The seed would put the PRNG in a degenerate state, as determined by
+    /// [`ValidatedSeed::is_valid_seed`](bevy_prng::ValidatedSeed::is_valid_seed).
+    Invalid,
+}
+
+impl fmt::Display for SeedError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::TooLong { expected, actual } => write!(
+                f,
+                "seed of {actual} bytes is longer than the expected {expected} bytes"
+            ),
+            Self::Invalid => write!(f, "seed is invalid for the PRNG"),
+        }
+    }
+}
+
+impl std::error::Error for SeedError {}
+
+/// Hash algorithms for deriving a seed from a string, as used by
+/// [`GlobalEntropy::reseed_from_str_with`](crate::resource::GlobalEntropy::reseed_from_str_with).
+/// Seeds derived from the same string are stable across runs, platforms and versions. For
+/// [`SeedHasher::Sip`] and [`SeedHasher::Fnv`], only the intermediate 64-bit hash follows the
+/// standard algorithm and matches that of external tools, while expanding it into a full seed
+/// uses the [`SeedableRng::seed_from_u64`] of the PRNG. That defaults to a PCG based expansion
+/// specific to `rand_core`, unless overridden by the PRNG.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum SeedHasher {
+    /// SipHash-2-4 with a key of all zeroes, producing a `u64` that is expanded into a full
+    /// seed with [`SeedableRng::seed_from_u64`].
+    Sip,
+    /// 64-bit FNV-1a, producing a `u64` that is expanded into a full seed with
+    /// [`SeedableRng::seed_from_u64`].
+    Fnv,
+    /// BLAKE3 in its extendable output mode, filling the full seed with hash output directly.
+    #[cfg(feature = "blake3")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "blake3")))]
+    Blake3,
+}
+
+impl SeedHasher {
+    /// Creates a PRNG from the seed derived by hashing `input`.
+    pub(crate) fn seed<R: SeedableRng>(self, input: &[u8]) -> R {
+        match self {
+            Self::Sip => R::seed_from_u64(sip_hash_2_4([0, 0], input)),
+            Self::Fnv => R::seed_from_u64(fnv1a_64(input)),
+            #[cfg(feature = "blake3")]
+            Self::Blake3 => {
+                let mut seed = R::Seed::default();
+
+                blake3::Hasher::new()
+                    .update(input)
+                    .finalize_xof()
+                    .fill(seed.as_mut());
+
+                R::from_seed(seed)
+            }
+        }
+    }
+}
+
+/// Hashes `input` with SipHash-2-4, keyed with the two little endian halves of a 128-bit key.
+fn sip_hash_2_4(key: [u64; 2], input: &[u8]) -> u64 {
+    fn sip_round(v: &mut [u64; 4]) {
+        v[0] = v[0].wrapping_add(v[1]);
+        v[1] = v[1].rotate_left(13) ^ v[0];
+        v[0] = v[0].rotate_left(32);
+        v[2] = v[2].wrapping_add(v[3]);
+        v[3] = v[3].rotate_left(16) ^ v[2];
+        v[0] = v[0].wrapping_add(v[3]);
+        v[3] = v[3].rotate_left(21) ^ v[0];
+        v[2] = v[2].wrapping_add(v[1]);
+        v[1] = v[1].rotate_left(17) ^ v[2];
+        v[2] = v[2].rotate_left(32);
+    }
+
+    fn compress(v: &mut [u64; 4], word: u64) {
+        v[3] ^= word;
+        sip_round(v);
+        sip_round(v);
+        v[0] ^= word;
+    }
+
+    let mut v = [
+        key[0] ^ 0x736f_6d65_7073_6575,
+        key[1] ^ 0x646f_7261_6e64_6f6d,
+        key[0] ^ 0x6c79_6765_6e65_7261,
+        key[1] ^ 0x7465_6462_7974_6573,
+    ];
+
+    let words = input.chunks_exact(8);
+    let tail = words.remainder();
+
+    for word in words {
+        // `chunks_exact` only yields chunks of 8 bytes, so the conversion can't fail.
+        compress(&mut v, u64::from_le_bytes(word.try_into().unwrap()));
+    }
+
+    // The final word holds the remaining bytes, with the length of the input in the top byte.
+    let mut last = [0; 8];
+    last[..tail.len()].copy_from_slice(tail);
+    last[7] = input.len() as u8;
+
+    compress(&mut v, u64::from_le_bytes(last));
+
+    v[2] ^= 0xff;
+
+    for _ in 0..4 {
+        sip_round(&mut v);
+    }
+
+    v[0] ^ v[1] ^ v[2] ^ v[3]
+}
+
+/// Hashes `input` with 64-bit FNV-1a.
+pub(crate) fn fnv1a_64(input: &[u8]) -> u64 {
+    fnv1a_64_iter(input.iter().copied())
+}
+
+/// Hashes the bytes yielded by `input` with 64-bit FNV-1a, without collecting them first.
+pub(crate) fn fnv1a_64_iter(input: impl IntoIterator<Item = u8>) -> u64 {
+    const FNV_OFFSET_BASIS: u64 = 0xcbf2_9ce4_8422_2325;
+    const FNV_PRIME: u64 = 0x0000_0100_0000_01b3;
+
+    input.into_iter().fold(FNV_OFFSET_BASIS, |hash, byte| {
+        (hash ^ u64::from(byte)).wrapping_mul(FNV_PRIME)
+    })
+}
+
+/// Panics on an attempt to seed `T` from entropy, which the `deterministic_only` feature
+/// forbids.
+#[cfg(feature = "deterministic_only")]
+#[track_caller]
+pub(crate) fn deny_entropy_seeding<T: bevy::reflect::TypePath>() -> ! {
+    panic!(
+        "{} was seeded from entropy, which the `deterministic_only` feature forbids. Seed it explicitly instead.",
+        T::short_type_path()
+    )
+}
+
+/// Returns `true` if the seed is likely to be a configuration mistake with little entropy,
+/// being all zeroes or having at most a quarter of its bytes non-zero, as happens when padding a
+/// short seed with zeroes.
+pub(crate) fn is_weak_seed(seed: &[u8]) -> bool {
+    let non_zero = seed.iter().filter(|&&byte| byte != 0).count();
+
+    non_zero * 4 <= seed.len()
+}
+
+/// Key of the seed mixer, being the first word of the BLAKE2b IV, so that mixed seeds don't
+/// coincide with plain SipHash outputs.
+const MIXER_KEY: u64 = 0x6a09_e667_f3bc_c908;
+
+/// Deterministically mixes the given inputs into `out`, so that every bit of the output
+/// depends on every bit of every input. The inputs are encoded in order, each prefixed by its
+/// length, so the output is sensitive to both the order and the boundaries of the inputs. Every
+/// 8 byte block of the output is then the SipHash-2-4 of the encoding, keyed by the index of
+/// the block. This is stable across runs and platforms, but is not a cryptographic hash.
+pub(crate) fn mix_seeds(inputs: &[&[u8]], out: &mut [u8]) {
+    let mut encoded = Vec::with_capacity(inputs.iter().map(|input| input.len() + 8).sum());
+
+    for input in inputs {
+        encoded.extend_from_slice(&(input.len() as u64).to_le_bytes());
+        encoded.extend_from_slice(input);
+    }
+
+    for (block, chunk) in out.chunks_mut(8).enumerate() {
+        let word = sip_hash_2_4([MIXER_KEY, block as u64], &encoded).to_le_bytes();
+
+        chunk.copy_from_slice(&word[..chunk.len()]);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn mixing() {
+        let mut a = [0; 32];
+        let mut b = [0; 32];
+
+        mix_seeds(&[&[1, 2, 3], &[4]], &mut a);
+        mix_seeds(&[&[1, 2, 3], &[4]], &mut b);
+
+        assert_eq!(a, b, "mixing should be deterministic");
+
+        mix_seeds(&[&[1, 2], &[3, 4]], &mut b);
+
+        assert_ne!(a, b, "the boundaries of inputs should affect the output");
+
+        mix_seeds(&[&[4], &[1, 2, 3]], &mut b);
+
+        assert_ne!(a, b, "the order of inputs should affect the output");
+
+        let mut short = [0; 5];
+
+        mix_seeds(&[&[1, 2, 3], &[4]], &mut short);
+
+        assert_eq!(short, a[..5], "shorter outputs should be a prefix");
+    }
+
+    #[test]
+    fn mixing_avalanche() {
+        let input = [0x5a; 32];
+        let mut mixed = [0; 32];
+
+        mix_seeds(&[&input], &mut mixed);
+
+        let mut total = 0;
+
+        for bit in 0..input.len() * 8 {
+            let mut flipped_input = input;
+            flipped_input[bit / 8] ^= 1 << (bit % 8);
+
+            let mut flipped = [0; 32];
+
+            mix_seeds(&[&flipped_input], &mut flipped);
+
+            let differing: u32 = mixed
+                .iter()
+                .zip(flipped)
+                .map(|(a, b)| (a ^ b).count_ones())
+                .sum();
+
+            // Each flip should change about half of the 256 output bits.
+            assert!(
+                (88..=168).contains(&differing),
+                "flipping input bit {bit} changed {differing} output bits"
+            );
+
+            total += differing;
+        }
+
+        let average = f64::from(total) / 256.0;
+
+        assert!(
+            (124.0..=132.0).contains(&average),
+            "flipping an input bit changed {average} output bits on average"
+        );
+    }
+
+    #[test]
+    fn weak_seeds() {
+        assert!(is_weak_seed(&[0; 32]), "all zero seeds are weak");
+
+        let mut padded = [0; 32];
+        padded[..8].copy_from_slice(&u64::MAX.to_le_bytes());
+
+        assert!(is_weak_seed(&padded), "mostly zero seeds are weak");
+
+        let strong: Vec<u8> = (1..=32).collect();
+
+        assert!(!is_weak_seed(&strong));
+        assert!(
+            !is_weak_seed(&[1; 32]),
+            "repeated non-zero bytes are not weak"
+        );
+    }
+
+    #[test]
+    fn sip_hash_matches_reference() {
+        #[allow(deprecated)]
+        fn reference(key: [u64; 2], input: &[u8]) -> u64 {
+            use std::hash::{Hasher, SipHasher};
+
+            let mut hasher = SipHasher::new_with_keys(key[0], key[1]);
+            hasher.write(input);
+            hasher.finish()
+        }
+
+        let input: Vec<u8> = (0..64).collect();
+
+        for key in [[0, 0], [0x0706_0504_0302_0100, 0x0f0e_0d0c_0b0a_0908]] {
+            for len in 0..input.len() {
+                assert_eq!(
+                    sip_hash_2_4(key, &input[..len]),
+                    reference(key, &input[..len]),
+                    "hash of {len} bytes with key {key:x?} should match"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn fnv_hash_matches_reference() {
+        assert_eq!(fnv1a_64(b""), 0xcbf2_9ce4_8422_2325);
+        assert_eq!(fnv1a_64(b"a"), 0xaf63_dc4c_8601_ec8c);
+        assert_eq!(fnv1a_64(b"foobar"), 0x8594_4171_f739_67e8);
+    }
+}
@@ -0,0 +1,816 @@
+use std::{cmp::Ordering, collections::HashMap};
+
+use crate::{resource::GlobalEntropy, traits::SeedableEntropySource};
+use bevy::{
+    ecs::query::ReadOnlyWorldQuery,
+    prelude::{Component, Entity, Query},
+};
+use rand::{
+    distributions::{Distribution, WeightedIndex},
+    seq::SliceRandom,
+    Rng,
+};
+
+/// Returns `weight` if it can be used for a weighted choice, and zero if the weight is zero,
+/// negative or not finite, so it is never chosen.
+fn valid_weight(weight: f32) -> f32 {
+    if weight.is_finite() && weight > 0.0 {
+        weight
+    } else {
+        0.0
+    }
+}
+
+impl<R: SeedableEntropySource + 'static> GlobalEntropy<R> {
+    /// Chooses an item from `items`, with the likelihood of each item being chosen being
+    /// proportional to the weight computed for it by `weight`. Items with a weight that is zero,
+    /// negative or not finite are skipped and never chosen. Returns `None` if there are no
+    /// items that can be chosen.
+    ///
+    /// ```
+    /// use bevy_rand::prelude::*;
+    /// use bevy_prng::ChaCha8Rng;
+    /// use rand_core::SeedableRng;
+    ///
+    /// let mut rng = GlobalEntropy::<ChaCha8Rng>::from_seed([1; 32]);
+    ///
+    /// let items = [("common", 10), ("rare", 1), ("never", 0)];
+    ///
+    /// let chosen = rng.choose_weighted_by(&items, |item| item.1 as f32);
+    ///
+    /// assert_ne!(chosen.map(|item| item.0), Some("never"));
+    /// ```
+    #[cfg_attr(docsrs, doc(cfg(feature = "rand")))]
+    pub fn choose_weighted_by<'a, T>(
+        &mut self,
+        items: &'a [T],
+        weight: impl Fn(&T) -> f32,
+    ) -> Option<&'a T> {
+        let weights = items.iter().map(|item| valid_weight(weight(item)));
+
+        // Only fails if there are no items or all weights are zero, so nothing can be chosen.
+        let index = WeightedIndex::new(weights).ok()?;
+
+        Some(&items[index.sample(self)])
+    }
+
+    /// Picks a tile index in proportion to `weights`, the core random step of wave function
+    /// collapse, where a cell that is still in superposition is collapsed into one of the tiles
+    /// it allows. Tiles that are no longer possible in the cell should be given a weight of
+    /// zero, and like with [`GlobalEntropy::choose_weighted_by`], tiles with a weight that is
+    /// zero, negative or not finite are never picked. For the same RNG state and weights, the
+    /// same tile is always picked, so a WFC loop that visits cells in a deterministic order
+    /// generates the same level for the same seed.
+    ///
+    /// # Panics
+    ///
+    /// Panics if no tile has a positive weight. In WFC, this is a contradiction, which should
+    /// be detected during propagation and handled by backtracking or restarting instead.
+    ///
+    /// A WFC loop repeatedly collapses the cell with the fewest possible tiles, breaking ties by
+    /// the lowest index, and then propagates the constraints of the picked tile to the
+    /// neighbouring cells:
+    ///
+    /// ```
+    /// use bevy_rand::prelude::*;
+    /// use bevy_prng::ChaCha8Rng;
+    /// use rand_core::SeedableRng;
+    ///
+    /// let mut rng = GlobalEntropy::<ChaCha8Rng>::from_seed([1; 32]);
+    ///
+    /// // Water, sand and grass, where water and grass can't be next to each other.
+    /// let weights = [3.0, 1.0, 2.0];
+    /// let allowed = |a: usize, b: usize| a.abs_diff(b) <= 1;
+    ///
+    /// let options = |cell: &[bool; 3]| cell.iter().filter(|&&possible| possible).count();
+    /// let mut cells = vec![[true; 3]; 16];
+    ///
+    /// while let Some(cell) = (0..cells.len())
+    ///     .filter(|&cell| options(&cells[cell]) > 1)
+    ///     .min_by_key(|&cell| options(&cells[cell]))
+    /// {
+    ///     let possible: Vec<f32> = (0..3)
+    ///         .map(|tile| if cells[cell][tile] { weights[tile] } else { 0.0 })
+    ///         .collect();
+    ///
+    ///     let tile = rng.collapse(&possible);
+    ///
+    ///     cells[cell] = [false; 3];
+    ///     cells[cell][tile] = true;
+    ///
+    ///     // Remove the tiles of neighbours that are no longer allowed next to any option.
+    ///     let mut changed = vec![cell];
+    ///
+    ///     while let Some(current) = changed.pop() {
+    ///         for neighbour in [current.wrapping_sub(1), current + 1] {
+    ///             if neighbour >= cells.len() {
+    ///                 continue;
+    ///             }
+    ///
+    ///             for tile in 0..3 {
+    ///                 let supported =
+    ///                     (0..3).any(|other| cells[current][other] && allowed(tile, other));
+    ///
+    ///                 if cells[neighbour][tile] && !supported {
+    ///                     cells[neighbour][tile] = false;
+    ///                     changed.push(neighbour);
+    ///                 }
+    ///             }
+    ///         }
+    ///     }
+    /// }
+    ///
+    /// let level: Vec<usize> = cells
+    ///     .iter()
+    ///     .map(|cell| cell.iter().position(|&possible| possible).unwrap())
+    ///     .collect();
+    ///
+    /// assert!(level.windows(2).all(|pair| allowed(pair[0], pair[1])));
+    /// ```
+    #[cfg_attr(docsrs, doc(cfg(feature = "rand")))]
+    pub fn collapse(&mut self, weights: &[f32]) -> usize {
+        WeightedIndex::new(weights.iter().map(|&weight| valid_weight(weight)))
+            .expect("collapse called without any tile of a positive weight")
+            .sample(self)
+    }
+
+    /// Chooses an entity from `query`, with the likelihood of each entity being chosen being
+    /// proportional to the weight computed from its component `C` by `weight`, such as for
+    /// picking an AI target by threat level. Like [`GlobalEntropy::choose_weighted_by`], entities
+    /// with a weight that is zero, negative or not finite are never chosen. Queries iterate in
+    /// an order that can differ between runs, so the entities are sorted first, making the
+    /// choice only depend on the RNG state and the set of entities. Returns `None` if the query
+    /// is empty, or no entity can be chosen.
+    ///
+    /// ```
+    /// use bevy::prelude::*;
+    /// use bevy_rand::prelude::*;
+    /// use bevy_prng::ChaCha8Rng;
+    ///
+    /// #[derive(Component)]
+    /// struct Threat(f32);
+    ///
+    /// fn pick_target(mut rng: ResMut<GlobalEntropy<ChaCha8Rng>>, q_threats: Query<(Entity, &Threat)>) {
+    ///     if let Some(target) = rng.choose_weighted_entity(&q_threats, |threat| threat.0) {
+    ///         println!("Targeting {target:?}");
+    ///     }
+    /// }
+    /// ```
+    #[cfg_attr(docsrs, doc(cfg(feature = "rand")))]
+    pub fn choose_weighted_entity<C: Component, F: ReadOnlyWorldQuery>(
+        &mut self,
+        query: &Query<(Entity, &C), F>,
+        weight: impl Fn(&C) -> f32,
+    ) -> Option<Entity> {
+        let mut entities: Vec<(Entity, &C)> = query.iter().collect();
+
+        entities.sort_unstable_by_key(|(entity, _)| *entity);
+
+        self.choose_weighted_by(&entities, |(_, component)| weight(component))
+            .map(|(entity, _)| *entity)
+    }
+
+    /// Shuffles only enough of `slice` to move `k` randomly chosen elements to its front, via a
+    /// partial Fisher–Yates shuffle. Returns the slice split into the `k` chosen elements and the
+    /// remaining elements, which are left in an unspecified order. If `k` is greater than the
+    /// length of the slice, the whole slice is shuffled. Useful for cases like drawing `k` cards
+    /// from a deck without needing to shuffle the whole deck.
+    ///
+    /// ```
+    /// use bevy_rand::prelude::*;
+    /// use bevy_prng::ChaCha8Rng;
+    /// use rand_core::SeedableRng;
+    ///
+    /// let mut rng = GlobalEntropy::<ChaCha8Rng>::from_seed([1; 32]);
+    ///
+    /// let mut deck: Vec<u32> = (1..=52).collect();
+    ///
+    /// let (hand, rest) = rng.partial_shuffle(&mut deck, 5);
+    ///
+    /// assert_eq!(hand.len(), 5);
+    /// assert_eq!(rest.len(), 47);
+    /// ```
+    #[cfg_attr(docsrs, doc(cfg(feature = "rand")))]
+    pub fn partial_shuffle<'a, T>(
+        &mut self,
+        slice: &'a mut [T],
+        k: usize,
+    ) -> (&'a mut [T], &'a mut [T]) {
+        let k = k.min(slice.len());
+
+        for i in 0..k {
+            let j = self.gen_range(i..slice.len());
+
+            slice.swap(i, j);
+        }
+
+        slice.split_at_mut(k)
+    }
+
+    /// Shuffles the elements of `slice` after the first `fixed_prefix` elements, leaving the
+    /// prefix in place, such as for shuffling a menu while keeping pinned items at the top. Does
+    /// nothing if `fixed_prefix` covers the whole slice. For the same RNG state and tail, the
+    /// same shuffle is always produced, regardless of the contents of the prefix.
+    ///
+    /// ```
+    /// use bevy_rand::prelude::*;
+    /// use bevy_prng::ChaCha8Rng;
+    /// use rand_core::SeedableRng;
+    ///
+    /// let mut rng = GlobalEntropy::<ChaCha8Rng>::from_seed([1; 32]);
+    ///
+    /// let mut menu = ["continue", "new game", "arcade", "versus", "training"];
+    ///
+    /// rng.shuffle_tail(&mut menu, 2);
+    ///
+    /// assert_eq!(&menu[..2], &["continue", "new game"]);
+    /// ```
+    #[cfg_attr(docsrs, doc(cfg(feature = "rand")))]
+    pub fn shuffle_tail<T>(&mut self, slice: &mut [T], fixed_prefix: usize) {
+        if let Some(tail) = slice.get_mut(fixed_prefix..) {
+            tail.shuffle(self);
+        }
+    }
+
+    /// Shuffles `items` into a random order that is biased by the weight computed for each item
+    /// by `weight`, via the Efraimidis–Spirakis weighted random permutation. Each position is
+    /// filled as if by drawing from the remaining items with a likelihood proportional to their
+    /// weight, so items with higher weights tend to end up towards the front. Items with a weight
+    /// that is zero, negative or not finite are placed after all other items, in a uniformly
+    /// random order.
+    ///
+    /// ```
+    /// use bevy_rand::prelude::*;
+    /// use bevy_prng::ChaCha8Rng;
+    /// use rand_core::SeedableRng;
+    ///
+    /// let mut rng = GlobalEntropy::<ChaCha8Rng>::from_seed([1; 32]);
+    ///
+    /// let mut queue = [("casual", 1.0), ("priority", 5.0), ("afk", 0.0)];
+    ///
+    /// rng.weighted_shuffle(&mut queue, |player| player.1);
+    ///
+    /// assert_eq!(queue[2].0, "afk");
+    /// ```
+    #[cfg_attr(docsrs, doc(cfg(feature = "rand")))]
+    pub fn weighted_shuffle<T>(&mut self, items: &mut [T], weight: impl Fn(&T) -> f32) {
+        // Keys of `u^(1 / w)` are compared in log space as `ln(u) / w`, which orders the same
+        // but avoids the precision loss of raising to tiny powers.
+        let keys: Vec<(bool, f64)> = items
+            .iter()
+            .map(|item| {
+                let weight = weight(item);
+                let u = 1.0 - self.gen::<f64>();
+
+                if weight.is_finite() && weight > 0.0 {
+                    (true, u.ln() / f64::from(weight))
+                } else {
+                    (false, u)
+                }
+            })
+            .collect();
+
+        let mut order: Vec<usize> = (0..items.len()).collect();
+
+        order.sort_by(|&a, &b| {
+            keys[b]
+                .0
+                .cmp(&keys[a].0)
+                .then_with(|| keys[b].1.partial_cmp(&keys[a].1).unwrap_or(Ordering::Equal))
+        });
+
+        // Apply the sorted order in place by following each cycle of the permutation.
+        let mut placed = vec![false; items.len()];
+
+        for start in 0..items.len() {
+            let mut current = start;
+
+            while !placed[current] {
+                placed[current] = true;
+
+                let next = order[current];
+
+                if next == start {
+                    break;
+                }
+
+                items.swap(current, next);
+                current = next;
+            }
+        }
+    }
+
+    /// Collects the entries of `map` into a `Vec` in a random order. The entries are sorted by
+    /// key before being shuffled, so for the same RNG state and map contents, the same order is
+    /// always produced, regardless of the iteration order of the map or its hasher.
+    ///
+    /// ```
+    /// use std::collections::HashMap;
+    ///
+    /// use bevy_rand::prelude::*;
+    /// use bevy_prng::ChaCha8Rng;
+    /// use rand_core::SeedableRng;
+    ///
+    /// let mut rng = GlobalEntropy::<ChaCha8Rng>::from_seed([1; 32]);
+    ///
+    /// let loot = HashMap::from([("sword", 1), ("shield", 2), ("potion", 5)]);
+    ///
+    /// let entries = rng.shuffled_entries(&loot);
+    ///
+    /// assert_eq!(entries.len(), 3);
+    /// ```
+    #[cfg_attr(docsrs, doc(cfg(feature = "rand")))]
+    pub fn shuffled_entries<K: Ord + Clone, V: Clone, S>(
+        &mut self,
+        map: &HashMap<K, V, S>,
+    ) -> Vec<(K, V)> {
+        let mut entries: Vec<(K, V)> = map
+            .iter()
+            .map(|(key, value)| (key.clone(), value.clone()))
+            .collect();
+
+        // Keys in a map are unique, so sorting by them gives a total order of the entries.
+        entries.sort_unstable_by(|a, b| a.0.cmp(&b.0));
+        entries.shuffle(self);
+
+        entries
+    }
+
+    /// Randomly splits `items` into `groups` balanced groups, such as for assigning players to
+    /// teams. The items are shuffled and then dealt out one at a time to each group in turn, so
+    /// the sizes of the groups differ by at most one, with the earlier groups getting the extra
+    /// items when they can't be split evenly. Groups are left empty if there are fewer items
+    /// than groups. For the same RNG state and items, the same groups are always produced.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `groups` is zero, as the items can't be split into no groups.
+    ///
+    /// ```
+    /// use bevy_rand::prelude::*;
+    /// use bevy_prng::ChaCha8Rng;
+    /// use rand_core::SeedableRng;
+    ///
+    /// let mut rng = GlobalEntropy::<ChaCha8Rng>::from_seed([1; 32]);
+    ///
+    /// let players = vec!["ada", "bob", "cy", "dee", "eve"];
+    ///
+    /// let teams = rng.partition_into(players, 2);
+    ///
+    /// assert_eq!(teams[0].len(), 3);
+    /// assert_eq!(teams[1].len(), 2);
+    /// ```
+    #[cfg_attr(docsrs, doc(cfg(feature = "rand")))]
+    pub fn partition_into<T>(&mut self, mut items: Vec<T>, groups: usize) -> Vec<Vec<T>> {
+        assert!(groups > 0, "partition_into called with zero groups");
+
+        items.shuffle(self);
+
+        let mut partition: Vec<Vec<T>> = (0..groups)
+            .map(|group| Vec::with_capacity((items.len() + groups - 1 - group) / groups))
+            .collect();
+
+        for (index, item) in items.into_iter().enumerate() {
+            partition[index % groups].push(item);
+        }
+
+        partition
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy::{ecs::system::SystemState, prelude::World};
+    use bevy_prng::ChaCha8Rng;
+    use rand_core::SeedableRng;
+
+    use super::*;
+
+    #[test]
+    fn weighted_by_skips_invalid_weights() {
+        let mut rng = GlobalEntropy::<ChaCha8Rng>::from_seed([5; 32]);
+
+        let items = [-3.0, 0.0, 2.0, f32::NAN, f32::INFINITY];
+
+        for _ in 0..100 {
+            assert_eq!(rng.choose_weighted_by(&items, |&item| item), Some(&2.0));
+        }
+    }
+
+    #[test]
+    fn weighted_by_nothing_to_choose() {
+        let mut rng = GlobalEntropy::<ChaCha8Rng>::from_seed([5; 32]);
+
+        assert_eq!(rng.choose_weighted_by(&[] as &[u32], |_| 1.0), None);
+        assert_eq!(rng.choose_weighted_by(&[1, 2, 3], |_| 0.0), None);
+    }
+
+    #[test]
+    fn weighted_by_follows_weights() {
+        let mut rng = GlobalEntropy::<ChaCha8Rng>::from_seed([5; 32]);
+
+        let items = [1u32, 9];
+        let mut counts = [0u32; 2];
+
+        for _ in 0..1000 {
+            let chosen = rng.choose_weighted_by(&items, |&item| item as f32).unwrap();
+
+            counts[usize::from(*chosen == 9)] += 1;
+        }
+
+        assert!(
+            counts[1] > counts[0] * 4,
+            "heavier weighted items should be chosen more often: {counts:?}"
+        );
+    }
+
+    #[test]
+    fn collapse_follows_weights() {
+        let mut rng = GlobalEntropy::<ChaCha8Rng>::from_seed([5; 32]);
+
+        let weights = [1.0, 0.0, 3.0, f32::NAN, 6.0, -2.0];
+        let mut counts = [0u32; 6];
+
+        for _ in 0..10_000 {
+            counts[rng.collapse(&weights)] += 1;
+        }
+
+        assert_eq!(
+            counts[1] + counts[3] + counts[5],
+            0,
+            "tiles without a valid weight should never be picked"
+        );
+
+        for (tile, expected) in [(0, 1_000), (2, 3_000), (4, 6_000)] {
+            assert!(
+                counts[tile].abs_diff(expected) < 200,
+                "tile {tile} was picked {} times, expected about {expected}",
+                counts[tile]
+            );
+        }
+    }
+
+    #[test]
+    fn collapse_deterministic() {
+        let mut rng1 = GlobalEntropy::<ChaCha8Rng>::from_seed([5; 32]);
+        let mut rng2 = GlobalEntropy::<ChaCha8Rng>::from_seed([5; 32]);
+
+        let weights = [0.5, 2.0, 1.5, 0.25];
+
+        let tiles1: Vec<usize> = (0..50).map(|_| rng1.collapse(&weights)).collect();
+        let tiles2: Vec<usize> = (0..50).map(|_| rng2.collapse(&weights)).collect();
+
+        assert_eq!(tiles1, tiles2);
+    }
+
+    #[test]
+    #[should_panic]
+    fn collapse_contradiction() {
+        let mut rng = GlobalEntropy::<ChaCha8Rng>::from_seed([5; 32]);
+
+        rng.collapse(&[0.0, 0.0]);
+    }
+
+    #[test]
+    fn partial_shuffle_subset() {
+        let mut rng = GlobalEntropy::<ChaCha8Rng>::from_seed([5; 32]);
+
+        let mut deck: Vec<u32> = (0..20).collect();
+
+        let (chosen, rest) = rng.partial_shuffle(&mut deck, 5);
+
+        assert_eq!(chosen.len(), 5);
+        assert_eq!(rest.len(), 15);
+
+        // No cards should be lost or duplicated by the partial shuffle
+        let mut sorted = deck.clone();
+        sorted.sort_unstable();
+
+        assert_eq!(sorted, (0..20).collect::<Vec<_>>());
+        assert_ne!(
+            &deck[..5],
+            &[0, 1, 2, 3, 4],
+            "the front of the deck should be shuffled"
+        );
+    }
+
+    #[test]
+    fn partial_shuffle_bounds() {
+        let mut rng = GlobalEntropy::<ChaCha8Rng>::from_seed([5; 32]);
+
+        let mut deck = [1, 2, 3];
+
+        let (chosen, rest) = rng.partial_shuffle(&mut deck, 0);
+
+        assert!(chosen.is_empty());
+        assert_eq!(rest, &[1, 2, 3]);
+
+        let (chosen, rest) = rng.partial_shuffle(&mut deck, 10);
+
+        assert_eq!(chosen.len(), 3);
+        assert!(rest.is_empty());
+    }
+
+    #[test]
+    fn partial_shuffle_deterministic() {
+        let mut rng1 = GlobalEntropy::<ChaCha8Rng>::from_seed([5; 32]);
+        let mut rng2 = GlobalEntropy::<ChaCha8Rng>::from_seed([5; 32]);
+
+        let mut deck1: Vec<u32> = (0..52).collect();
+        let mut deck2 = deck1.clone();
+
+        assert_eq!(
+            rng1.partial_shuffle(&mut deck1, 7).0,
+            rng2.partial_shuffle(&mut deck2, 7).0
+        );
+    }
+
+    #[test]
+    fn shuffle_tail_keeps_prefix() {
+        let mut rng = GlobalEntropy::<ChaCha8Rng>::from_seed([5; 32]);
+
+        let mut items: Vec<u32> = (0..20).collect();
+
+        rng.shuffle_tail(&mut items, 5);
+
+        assert_eq!(&items[..5], &[0, 1, 2, 3, 4]);
+
+        let mut tail = items[5..].to_vec();
+        tail.sort_unstable();
+
+        assert_eq!(tail, (5..20).collect::<Vec<_>>());
+        assert_ne!(
+            &items[5..],
+            &tail[..],
+            "the tail of the items should be shuffled"
+        );
+
+        let mut short = [1, 2, 3];
+
+        rng.shuffle_tail(&mut short, 3);
+        rng.shuffle_tail(&mut short, 10);
+
+        assert_eq!(short, [1, 2, 3]);
+    }
+
+    #[test]
+    fn shuffle_tail_deterministic() {
+        let mut rng1 = GlobalEntropy::<ChaCha8Rng>::from_seed([5; 32]);
+        let mut rng2 = GlobalEntropy::<ChaCha8Rng>::from_seed([5; 32]);
+
+        let mut items1: Vec<u32> = (0..30).collect();
+        // A different prefix, as only the tail should affect the shuffle.
+        let mut items2: Vec<u32> = (100..103).chain(3..30).collect();
+
+        rng1.shuffle_tail(&mut items1, 3);
+        rng2.shuffle_tail(&mut items2, 3);
+
+        assert_eq!(&items1[3..], &items2[3..]);
+    }
+
+    #[test]
+    fn weighted_shuffle_favours_heavy_items() {
+        let mut rng = GlobalEntropy::<ChaCha8Rng>::from_seed([5; 32]);
+
+        let mut leads = 0;
+
+        for _ in 0..1000 {
+            let mut items = [1.0, 1.0, 1000.0, 1.0, 1.0];
+
+            rng.weighted_shuffle(&mut items, |&item| item);
+
+            leads += u32::from(items[0] == 1000.0);
+        }
+
+        assert!(
+            leads > 980,
+            "the heaviest item should almost always lead: {leads}"
+        );
+    }
+
+    #[test]
+    fn weighted_shuffle_is_permutation() {
+        let mut rng = GlobalEntropy::<ChaCha8Rng>::from_seed([5; 32]);
+
+        let mut items: Vec<u32> = (0..50).collect();
+
+        rng.weighted_shuffle(&mut items, |&item| item as f32);
+
+        assert_eq!(
+            items.iter().position(|&item| item == 0),
+            Some(49),
+            "zero weighted items should be placed last"
+        );
+
+        items.sort_unstable();
+
+        assert_eq!(items, (0..50).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn weighted_shuffle_deterministic() {
+        let mut rng1 = GlobalEntropy::<ChaCha8Rng>::from_seed([5; 32]);
+        let mut rng2 = GlobalEntropy::<ChaCha8Rng>::from_seed([5; 32]);
+
+        let mut items1: Vec<u32> = (0..20).collect();
+        let mut items2 = items1.clone();
+
+        rng1.weighted_shuffle(&mut items1, |&item| item as f32 + 1.0);
+        rng2.weighted_shuffle(&mut items2, |&item| item as f32 + 1.0);
+
+        assert_eq!(items1, items2);
+        assert_ne!(
+            items1,
+            (0..20).collect::<Vec<_>>(),
+            "the items should be shuffled"
+        );
+    }
+
+    #[test]
+    fn weighted_by_deterministic() {
+        let mut rng1 = GlobalEntropy::<ChaCha8Rng>::from_seed([5; 32]);
+        let mut rng2 = GlobalEntropy::<ChaCha8Rng>::from_seed([5; 32]);
+
+        let items = ["a", "bb", "ccc", "dddd"];
+
+        for _ in 0..10 {
+            assert_eq!(
+                rng1.choose_weighted_by(&items, |item| item.len() as f32),
+                rng2.choose_weighted_by(&items, |item| item.len() as f32)
+            );
+        }
+    }
+
+    #[test]
+    fn shuffled_entries_reproducible() {
+        let mut rng1 = GlobalEntropy::<ChaCha8Rng>::from_seed([5; 32]);
+        let mut rng2 = GlobalEntropy::<ChaCha8Rng>::from_seed([5; 32]);
+
+        // Separately built maps have different iteration orders, due to their random hashers.
+        let map1: HashMap<u32, String> = (0..50).map(|key| (key, key.to_string())).collect();
+        let map2: HashMap<u32, String> = (0..50).rev().map(|key| (key, key.to_string())).collect();
+
+        let entries1 = rng1.shuffled_entries(&map1);
+        let entries2 = rng2.shuffled_entries(&map2);
+
+        assert_eq!(entries1, entries2);
+
+        let mut sorted = entries1.clone();
+        sorted.sort();
+
+        assert_eq!(
+            sorted,
+            (0..50)
+                .map(|key| (key, key.to_string()))
+                .collect::<Vec<_>>(),
+            "every entry should be collected exactly once"
+        );
+        assert_ne!(entries1, sorted, "the entries should be shuffled");
+    }
+
+    #[test]
+    fn shuffled_entries_empty_map() {
+        let mut rng = GlobalEntropy::<ChaCha8Rng>::from_seed([5; 32]);
+
+        assert!(rng.shuffled_entries(&HashMap::<u32, u32>::new()).is_empty());
+    }
+
+    #[test]
+    fn partition_balanced() {
+        let mut rng = GlobalEntropy::<ChaCha8Rng>::from_seed([5; 32]);
+
+        for (len, groups) in [(10, 3), (9, 3), (2, 5), (0, 4), (100, 1)] {
+            let partition = rng.partition_into((0..len).collect(), groups);
+
+            assert_eq!(partition.len(), groups);
+
+            let sizes: Vec<usize> = partition.iter().map(Vec::len).collect();
+            let (min, max) = (sizes.iter().min().unwrap(), sizes.iter().max().unwrap());
+
+            assert!(max - min <= 1, "group sizes should be balanced: {sizes:?}");
+
+            let mut items: Vec<u32> = partition.into_iter().flatten().collect();
+            items.sort_unstable();
+
+            assert_eq!(
+                items,
+                (0..len).collect::<Vec<_>>(),
+                "every item should be in exactly one group"
+            );
+        }
+    }
+
+    #[test]
+    fn partition_deterministic() {
+        let mut rng1 = GlobalEntropy::<ChaCha8Rng>::from_seed([5; 32]);
+        let mut rng2 = GlobalEntropy::<ChaCha8Rng>::from_seed([5; 32]);
+
+        let items: Vec<u32> = (0..30).collect();
+
+        let partition1 = rng1.partition_into(items.clone(), 4);
+        let partition2 = rng2.partition_into(items, 4);
+
+        assert_eq!(partition1, partition2);
+        assert_ne!(
+            partition1[0],
+            vec![0, 4, 8, 12, 16, 20, 24, 28],
+            "the items should be shuffled before being dealt"
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn partition_zero_groups() {
+        let mut rng = GlobalEntropy::<ChaCha8Rng>::from_seed([5; 32]);
+
+        rng.partition_into(vec![1, 2, 3], 0);
+    }
+
+    #[derive(Component)]
+    struct Threat(f32);
+
+    #[derive(Component)]
+    struct Marker;
+
+    #[test]
+    fn weighted_entity_follows_weights() {
+        let mut world = World::new();
+
+        let low = world.spawn(Threat(1.0)).id();
+        let high = world.spawn(Threat(9.0)).id();
+        world.spawn(Threat(0.0));
+
+        let mut state = SystemState::<Query<(Entity, &Threat)>>::new(&mut world);
+        let q_threats = state.get(&world);
+
+        let mut rng = GlobalEntropy::<ChaCha8Rng>::from_seed([5; 32]);
+
+        let mut counts = [0u32; 2];
+
+        for _ in 0..1000 {
+            let chosen = rng
+                .choose_weighted_entity(&q_threats, |threat| threat.0)
+                .unwrap();
+
+            assert!(
+                chosen == low || chosen == high,
+                "zero weights should never be chosen"
+            );
+
+            counts[usize::from(chosen == high)] += 1;
+        }
+
+        assert!(
+            counts[0] > 0 && counts[1] > counts[0] * 4,
+            "heavier weighted entities should be chosen more often: {counts:?}"
+        );
+    }
+
+    #[test]
+    fn weighted_entity_ignores_query_order() {
+        let spawn_threats = |marked: bool| {
+            let mut world = World::new();
+
+            let entities: Vec<Entity> = (1..=10)
+                .map(|threat| world.spawn(Threat(threat as f32)).id())
+                .collect();
+
+            // Moving entities into another archetype changes the query iteration order.
+            if marked {
+                for &entity in entities.iter().step_by(2) {
+                    world.entity_mut(entity).insert(Marker);
+                }
+            }
+
+            world
+        };
+
+        let choose = |mut world: World| {
+            let mut state = SystemState::<Query<(Entity, &Threat)>>::new(&mut world);
+            let q_threats = state.get(&world);
+
+            let mut rng = GlobalEntropy::<ChaCha8Rng>::from_seed([5; 32]);
+
+            (0..20)
+                .map(|_| rng.choose_weighted_entity(&q_threats, |threat| threat.0))
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(choose(spawn_threats(false)), choose(spawn_threats(true)));
+    }
+
+    #[test]
+    fn weighted_entity_empty_query() {
+        let mut world = World::new();
+
+        let mut state = SystemState::<Query<(Entity, &Threat)>>::new(&mut world);
+        let q_threats = state.get(&world);
+
+        let mut rng = GlobalEntropy::<ChaCha8Rng>::from_seed([5; 32]);
+
+        assert_eq!(
+            rng.choose_weighted_entity(&q_threats, |threat| threat.0),
+            None
+        );
+    }
+}
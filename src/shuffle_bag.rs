@@ -0,0 +1,168 @@
+use rand::Rng;
+use rand_core::RngCore;
+
+/// A "shuffle bag" for drawing items with target frequencies, while avoiding the long streaks
+/// that pure random rolls can produce, such as for crits that should feel fair. Every item is
+/// placed in the bag as many times as its quota, and items are then drawn from the bag without
+/// replacement. Once the bag is empty, it is refilled for the next cycle. Within each cycle,
+/// every item is drawn exactly its quota of times, in a random order.
+///
+/// Given the same RNG state, the same sequence of items is always drawn.
+///
+/// ```
+/// use bevy_rand::prelude::*;
+/// use bevy_prng::ChaCha8Rng;
+/// use rand_core::SeedableRng;
+///
+/// let mut rng = GlobalEntropy::<ChaCha8Rng>::from_seed([1; 32]);
+///
+/// // Crit exactly 1 in 5 hits, no matter how lucky or unlucky the rolls are.
+/// let mut crits = PseudoShuffleBag::new([(true, 1), (false, 4)]);
+///
+/// let crit_count = (0..5).filter(|_| *crits.draw(&mut rng).unwrap()).count();
+///
+/// assert_eq!(crit_count, 1);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct PseudoShuffleBag<T> {
+    items: Vec<T>,
+    quotas: Vec<u32>,
+    bag: Vec<usize>,
+}
+
+impl<T> PseudoShuffleBag<T> {
+    /// Create a new bag from items paired with their quota, which is how many times the item
+    /// is drawn per cycle. Items with a quota of zero are never drawn.
+    #[must_use]
+    pub fn new(items: impl IntoIterator<Item = (T, u32)>) -> Self {
+        let (items, quotas) = items.into_iter().unzip();
+
+        Self {
+            items,
+            quotas,
+            bag: Vec::new(),
+        }
+    }
+
+    /// Draws the next item from the bag, refilling the bag first if the current cycle is over.
+    /// Returns `None` if there are no items with a quota to draw from.
+    pub fn draw(&mut self, rng: &mut impl RngCore) -> Option<&T> {
+        if self.bag.is_empty() {
+            self.refill();
+        }
+
+        if self.bag.is_empty() {
+            return None;
+        }
+
+        let index = self.bag.swap_remove(rng.gen_range(0..self.bag.len()));
+
+        Some(&self.items[index])
+    }
+
+    /// Returns how many draws are left before the current cycle is over.
+    #[inline]
+    #[must_use]
+    pub fn remaining(&self) -> usize {
+        self.bag.len()
+    }
+
+    /// Returns how many draws make up a full cycle, being the sum of all quotas.
+    #[must_use]
+    pub fn cycle_len(&self) -> usize {
+        self.quotas.iter().map(|&quota| quota as usize).sum()
+    }
+
+    /// Discards the rest of the current cycle, so the next draw starts a fresh cycle.
+    #[inline]
+    pub fn reset(&mut self) {
+        self.bag.clear();
+    }
+
+    fn refill(&mut self) {
+        for (index, &quota) in self.quotas.iter().enumerate() {
+            self.bag
+                .extend(std::iter::repeat(index).take(quota as usize));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy_prng::ChaCha8Rng;
+    use rand_core::SeedableRng;
+
+    use crate::resource::GlobalEntropy;
+
+    use super::*;
+
+    #[test]
+    fn quotas_per_cycle() {
+        let mut rng = GlobalEntropy::<ChaCha8Rng>::from_seed([4; 32]);
+
+        let mut bag = PseudoShuffleBag::new([('a', 1), ('b', 3), ('c', 0), ('d', 6)]);
+
+        assert_eq!(bag.cycle_len(), 10);
+
+        for _ in 0..20 {
+            let mut counts = [0; 4];
+
+            for _ in 0..bag.cycle_len() {
+                counts[(*bag.draw(&mut rng).unwrap() as u8 - b'a') as usize] += 1;
+            }
+
+            assert_eq!(
+                counts,
+                [1, 3, 0, 6],
+                "every item should be drawn exactly its quota per cycle"
+            );
+            assert_eq!(bag.remaining(), 0);
+        }
+    }
+
+    #[test]
+    fn deterministic_order() {
+        let mut rng1 = GlobalEntropy::<ChaCha8Rng>::from_seed([4; 32]);
+        let mut rng2 = GlobalEntropy::<ChaCha8Rng>::from_seed([4; 32]);
+
+        let mut bag1 = PseudoShuffleBag::new((0..8).map(|item| (item, 2)));
+        let mut bag2 = bag1.clone();
+
+        let drawn1: Vec<_> = (0..32).map(|_| *bag1.draw(&mut rng1).unwrap()).collect();
+        let drawn2: Vec<_> = (0..32).map(|_| *bag2.draw(&mut rng2).unwrap()).collect();
+
+        assert_eq!(drawn1, drawn2);
+        assert_ne!(
+            drawn1[..16],
+            drawn1[16..],
+            "each cycle should be shuffled anew"
+        );
+    }
+
+    #[test]
+    fn nothing_to_draw() {
+        let mut rng = GlobalEntropy::<ChaCha8Rng>::from_seed([4; 32]);
+
+        let mut empty = PseudoShuffleBag::<u32>::new([]);
+        let mut zero = PseudoShuffleBag::new([(1, 0)]);
+
+        assert_eq!(empty.draw(&mut rng), None);
+        assert_eq!(zero.draw(&mut rng), None);
+    }
+
+    #[test]
+    fn reset_starts_new_cycle() {
+        let mut rng = GlobalEntropy::<ChaCha8Rng>::from_seed([4; 32]);
+
+        let mut bag = PseudoShuffleBag::new([(1, 2), (2, 2)]);
+
+        bag.draw(&mut rng);
+
+        assert_eq!(bag.remaining(), 3);
+
+        bag.reset();
+        bag.draw(&mut rng);
+
+        assert_eq!(bag.remaining(), 3, "a reset should start a full cycle");
+    }
+}
@@ -0,0 +1,347 @@
+use crate::{component::EntropyComponent, resource::GlobalEntropy, traits::SeedableEntropySource};
+#[cfg(feature = "rand")]
+use bevy::prelude::{Event, EventWriter};
+use bevy::{
+    ecs::query::ReadOnlyWorldQuery,
+    log::warn,
+    prelude::{Entity, In, Query, ResMut},
+    utils::HashMap,
+};
+#[cfg(feature = "rand")]
+use rand::{
+    distributions::{Distribution, Standard},
+    Rng,
+};
+
+/// A system that outputs a freshly sampled random `T` from the [`GlobalEntropy`] of `R`, for
+/// piping random inputs into other systems with `.pipe()`. Values are sampled from the
+/// [`Standard`] distribution, so for example floats are within `0.0..1.0`, and integers span
+/// their whole range.
+///
+/// ```
+/// use bevy::prelude::*;
+/// use bevy_rand::prelude::*;
+/// use bevy_rand::systems::emit_random;
+/// use bevy_prng::ChaCha8Rng;
+///
+/// fn roll_loot(In(roll): In<f32>) {
+///     if roll < 0.1 {
+///         println!("Rare drop!");
+///     }
+/// }
+///
+/// App::new()
+///     .add_plugins(EntropyPlugin::<ChaCha8Rng>::with_seed([1; 32]))
+///     .add_systems(Update, emit_random::<ChaCha8Rng, f32>.pipe(roll_loot));
+/// ```
+#[cfg(feature = "rand")]
+#[cfg_attr(docsrs, doc(cfg(feature = "rand")))]
+pub fn emit_random<R: SeedableEntropySource + 'static, T>(mut rng: ResMut<GlobalEntropy<R>>) -> T
+where
+    Standard: Distribution<T>,
+{
+    rng.gen()
+}
+
+/// Creates a system that rolls against `probability` every time it runs, sending a default `E`
+/// event whenever the roll succeeds, such as for triggering rare events or telemetry on a
+/// per-frame chance. The rolls are drawn from the [`GlobalEntropy`] of `R`, so for the same
+/// seed and schedule, the events fire on the same frames.
+///
+/// # Panics
+///
+/// Panics if `probability` is not within `0.0..=1.0`.
+///
+/// ```
+/// use bevy::prelude::*;
+/// use bevy_rand::prelude::*;
+/// use bevy_rand::systems::roll_event;
+/// use bevy_prng::ChaCha8Rng;
+///
+/// #[derive(Event, Default)]
+/// struct MeteorShower;
+///
+/// fn start_meteor_shower(mut events: EventReader<MeteorShower>) {
+///     for _ in events.read() {
+///         println!("A meteor shower has started!");
+///     }
+/// }
+///
+/// App::new()
+///     .add_plugins(EntropyPlugin::<ChaCha8Rng>::with_seed([1; 32]))
+///     .add_event::<MeteorShower>()
+///     .add_systems(
+///         Update,
+///         (roll_event::<ChaCha8Rng, MeteorShower>(0.001), start_meteor_shower).chain(),
+///     );
+/// ```
+#[cfg(feature = "rand")]
+#[cfg_attr(docsrs, doc(cfg(feature = "rand")))]
+pub fn roll_event<R: SeedableEntropySource + 'static, E: Event + Default>(
+    probability: f64,
+) -> impl FnMut(ResMut<GlobalEntropy<R>>, EventWriter<E>) {
+    assert!(
+        (0.0..=1.0).contains(&probability),
+        "roll_event called with a probability outside of `0.0..=1.0`"
+    );
+
+    move |mut rng, mut events| {
+        if rng.gen_bool(probability) {
+            events.send_default();
+        }
+    }
+}
+
+/// A system that reseeds the [`EntropyComponent`] of `R` on every entity in the given map with
+/// the seed paired to it, such as for applying seeds received over the network in one go.
+/// Entities that don't exist or have no [`EntropyComponent`] of `R` are skipped with a warning.
+/// Meant to be run with its input, either via `.pipe()` or `World::run_system_once_with`.
+///
+/// ```
+/// use bevy::{ecs::system::RunSystemOnce, prelude::*, utils::HashMap};
+/// use bevy_rand::prelude::*;
+/// use bevy_rand::systems::apply_seeds;
+/// use rand_core::SeedableRng;
+/// use bevy_prng::ChaCha8Rng;
+///
+/// let mut world = World::new();
+///
+/// let entity = world.spawn(EntropyComponent::<ChaCha8Rng>::from_seed([1; 32])).id();
+///
+/// let seeds = HashMap::from([(entity, [1; 32])]);
+///
+/// world.run_system_once_with(seeds, apply_seeds::<ChaCha8Rng>);
+/// ```
+pub fn apply_seeds<R: SeedableEntropySource + 'static>(
+    In(seeds): In<HashMap<Entity, R::Seed>>,
+    mut q_rngs: Query<&mut EntropyComponent<R>>,
+) {
+    for (entity, seed) in seeds {
+        match q_rngs.get_mut(entity) {
+            Ok(mut rng) => rng.reseed(seed),
+            Err(_) => warn!(
+                "Skipped reseeding {entity:?}, as it has no EntropyComponent<{}>",
+                R::short_type_path()
+            ),
+        }
+    }
+}
+
+/// A system that reseeds the [`EntropyComponent`] of `R` on every entity matching the query
+/// filter `F`, by forking a new seed for each from the [`GlobalEntropy`] of `R`, such as for
+/// reseeding only the enemies when a new wave starts. Instantiate it for a concrete filter by
+/// naming both type parameters, like `reseed_filtered::<ChaCha8Rng, With<Enemy>>`. Entities
+/// are reseeded in query iteration order, so for deterministic results, the order of entity
+/// spawns must be deterministic as well.
+///
+/// ```
+/// use bevy::prelude::*;
+/// use bevy_rand::prelude::*;
+/// use bevy_rand::systems::reseed_filtered;
+/// use bevy_prng::ChaCha8Rng;
+///
+/// #[derive(Component)]
+/// struct Enemy;
+///
+/// App::new()
+///     .add_plugins(EntropyPlugin::<ChaCha8Rng>::with_seed([1; 32]))
+///     .add_systems(Update, reseed_filtered::<ChaCha8Rng, With<Enemy>>);
+/// ```
+pub fn reseed_filtered<R: SeedableEntropySource + 'static, F: ReadOnlyWorldQuery>(
+    mut global: ResMut<GlobalEntropy<R>>,
+    mut q_rngs: Query<&mut EntropyComponent<R>, F>,
+) {
+    for mut rng in &mut q_rngs {
+        *rng = EntropyComponent::from(&mut global);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy::{ecs::system::RunSystemOnce, prelude::World};
+    use bevy_prng::ChaCha8Rng;
+    use rand_core::SeedableRng;
+
+    use super::*;
+
+    #[cfg(feature = "rand")]
+    use bevy::prelude::{App, IntoSystem, Resource, Update};
+
+    #[cfg(feature = "rand")]
+    use crate::plugin::EntropyPlugin;
+
+    #[cfg(feature = "rand")]
+    #[derive(Default, Resource)]
+    struct Received(Vec<u32>);
+
+    #[cfg(feature = "rand")]
+    fn receive(In(value): In<u32>, mut received: ResMut<Received>) {
+        received.0.push(value);
+    }
+
+    #[cfg(feature = "rand")]
+    fn piped_values(seed: [u8; 32]) -> Vec<u32> {
+        let mut app = App::new();
+
+        app.add_plugins(EntropyPlugin::<ChaCha8Rng>::with_seed(seed))
+            .init_resource::<Received>()
+            .add_systems(Update, emit_random::<ChaCha8Rng, u32>.pipe(receive));
+
+        for _ in 0..5 {
+            app.update();
+        }
+
+        app.world.remove_resource::<Received>().unwrap().0
+    }
+
+    #[cfg(feature = "rand")]
+    #[test]
+    fn rolled_events() {
+        use bevy::prelude::{Event, EventReader, IntoSystemConfigs};
+
+        #[derive(Default, Event)]
+        struct Always;
+
+        #[derive(Default, Event)]
+        struct Never;
+
+        #[derive(Default, Resource)]
+        struct Fired {
+            always: usize,
+            never: usize,
+        }
+
+        fn count(
+            mut always: EventReader<Always>,
+            mut never: EventReader<Never>,
+            mut fired: ResMut<Fired>,
+        ) {
+            fired.always += always.read().count();
+            fired.never += never.read().count();
+        }
+
+        let mut app = App::new();
+
+        app.add_plugins(EntropyPlugin::<ChaCha8Rng>::with_seed([3; 32]))
+            .add_event::<Always>()
+            .add_event::<Never>()
+            .init_resource::<Fired>()
+            .add_systems(
+                Update,
+                (
+                    roll_event::<ChaCha8Rng, Always>(1.0),
+                    roll_event::<ChaCha8Rng, Never>(0.0),
+                    count,
+                )
+                    .chain(),
+            );
+
+        for _ in 0..10 {
+            app.update();
+        }
+
+        let fired = app.world.resource::<Fired>();
+
+        assert_eq!(fired.always, 10, "a certain roll should fire every frame");
+        assert_eq!(fired.never, 0, "an impossible roll should never fire");
+    }
+
+    #[cfg(feature = "rand")]
+    #[test]
+    fn piped_random_values() {
+        let values = piped_values([3; 32]);
+
+        let mut expected = GlobalEntropy::<ChaCha8Rng>::from_seed([3; 32]);
+
+        assert_eq!(
+            values,
+            (0..5).map(|_| expected.gen::<u32>()).collect::<Vec<_>>(),
+            "the piped values should be sampled from the global source"
+        );
+        assert_eq!(values, piped_values([3; 32]));
+    }
+
+    #[test]
+    fn apply_seed_map() {
+        let mut world = World::new();
+
+        let first = world
+            .spawn(EntropyComponent::<ChaCha8Rng>::from_seed([4; 32]))
+            .id();
+        let second = world
+            .spawn(EntropyComponent::<ChaCha8Rng>::from_seed([5; 32]))
+            .id();
+        let untouched = world
+            .spawn(EntropyComponent::<ChaCha8Rng>::from_seed([6; 32]))
+            .id();
+        let missing = world.spawn_empty().id();
+
+        let before = world
+            .get::<EntropyComponent<ChaCha8Rng>>(untouched)
+            .cloned();
+
+        let seeds = HashMap::from([(first, [1; 32]), (second, [2; 32]), (missing, [3; 32])]);
+
+        world.run_system_once_with(seeds, apply_seeds::<ChaCha8Rng>);
+
+        assert_eq!(
+            world.get::<EntropyComponent<ChaCha8Rng>>(first),
+            Some(&EntropyComponent::from_seed([1; 32]))
+        );
+        assert_eq!(
+            world.get::<EntropyComponent<ChaCha8Rng>>(second),
+            Some(&EntropyComponent::from_seed([2; 32]))
+        );
+        assert_eq!(
+            world
+                .get::<EntropyComponent<ChaCha8Rng>>(untouched)
+                .cloned(),
+            before,
+            "entities without a seed should not be reseeded"
+        );
+    }
+
+    #[test]
+    fn reseed_marked_entities() {
+        use bevy::prelude::{Component, With};
+
+        #[derive(Component)]
+        struct Enemy;
+
+        let mut world = World::new();
+
+        world.insert_resource(GlobalEntropy::<ChaCha8Rng>::from_seed([4; 32]));
+
+        let enemies: Vec<_> = (0..3)
+            .map(|_| {
+                world
+                    .spawn((Enemy, EntropyComponent::<ChaCha8Rng>::from_seed([1; 32])))
+                    .id()
+            })
+            .collect();
+        let other = world
+            .spawn(EntropyComponent::<ChaCha8Rng>::from_seed([1; 32]))
+            .id();
+
+        world.run_system_once(reseed_filtered::<ChaCha8Rng, With<Enemy>>);
+
+        let original = EntropyComponent::<ChaCha8Rng>::from_seed([1; 32]);
+
+        for enemy in &enemies {
+            assert_ne!(
+                world.get::<EntropyComponent<ChaCha8Rng>>(*enemy),
+                Some(&original),
+                "enemies should be reseeded"
+            );
+        }
+        assert_ne!(
+            world.get::<EntropyComponent<ChaCha8Rng>>(enemies[0]),
+            world.get::<EntropyComponent<ChaCha8Rng>>(enemies[1]),
+        );
+        assert_eq!(
+            world.get::<EntropyComponent<ChaCha8Rng>>(other),
+            Some(&original),
+            "entities not matching the filter should be left untouched"
+        );
+    }
+}
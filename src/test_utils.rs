@@ -0,0 +1,52 @@
+use crate::{plugin::EntropyPlugin, traits::SeedableEntropySource};
+use bevy::prelude::{App, MinimalPlugins};
+
+/// Creates a minimal [`App`] with a [`crate::resource::GlobalEntropy`] resource seeded with
+/// `seed`, for use in deterministic tests. The app contains [`MinimalPlugins`] along with
+/// an [`EntropyPlugin`] configured via [`EntropyPlugin::with_seed`].
+///
+/// ```
+/// use bevy::prelude::*;
+/// use bevy_rand::{prelude::*, test_utils::seeded_app};
+/// use bevy_prng::ChaCha8Rng;
+///
+/// let app = seeded_app::<ChaCha8Rng>([1; 32]);
+///
+/// assert!(app.world.contains_resource::<GlobalEntropy<ChaCha8Rng>>());
+/// ```
+#[must_use]
+pub fn seeded_app<R: SeedableEntropySource + 'static>(seed: R::Seed) -> App
+where
+    R::Seed: Send + Sync + Copy,
+{
+    let mut app = App::new();
+
+    app.add_plugins((MinimalPlugins, EntropyPlugin::<R>::with_seed(seed)));
+
+    app
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy_prng::ChaCha8Rng;
+    use rand_core::{RngCore, SeedableRng};
+
+    use crate::resource::GlobalEntropy;
+
+    use super::*;
+
+    #[test]
+    fn seeded_app_is_deterministic() {
+        let mut app = seeded_app::<ChaCha8Rng>([6; 32]);
+
+        let mut expected = GlobalEntropy::<ChaCha8Rng>::from_seed([6; 32]);
+
+        let mut rng = app.world.resource_mut::<GlobalEntropy<ChaCha8Rng>>();
+
+        assert_eq!(
+            rng.next_u32(),
+            expected.next_u32(),
+            "the seeded app should produce the same output as a directly seeded GlobalEntropy"
+        );
+    }
+}
@@ -0,0 +1,132 @@
+use crate::{resource::GlobalEntropy, traits::SeedableEntropySource};
+use rand::seq::SliceRandom;
+
+/// The words that placeholder text is assembled from by [`GlobalEntropy::gen_words`].
+const LOREM_WORDS: &[&str] = &[
+    "lorem",
+    "ipsum",
+    "dolor",
+    "sit",
+    "amet",
+    "consectetur",
+    "adipiscing",
+    "elit",
+    "sed",
+    "do",
+    "eiusmod",
+    "tempor",
+    "incididunt",
+    "ut",
+    "labore",
+    "et",
+    "dolore",
+    "magna",
+    "aliqua",
+    "enim",
+    "ad",
+    "minim",
+    "veniam",
+    "quis",
+    "nostrud",
+    "exercitation",
+    "ullamco",
+    "laboris",
+    "nisi",
+    "aliquip",
+    "ex",
+    "ea",
+    "commodo",
+    "consequat",
+    "duis",
+    "aute",
+    "irure",
+    "in",
+    "reprehenderit",
+    "voluptate",
+    "velit",
+    "esse",
+    "cillum",
+    "fugiat",
+    "nulla",
+    "pariatur",
+    "excepteur",
+    "sint",
+    "occaecat",
+    "cupidatat",
+    "non",
+    "proident",
+    "sunt",
+    "culpa",
+    "qui",
+    "officia",
+    "deserunt",
+    "mollit",
+    "anim",
+    "id",
+    "est",
+    "laborum",
+];
+
+impl<R: SeedableEntropySource + 'static> GlobalEntropy<R> {
+    /// Generates `count` words of "lorem ipsum" style placeholder text, separated by single
+    /// spaces, such as for filling UI mockups. The words are picked from a fixed internal word
+    /// list, so for the same RNG state, the same text is always generated. Returns an empty
+    /// `String` if `count` is zero.
+    ///
+    /// ```
+    /// use bevy_rand::prelude::*;
+    /// use bevy_prng::ChaCha8Rng;
+    /// use rand_core::SeedableRng;
+    ///
+    /// let mut rng = GlobalEntropy::<ChaCha8Rng>::from_seed([1; 32]);
+    ///
+    /// let text = rng.gen_words(5);
+    ///
+    /// assert_eq!(text.split(' ').count(), 5);
+    /// ```
+    #[cfg_attr(docsrs, doc(cfg(feature = "rand")))]
+    pub fn gen_words(&mut self, count: usize) -> String {
+        let mut text = String::new();
+
+        for index in 0..count {
+            if index > 0 {
+                text.push(' ');
+            }
+
+            // The word list is never empty, so a word can always be chosen.
+            text.push_str(LOREM_WORDS.choose(self).unwrap());
+        }
+
+        text
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy_prng::ChaCha8Rng;
+    use rand_core::SeedableRng;
+
+    use super::*;
+
+    #[test]
+    fn word_count() {
+        let mut rng = GlobalEntropy::<ChaCha8Rng>::from_seed([3; 32]);
+
+        for count in [1, 2, 10, 100] {
+            let text = rng.gen_words(count);
+
+            assert_eq!(text.split(' ').count(), count);
+            assert!(text.split(' ').all(|word| LOREM_WORDS.contains(&word)));
+        }
+
+        assert!(rng.gen_words(0).is_empty());
+    }
+
+    #[test]
+    fn words_deterministic() {
+        let mut rng1 = GlobalEntropy::<ChaCha8Rng>::from_seed([3; 32]);
+        let mut rng2 = GlobalEntropy::<ChaCha8Rng>::from_seed([3; 32]);
+
+        assert_eq!(rng1.gen_words(20), rng2.gen_words(20));
+    }
+}
@@ -1,11 +1,64 @@
-use std::{cell::UnsafeCell, rc::Rc};
+use std::{
+    cell::UnsafeCell,
+    rc::Rc,
+    sync::{PoisonError, RwLock},
+};
 
 use rand_chacha::ChaCha8Rng;
-use rand_core::{CryptoRng, RngCore, SeedableRng};
+use rand_core::{CryptoRng, OsRng, RngCore, SeedableRng};
+
+type FallbackSeedSource = Box<dyn Fn() -> [u8; 32] + Send + Sync>;
+
+static FALLBACK_SEED_SOURCE: RwLock<Option<FallbackSeedSource>> = RwLock::new(None);
 
 thread_local! {
     // We require `Rc` to avoid premature freeing when `ThreadLocalEntropy` is used within thread-local destructors.
-    static SOURCE: Rc<UnsafeCell<ChaCha8Rng>> = Rc::new(UnsafeCell::new(ChaCha8Rng::from_entropy()));
+    static SOURCE: Rc<UnsafeCell<ChaCha8Rng>> = Rc::new(UnsafeCell::new(init_source(|seed| OsRng.try_fill_bytes(seed))));
+}
+
+/// Registers a fallback seed source for initialising the thread local entropy sources, to be used
+/// whenever the OS/Hardware entropy source provided by `getrandom` fails. This allows applications
+/// running in sandboxed or headless environments without a working entropy source to continue
+/// running instead of panicking. Registering a new source replaces any previously registered one,
+/// and only affects thread local sources that have yet to be initialised.
+///
+/// The fallback source should still provide as unpredictable a seed as is possible in the given
+/// environment, as its output will be used to seed all non-deterministic PRNG instances.
+///
+/// ```
+/// bevy_rand::set_fallback_seed_source(|| {
+///     // Source a seed from somewhere else available in the environment
+///     [42; 32]
+/// });
+/// ```
+#[cfg_attr(docsrs, doc(cfg(feature = "thread_local_entropy")))]
+pub fn set_fallback_seed_source(source: impl Fn() -> [u8; 32] + Send + Sync + 'static) {
+    *FALLBACK_SEED_SOURCE
+        .write()
+        .unwrap_or_else(PoisonError::into_inner) = Some(Box::new(source));
+}
+
+/// Initialises a new [`ChaCha8Rng`] source from the `primary` entropy source, falling back to the
+/// registered fallback seed source if the primary source fails.
+///
+/// # Panics
+///
+/// If the primary source fails and no fallback seed source has been registered.
+fn init_source(primary: impl FnOnce(&mut [u8]) -> Result<(), rand_core::Error>) -> ChaCha8Rng {
+    let mut seed = <ChaCha8Rng as SeedableRng>::Seed::default();
+
+    if let Err(err) = primary(seed.as_mut()) {
+        let fallback = FALLBACK_SEED_SOURCE
+            .read()
+            .unwrap_or_else(PoisonError::into_inner);
+
+        match fallback.as_ref() {
+            Some(source) => seed = source(),
+            None => panic!("ThreadLocalEntropy failed to initialise: {err}"),
+        }
+    }
+
+    ChaCha8Rng::from_seed(seed)
 }
 
 /// [`ThreadLocalEntropy`] uses thread local [`ChaCha8Rng`] instances to provide faster alternative for
@@ -15,6 +68,10 @@ thread_local! {
 /// [Too Much Crypto](https://eprint.iacr.org/2019/1492.pdf) paper. [`ThreadLocalEntropy`] is not thread-safe and
 /// cannot be sent or synchronised between threads, it should be initialised within each thread context it is
 /// needed in.
+///
+/// Each thread local source is seeded via [`OsRng`], which defers to whichever backend `getrandom` has for
+/// the target, including any custom backend registered with `getrandom::register_custom_getrandom!` when
+/// the `custom_getrandom` feature is enabled. No particular platform source is assumed.
 #[derive(Clone)]
 pub(crate) struct ThreadLocalEntropy(Rc<UnsafeCell<ChaCha8Rng>>);
 
@@ -24,7 +81,7 @@ impl ThreadLocalEntropy {
     #[inline]
     #[must_use]
     pub(crate) fn new() -> Self {
-        Self(SOURCE.with(|source| Rc::clone(source)))
+        Self(SOURCE.with(Rc::clone))
     }
 }
 
@@ -166,6 +223,35 @@ mod tests {
         assert_ne!(&bytes1, &bytes2);
     }
 
+    #[test]
+    fn fallback_seed_source_on_primary_failure() {
+        // The fallback source is process wide, so whatever was registered before is restored
+        // once done, to not leak the test source into anything else.
+        let previous = FALLBACK_SEED_SOURCE
+            .write()
+            .unwrap_or_else(PoisonError::into_inner)
+            .take();
+
+        set_fallback_seed_source(|| [3; 32]);
+
+        let rng = init_source(|_| Err(rand_core::Error::new("simulated entropy failure")));
+
+        // With the primary source failing, the fallback seed must have been used.
+        assert_eq!(rng, ChaCha8Rng::from_seed([3; 32]));
+
+        let rng = init_source(|seed| {
+            seed.fill(5);
+            Ok(())
+        });
+
+        // The fallback is never consulted while the primary source succeeds.
+        assert_eq!(rng, ChaCha8Rng::from_seed([5; 32]));
+
+        *FALLBACK_SEED_SOURCE
+            .write()
+            .unwrap_or_else(PoisonError::into_inner) = previous;
+    }
+
     #[test]
     fn non_leaking_debug() {
         assert_eq!(
@@ -0,0 +1,295 @@
+use std::time::Duration;
+
+use crate::{resource::GlobalEntropy, traits::SeedableEntropySource};
+use rand::distributions::{Distribution, Uniform};
+
+const NANOS_PER_SEC: u128 = 1_000_000_000;
+
+/// Converts a count of nanoseconds back into a [`Duration`], which must fit into one.
+fn duration_from_nanos(nanos: u128) -> Duration {
+    Duration::new(
+        (nanos / NANOS_PER_SEC) as u64,
+        (nanos % NANOS_PER_SEC) as u32,
+    )
+}
+
+/// Samples a [`Duration`] uniformly from the inclusive range of `low..=high`, at nanosecond precision.
+fn sample_duration<R: rand_core::RngCore>(rng: &mut R, low: Duration, high: Duration) -> Duration {
+    // Both bounds are valid durations, so the sampled value always fits back into one.
+    duration_from_nanos(Uniform::new_inclusive(low.as_nanos(), high.as_nanos()).sample(rng))
+}
+
+impl<R: SeedableEntropySource + 'static> GlobalEntropy<R> {
+    /// Generates a [`Duration`] that is `base` randomly offset by up to `jitter` in either
+    /// direction, so within the inclusive range of `base - jitter..=base + jitter`. The lower
+    /// bound is clamped to zero so that a `jitter` larger than `base` never produces a negative
+    /// duration. Useful for timers that should fire "every 5s ± 1s".
+    ///
+    /// ```
+    /// use std::time::Duration;
+    /// use bevy_rand::prelude::*;
+    /// use bevy_prng::ChaCha8Rng;
+    /// use rand_core::SeedableRng;
+    ///
+    /// let mut rng = GlobalEntropy::<ChaCha8Rng>::from_seed([1; 32]);
+    ///
+    /// let delay = rng.jittered_duration(Duration::from_secs(5), Duration::from_secs(1));
+    ///
+    /// assert!(delay >= Duration::from_secs(4) && delay <= Duration::from_secs(6));
+    /// ```
+    #[cfg_attr(docsrs, doc(cfg(feature = "rand")))]
+    pub fn jittered_duration(&mut self, base: Duration, jitter: Duration) -> Duration {
+        sample_duration(
+            self,
+            base.saturating_sub(jitter),
+            base.saturating_add(jitter),
+        )
+    }
+
+    /// Generates the delay before retrying after `attempt` failed attempts, with exponential
+    /// backoff and "full jitter", such as for reconnecting to a server. The delay is sampled
+    /// uniformly from `0..=min(cap, base * 2^attempt)`, which spreads out the retries of many
+    /// clients failing at the same time, rather than having them retry in lockstep. Once the
+    /// backoff would exceed `cap`, including when it overflows, `cap` is used as the upper bound
+    /// instead.
+    ///
+    /// ```
+    /// use std::time::Duration;
+    /// use bevy_rand::prelude::*;
+    /// use bevy_prng::ChaCha8Rng;
+    /// use rand_core::SeedableRng;
+    ///
+    /// let mut rng = GlobalEntropy::<ChaCha8Rng>::from_seed([1; 32]);
+    ///
+    /// let delay = rng.backoff_jitter(3, Duration::from_millis(100), Duration::from_secs(10));
+    ///
+    /// assert!(delay <= Duration::from_millis(800));
+    /// ```
+    #[cfg_attr(docsrs, doc(cfg(feature = "rand")))]
+    pub fn backoff_jitter(&mut self, attempt: u32, base: Duration, cap: Duration) -> Duration {
+        let backoff = 1u32
+            .checked_shl(attempt)
+            .and_then(|factor| base.checked_mul(factor))
+            .map_or(cap, |backoff| backoff.min(cap));
+
+        sample_duration(self, Duration::ZERO, backoff)
+    }
+
+    /// Generates `count` delays that add up to exactly `total`, such as for staggering the
+    /// spawns of an enemy wave over its duration. `count - 1` split points are sampled
+    /// uniformly within `0..=total` and sorted, and the delays are the gaps between them, so
+    /// every ordering of delays is equally likely. Delays are computed in whole nanoseconds, so
+    /// they sum to `total` without any rounding error. Returns no delays if `count` is zero.
+    ///
+    /// For the same RNG state, the same delays are always generated.
+    ///
+    /// ```
+    /// use std::time::Duration;
+    /// use bevy_rand::prelude::*;
+    /// use bevy_prng::ChaCha8Rng;
+    /// use rand_core::SeedableRng;
+    ///
+    /// let mut rng = GlobalEntropy::<ChaCha8Rng>::from_seed([1; 32]);
+    ///
+    /// let delays = rng.gen_delays(8, Duration::from_secs(20));
+    ///
+    /// assert_eq!(delays.iter().sum::<Duration>(), Duration::from_secs(20));
+    /// ```
+    #[cfg_attr(docsrs, doc(cfg(feature = "rand")))]
+    pub fn gen_delays(&mut self, count: usize, total: Duration) -> Vec<Duration> {
+        if count == 0 {
+            return Vec::new();
+        }
+
+        let total = total.as_nanos();
+        let split = Uniform::new_inclusive(0, total);
+
+        let mut points: Vec<u128> = (1..count).map(|_| split.sample(self)).collect();
+        points.sort_unstable();
+        points.push(total);
+
+        let mut previous = 0;
+
+        points
+            .into_iter()
+            .map(|point| {
+                let delay = point - previous;
+
+                previous = point;
+
+                duration_from_nanos(delay)
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy_prng::ChaCha8Rng;
+    use rand_core::SeedableRng;
+
+    use super::*;
+
+    #[test]
+    fn jitter_within_window() {
+        let mut rng = GlobalEntropy::<ChaCha8Rng>::from_seed([3; 32]);
+
+        let base = Duration::from_secs(5);
+        let jitter = Duration::from_secs(1);
+
+        for _ in 0..1000 {
+            let delay = rng.jittered_duration(base, jitter);
+
+            assert!(
+                (base - jitter..=base + jitter).contains(&delay),
+                "{delay:?} is outside of the jitter window"
+            );
+        }
+    }
+
+    #[test]
+    fn jitter_clamped_to_zero() {
+        let mut rng = GlobalEntropy::<ChaCha8Rng>::from_seed([3; 32]);
+
+        // The jitter exceeds the base, so the window is clamped to `0..=6ns`.
+        let base = Duration::from_nanos(1);
+        let jitter = Duration::from_nanos(5);
+
+        let delays: Vec<Duration> = (0..1000)
+            .map(|_| rng.jittered_duration(base, jitter))
+            .collect();
+
+        for &delay in &delays {
+            assert!(
+                (Duration::ZERO..=base + jitter).contains(&delay),
+                "{delay:?} is outside of the clamped jitter window"
+            );
+        }
+
+        assert!(
+            delays.contains(&Duration::ZERO),
+            "the clamped lower bound should be reachable"
+        );
+    }
+
+    #[test]
+    fn jitter_deterministic() {
+        let mut rng1 = GlobalEntropy::<ChaCha8Rng>::from_seed([3; 32]);
+        let mut rng2 = GlobalEntropy::<ChaCha8Rng>::from_seed([3; 32]);
+
+        let base = Duration::from_secs(5);
+        let jitter = Duration::from_millis(250);
+
+        for _ in 0..10 {
+            assert_eq!(
+                rng1.jittered_duration(base, jitter),
+                rng2.jittered_duration(base, jitter)
+            );
+        }
+
+        assert_eq!(
+            rng1.jittered_duration(base, Duration::ZERO),
+            base,
+            "no jitter should always return the base duration"
+        );
+    }
+
+    #[test]
+    fn backoff_within_window() {
+        let mut rng = GlobalEntropy::<ChaCha8Rng>::from_seed([3; 32]);
+
+        let base = Duration::from_millis(50);
+        let cap = Duration::from_secs(2);
+
+        for attempt in [0, 1, 5, 6, 31, 32, u32::MAX] {
+            let window = match attempt {
+                0 => base,
+                1 => base * 2,
+                5 => base * 32,
+                _ => cap,
+            };
+
+            for _ in 0..100 {
+                let delay = rng.backoff_jitter(attempt, base, cap);
+
+                assert!(
+                    delay <= window,
+                    "{delay:?} is above {window:?} for attempt {attempt}"
+                );
+            }
+        }
+
+        assert_eq!(
+            rng.backoff_jitter(4, base, Duration::ZERO),
+            Duration::ZERO,
+            "a zero cap should always return no delay"
+        );
+    }
+
+    #[test]
+    fn backoff_deterministic() {
+        let mut rng1 = GlobalEntropy::<ChaCha8Rng>::from_seed([3; 32]);
+        let mut rng2 = GlobalEntropy::<ChaCha8Rng>::from_seed([3; 32]);
+
+        let base = Duration::from_millis(100);
+        let cap = Duration::from_secs(30);
+
+        for attempt in 0..10 {
+            assert_eq!(
+                rng1.backoff_jitter(attempt, base, cap),
+                rng2.backoff_jitter(attempt, base, cap)
+            );
+        }
+    }
+
+    #[test]
+    fn delays_sum_to_total() {
+        let mut rng = GlobalEntropy::<ChaCha8Rng>::from_seed([3; 32]);
+
+        for (count, total) in [
+            (1, Duration::from_secs(5)),
+            (10, Duration::from_millis(1_234)),
+            (100, Duration::from_nanos(17)),
+            (5, Duration::ZERO),
+        ] {
+            let delays = rng.gen_delays(count, total);
+
+            assert_eq!(delays.len(), count);
+            assert_eq!(
+                delays.iter().sum::<Duration>(),
+                total,
+                "the delays of {count} spawns should sum to {total:?}"
+            );
+        }
+
+        assert!(rng.gen_delays(0, Duration::from_secs(1)).is_empty());
+    }
+
+    #[test]
+    fn delays_staggered() {
+        let mut rng = GlobalEntropy::<ChaCha8Rng>::from_seed([3; 32]);
+
+        let delays = rng.gen_delays(1000, Duration::from_secs(100));
+
+        // Uniform split points give exponentially distributed gaps, with a mean of 100ms.
+        let short = delays
+            .iter()
+            .filter(|&&delay| delay < Duration::from_millis(100))
+            .count();
+
+        assert!(
+            (582..682).contains(&short),
+            "about 63% of the delays should be shorter than the mean: {short}"
+        );
+    }
+
+    #[test]
+    fn delays_deterministic() {
+        let mut rng1 = GlobalEntropy::<ChaCha8Rng>::from_seed([3; 32]);
+        let mut rng2 = GlobalEntropy::<ChaCha8Rng>::from_seed([3; 32]);
+
+        let total = Duration::from_secs(30);
+
+        assert_eq!(rng1.gen_delays(12, total), rng2.gen_delays(12, total));
+    }
+}
@@ -0,0 +1,80 @@
+#![cfg(all(feature = "custom_getrandom", not(feature = "deterministic_only")))]
+
+use bevy_prng::ChaCha8Rng;
+use bevy_rand::prelude::*;
+use rand_core::{RngCore, SeedableRng};
+
+#[cfg(target_arch = "wasm32")]
+use wasm_bindgen_test::*;
+
+#[cfg(target_arch = "wasm32")]
+wasm_bindgen_test::wasm_bindgen_test_configure!(run_in_browser);
+
+/// A stand-in for a platform specific entropy source, such as a hardware RNG peripheral
+/// on a bare-metal target. Every call yields the same bytes, so that tests can tell them apart
+/// from those of any other source. Not suitable for actual use as an entropy source.
+fn custom_entropy_source(dest: &mut [u8]) -> Result<(), getrandom::Error> {
+    let mut state: u64 = 0;
+
+    for chunk in dest.chunks_mut(8) {
+        // SplitMix64
+        state = state.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z = state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^= z >> 31;
+
+        chunk.copy_from_slice(&z.to_le_bytes()[..chunk.len()]);
+    }
+
+    Ok(())
+}
+
+// Registering only takes effect on targets that `getrandom` has no built-in support for,
+// otherwise the platform's own source is used. Either way, `bevy_rand` sources its
+// entropy through whichever backend `getrandom` ends up using.
+getrandom::register_custom_getrandom!(custom_entropy_source);
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn entropy_via_registered_backend() {
+    let mut buffer = [0u8; 32];
+
+    custom_entropy_source(&mut buffer).unwrap();
+
+    assert_ne!(buffer, [0; 32]);
+
+    let mut rng1 = EntropyComponent::<ChaCha8Rng>::from_entropy();
+    let mut rng2 = GlobalEntropy::<ChaCha8Rng>::from_entropy();
+
+    // Both sources should be initialised and usable from the registered backend
+    assert_ne!(rng1.next_u64(), rng2.next_u64());
+}
+
+// Every target that CI tests on has a built-in source, with `wasm32` using the `js` source
+// enabled by the dev-dependencies, so the registered source is never used there. Asserting that
+// its bytes are used is thus limited to targets without a built-in source.
+#[test]
+#[cfg(any(target_os = "none", target_os = "uefi", target_os = "zkvm"))]
+fn seeds_from_registered_source() {
+    let mut bytes = [0u8; 32];
+
+    custom_entropy_source(&mut bytes).unwrap();
+
+    // With thread local entropy, the registered source seeds the thread local source instead,
+    // which then provides the seed.
+    #[cfg(feature = "thread_local_entropy")]
+    let bytes = {
+        let mut seed = [0u8; 32];
+
+        ChaCha8Rng::from_seed(bytes).fill_bytes(&mut seed);
+
+        seed
+    };
+
+    assert_eq!(
+        GlobalEntropy::<ChaCha8Rng>::from_entropy(),
+        GlobalEntropy::<ChaCha8Rng>::from_seed(bytes),
+        "seeding from entropy should use the bytes of the registered source"
+    );
+}
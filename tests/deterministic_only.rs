@@ -0,0 +1,66 @@
+#![cfg(feature = "deterministic_only")]
+//! Also checked by CI with `ci/deterministic_only/clippy.toml`, which must reject the
+//! `from_entropy`, `EntropyPlugin::new` and `EntropyPlugin::lazy` calls below.
+
+use bevy::prelude::*;
+use bevy_prng::ChaCha8Rng;
+use bevy_rand::prelude::*;
+use rand_core::{RngCore, SeedableRng};
+
+fn seeded_from_entropy<T: SeedableRng>() -> T {
+    T::from_entropy()
+}
+
+#[test]
+#[should_panic(expected = "deterministic_only")]
+fn rejects_trait_path() {
+    let _ = <GlobalEntropy<ChaCha8Rng> as SeedableRng>::from_entropy();
+}
+
+#[test]
+#[should_panic(expected = "deterministic_only")]
+fn rejects_generic_path() {
+    let _ = seeded_from_entropy::<EntropyComponent<ChaCha8Rng>>();
+}
+
+#[test]
+#[should_panic(expected = "deterministic_only")]
+fn rejects_default() {
+    let _ = EntropyComponent::<ChaCha8Rng>::default();
+}
+
+#[test]
+#[should_panic(expected = "deterministic_only")]
+fn rejects_unseeded_plugin() {
+    App::new().add_plugins(EntropyPlugin::<ChaCha8Rng>::new());
+}
+
+#[test]
+#[should_panic(expected = "deterministic_only")]
+fn rejects_lazy_plugin_on_first_use() {
+    let mut app = App::new();
+
+    app.add_plugins(EntropyPlugin::<ChaCha8Rng>::lazy());
+
+    app.world
+        .resource_mut::<LazyGlobalEntropy<ChaCha8Rng>>()
+        .next_u32();
+}
+
+#[test]
+fn allows_explicit_seeds() {
+    let mut app = App::new();
+
+    app.insert_resource(RngConfig::<ChaCha8Rng>::new([3; 32]))
+        .add_plugins(EntropyPlugin::<ChaCha8Rng>::default());
+
+    let mut expected = GlobalEntropy::<ChaCha8Rng>::from_seed([3; 32]);
+
+    assert_eq!(
+        app.world
+            .resource_mut::<GlobalEntropy<ChaCha8Rng>>()
+            .next_u64(),
+        expected.next_u64(),
+        "explicitly seeded sources should still be usable"
+    );
+}